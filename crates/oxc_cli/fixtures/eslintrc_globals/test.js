@@ -0,0 +1,2 @@
+foo = 1;
+bar = 1;
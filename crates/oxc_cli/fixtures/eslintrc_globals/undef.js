@@ -0,0 +1,2 @@
+baz = 1;
+new Promise(() => {});
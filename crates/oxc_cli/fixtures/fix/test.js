@@ -0,0 +1,3 @@
+debugger;
+if (typeof foo == undefined) {
+}
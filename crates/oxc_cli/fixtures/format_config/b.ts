@@ -0,0 +1,3 @@
+function b() {
+    return 1;
+}
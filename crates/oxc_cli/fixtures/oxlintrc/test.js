@@ -0,0 +1,3 @@
+debugger;
+test.skip('foo', () => {});
+if (x == NaN) {}
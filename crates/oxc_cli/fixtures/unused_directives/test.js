@@ -0,0 +1,8 @@
+// eslint-disable-next-line no-debugger
+debugger;
+
+// eslint-disable-next-line no-debugger, no-console
+debugger;
+
+/* eslint-disable-next-line */
+foo();
@@ -1,10 +1,15 @@
 use std::path::PathBuf;
 
 use bpaf::Bpaf;
+use oxc_prettier::{
+    ArrowParens, EndOfLine, PartialPrettierOptions, PrettierOptions, QuoteProps, TrailingComma,
+};
 
 use super::{
     ignore::{ignore_options, IgnoreOptions},
-    misc_options, CliCommand, MiscOptions, VERSION,
+    misc_options,
+    stdin::{stdin_options, StdinOptions},
+    CliCommand, MiscOptions, VERSION,
 };
 
 /// Formatter for the JavaScript Oxidation Compiler
@@ -23,13 +28,225 @@ impl FormatCommand {
 
 #[derive(Debug, Clone, Bpaf)]
 pub struct FormatOptions {
+    #[bpaf(external)]
+    pub format_output_options: FormatOutputOptions,
+
+    #[bpaf(external)]
+    pub printer_options: PrinterOptions,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
     #[bpaf(external)]
     pub ignore_options: IgnoreOptions,
 
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
     /// Single file, single path or list of paths
     #[bpaf(positional("PATH"), many)]
     pub paths: Vec<PathBuf>,
 }
+
+/// Output
+#[derive(Debug, Clone, Bpaf)]
+pub struct FormatOutputOptions {
+    /// Edit files in place
+    #[bpaf(short, long)]
+    pub write: bool,
+
+    /// Check if the files are formatted, list the files which are not
+    #[bpaf(short, long)]
+    pub check: bool,
+
+    /// Print the paths of the files which are not formatted
+    #[bpaf(short, long)]
+    pub list_different: bool,
+}
+
+/// What to do with the formatted code
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FormatMode {
+    /// Print the formatted code
+    Stdout,
+    Write,
+    Check,
+    ListDifferent,
+}
+
+impl FormatOutputOptions {
+    /// # Errors
+    ///
+    /// Returns `Err` if more than one of `--write`, `--check` and `--list-different` is given.
+    pub fn mode(&self) -> Result<FormatMode, &'static str> {
+        match (self.write, self.check, self.list_different) {
+            (false, false, false) => Ok(FormatMode::Stdout),
+            (true, false, false) => Ok(FormatMode::Write),
+            (false, true, false) => Ok(FormatMode::Check),
+            (false, false, true) => Ok(FormatMode::ListDifferent),
+            _ => Err("Only one of --write, --check and --list-different can be used"),
+        }
+    }
+}
+
+/// Printer Options
+#[derive(Debug, Clone, Bpaf)]
+pub struct PrinterOptions {
+    /// The line length that the printer will wrap on (default: 80)
+    #[bpaf(argument("INT"), hide_usage)]
+    pub print_width: Option<usize>,
+
+    /// The number of spaces per indentation-level (default: 2)
+    #[bpaf(argument("INT"), hide_usage)]
+    pub tab_width: Option<usize>,
+
+    /// Indent with tabs instead of spaces
+    #[bpaf(switch, hide_usage)]
+    pub use_tabs: bool,
+
+    /// Which end of line characters to apply: lf, crlf, cr or auto (default: lf)
+    #[bpaf(argument::<String>("EOL"), parse(|s: String| parse_end_of_line(&s)), optional, hide_usage)]
+    pub end_of_line: Option<EndOfLine>,
+
+    /// Do not print semicolons, except at the beginning of lines which may need them
+    #[bpaf(switch, hide_usage)]
+    pub no_semi: bool,
+
+    /// Use single quotes instead of double quotes
+    #[bpaf(switch, hide_usage)]
+    pub single_quote: bool,
+
+    /// Use single quotes instead of double quotes in JSX
+    #[bpaf(switch, hide_usage)]
+    pub jsx_single_quote: bool,
+
+    /// When to quote object properties: as-needed, consistent or preserve (default: as-needed)
+    #[bpaf(argument::<String>("WHEN"), parse(|s: String| parse_quote_props(&s)), optional, hide_usage)]
+    pub quote_props: Option<QuoteProps>,
+
+    /// Where to print trailing commas: all, es5 or none (default: all)
+    #[bpaf(argument::<String>("WHERE"), parse(|s: String| parse_trailing_comma(&s)), optional, hide_usage)]
+    pub trailing_comma: Option<TrailingComma>,
+
+    /// Do not print spaces between brackets in object literals
+    #[bpaf(switch, hide_usage)]
+    pub no_bracket_spacing: bool,
+
+    /// Put the `>` of a multi-line JSX element at the end of the last line
+    #[bpaf(switch, hide_usage)]
+    pub bracket_same_line: bool,
+
+    /// Parentheses around a sole arrow function parameter: always or avoid (default: always)
+    #[bpaf(argument::<String>("WHEN"), parse(|s: String| parse_arrow_parens(&s)), optional, hide_usage)]
+    pub arrow_parens: Option<ArrowParens>,
+}
+
+impl PrinterOptions {
+    /// `options` with the given flags applied
+    pub fn apply(&self, mut options: PrettierOptions) -> PrettierOptions {
+        self.to_partial_options().apply(&mut options);
+        options
+    }
+
+    /// The options given by the flags, switches which are not given are `None`
+    fn to_partial_options(&self) -> PartialPrettierOptions {
+        PartialPrettierOptions {
+            print_width: self.print_width,
+            tab_width: self.tab_width,
+            use_tabs: self.use_tabs.then_some(true),
+            end_of_line: self.end_of_line,
+            semi: self.no_semi.then_some(false),
+            single_quote: self.single_quote.then_some(true),
+            quote_props: self.quote_props,
+            jsx_single_quote: self.jsx_single_quote.then_some(true),
+            trailing_comma: self.trailing_comma,
+            bracket_spacing: self.no_bracket_spacing.then_some(false),
+            bracket_same_line: self.bracket_same_line.then_some(true),
+            arrow_parens: self.arrow_parens,
+        }
+    }
+}
+
+// The `FromStr` implementations of the printer options fall back to the default for unknown values,
+// the flags reject them instead.
+
+fn parse_end_of_line(s: &str) -> Result<EndOfLine, String> {
+    match s {
+        "lf" => Ok(EndOfLine::Lf),
+        "crlf" => Ok(EndOfLine::Crlf),
+        "cr" => Ok(EndOfLine::Cr),
+        "auto" => Ok(EndOfLine::Auto),
+        _ => Err(format!("expected lf, crlf, cr or auto, got {s:?}")),
+    }
+}
+
+fn parse_quote_props(s: &str) -> Result<QuoteProps, String> {
+    match s {
+        "as-needed" => Ok(QuoteProps::AsNeeded),
+        "consistent" => Ok(QuoteProps::Consistent),
+        "preserve" => Ok(QuoteProps::Preserve),
+        _ => Err(format!("expected as-needed, consistent or preserve, got {s:?}")),
+    }
+}
+
+fn parse_trailing_comma(s: &str) -> Result<TrailingComma, String> {
+    match s {
+        "all" => Ok(TrailingComma::All),
+        "es5" => Ok(TrailingComma::ES5),
+        "none" => Ok(TrailingComma::None),
+        _ => Err(format!("expected all, es5 or none, got {s:?}")),
+    }
+}
+
+fn parse_arrow_parens(s: &str) -> Result<ArrowParens, String> {
+    match s {
+        "always" => Ok(ArrowParens::Always),
+        "avoid" => Ok(ArrowParens::Avoid),
+        _ => Err(format!("expected always or avoid, got {s:?}")),
+    }
+}
+
+#[cfg(test)]
+mod format_options {
+    use oxc_prettier::{PrettierOptions, TrailingComma};
+
+    use super::{format_command, FormatMode, FormatOptions};
+
+    fn get_format_options(arg: &str) -> FormatOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        format_command().run_inner(args.as_slice()).unwrap().format_options
+    }
+
+    #[test]
+    fn mode() {
+        let options = get_format_options("src");
+        assert_eq!(options.format_output_options.mode(), Ok(FormatMode::Stdout));
+        let options = get_format_options("--write src");
+        assert_eq!(options.format_output_options.mode(), Ok(FormatMode::Write));
+        let options = get_format_options("-c src");
+        assert_eq!(options.format_output_options.mode(), Ok(FormatMode::Check));
+        let options = get_format_options("-l src");
+        assert_eq!(options.format_output_options.mode(), Ok(FormatMode::ListDifferent));
+        let options = get_format_options("--check --write src");
+        assert!(options.format_output_options.mode().is_err());
+    }
+
+    #[test]
+    fn printer_options() {
+        let options = get_format_options(
+            "--print-width 100 --no-semi --single-quote --trailing-comma es5 src",
+        );
+        let prettier_options = options.printer_options.apply(PrettierOptions::default());
+        assert_eq!(prettier_options.print_width, 100);
+        assert_eq!(prettier_options.tab_width, 2);
+        assert!(!prettier_options.semi);
+        assert!(prettier_options.single_quote);
+        assert_eq!(prettier_options.trailing_comma, TrailingComma::ES5);
+    }
+
+    #[test]
+    fn invalid_printer_option() {
+        let result = format_command().run_inner(&["--trailing-comma", "some", "src"]);
+        assert!(result.is_err());
+    }
+}
@@ -1,11 +1,15 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
-use oxc_linter::AllowWarnDeny;
+use oxc_linter::{AllowWarnDeny, FixKind};
+
+use crate::git::Changes;
 
 use super::{
     ignore::{ignore_options, IgnoreOptions},
-    misc_options, CliCommand, MiscOptions, VERSION,
+    misc_options,
+    stdin::{stdin_options, StdinOptions},
+    CliCommand, MiscOptions, VERSION,
 };
 
 // To add a header or footer, see
@@ -44,16 +48,40 @@ pub struct LintOptions {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
-    /// list all the rules that are currently registered
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub git_options: GitOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
+    /// list all the rules that are currently registered, use `--format json` for their metadata and documentation
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print the documentation and default configuration of a rule, e.g. `no-debugger` or `@typescript-eslint/no-explicit-any`
+    #[bpaf(argument("RULE"), hide_usage)]
+    pub explain: Option<String>,
+
+    /// Keep running and lint the files affected by each change again
+    #[bpaf(switch, hide_usage)]
+    pub watch: bool,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
-    /// ESLint configuration file (experimental)
+    /// Oxlint or ESLint configuration file (experimental)
     ///
     /// * only `.json` extension is supported
+    /// * files named `.oxlintrc.json` are read in the native format, see `npm/oxlint/configuration_schema.json`
+    /// * when not provided, the nearest `.oxlintrc.json`, `.eslintrc.json` or `.eslintrc` of each file is used,
+    ///   merged with the ones in parent directories up to a configuration with `"root": true`
+    ///   or the root of the git repository
     #[bpaf(long, short, argument("PATH"))]
     pub config: Option<PathBuf>,
 
@@ -78,10 +106,11 @@ const PATHS_ERROR_MESSAGE: &str = "PATH cannot start with \"..\"";
 
 // This is formatted according to
 // <https://docs.rs/bpaf/latest/bpaf/params/struct.NamedArg.html#method.help>
-/// Allowing / Denying Multiple Lints
+/// Allowing / Warning / Denying Multiple Lints
 /// For example `-D correctness -A no-debugger` or `-A all -D no-debugger`.
 /// ㅤ
-///  The default category is "-D correctness".
+///  The default category is "-W correctness".
+///  Rule names take precedence over the configuration file.
 ///  Use "--rules" for rule names.
 ///  Use "--help --help" for rule categories.
 ///
@@ -100,6 +129,11 @@ pub enum LintFilter {
         #[bpaf(short('A'), long("allow"), argument("NAME"))]
         String,
     ),
+    Warn(
+        /// Warn the rule or category (emit a warning)
+        #[bpaf(short('W'), long("warn"), argument("NAME"))]
+        String,
+    ),
     Deny(
         /// Deny the rule or category (emit an error)
         #[bpaf(short('D'), long("deny"), argument("NAME"))]
@@ -111,6 +145,7 @@ impl LintFilter {
     fn into_tuple(self) -> (AllowWarnDeny, String) {
         match self {
             Self::Allow(s) => (AllowWarnDeny::Allow, s),
+            Self::Warn(s) => (AllowWarnDeny::Warn, s),
             Self::Deny(s) => (AllowWarnDeny::Deny, s),
         }
     }
@@ -122,6 +157,32 @@ pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Apply the safe fixes, and the first suggestion of the issues which offer suggestions instead of fixes.
+    /// Suggestions may change the behavior of the program
+    #[bpaf(switch)]
+    pub fix_suggestions: bool,
+
+    /// Apply dangerous fixes as well as safe fixes, implies `--fix`.
+    /// Dangerous fixes may change the behavior of the program
+    #[bpaf(switch)]
+    pub fix_dangerously: bool,
+}
+
+impl FixOptions {
+    pub fn fix_kind(&self) -> FixKind {
+        let mut kind = FixKind::empty();
+        if self.fix || self.fix_suggestions || self.fix_dangerously {
+            kind |= FixKind::Fix;
+        }
+        if self.fix_suggestions {
+            kind |= FixKind::Suggestion;
+        }
+        if self.fix_dangerously {
+            kind |= FixKind::Dangerous;
+        }
+        kind
+    }
 }
 
 /// Handle Warnings
@@ -139,21 +200,111 @@ pub struct WarningOptions {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Report `eslint-disable` directives which do not suppress any problem, use `--fix` to remove them
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint files which changed since the last run,
+    /// the diagnostics of unchanged files are read from the cache
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path to the cache file
+    #[bpaf(argument("PATH"), fallback(PathBuf::from(".oxlintcache")), hide_usage)]
+    pub cache_location: PathBuf,
+}
+
+/// Git
+#[derive(Debug, Clone, Bpaf)]
+pub struct GitOptions {
+    /// Only lint files changed since the revision, including uncommitted and untracked files
+    #[bpaf(argument("REV"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// Only lint files with staged changes
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+
+    /// Only report diagnostics on the lines changed, used with `--changed-since` or `--staged`
+    #[bpaf(switch, hide_usage)]
+    pub only_changed_lines: bool,
+}
+
+impl GitOptions {
+    /// # Errors
+    ///
+    /// Returns `Err` if the options conflict with each other
+    pub fn changes(&self) -> Result<Option<Changes>, &'static str> {
+        match (&self.changed_since, self.staged) {
+            (Some(_), true) => Err("--changed-since and --staged cannot be used together"),
+            (Some(revision), false) => Ok(Some(Changes::Since(revision))),
+            (None, true) => Ok(Some(Changes::Staged)),
+            (None, false) if self.only_changed_lines => {
+                Err("--only-changed-lines requires --changed-since or --staged")
+            }
+            (None, false) => Ok(None),
+        }
+    }
+}
+
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    /// Only report problems which are not recorded in the baseline file,
+    /// and report the recorded problems which no longer occur
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub baseline: Option<PathBuf>,
+
+    /// Record all problems in the baseline file instead of reporting them
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub write_baseline: Option<PathBuf>,
 }
 
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json)
-    // last flag is the default
-    #[bpaf(long, short, flag(OutputFormat::Json, OutputFormat::Default))]
+    /// Use a specific output format (default, json, sarif, junit, checkstyle, github)
+    #[bpaf(long, short, argument("FORMAT"), fallback(OutputFormat::Default))]
     pub format: OutputFormat,
+
+    /// Print the time spent in each rule, and in parsing and building semantic for the slowest files
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum OutputFormat {
     Default,
+    /// ESLint's `json` formatter
     Json,
+    Sarif,
+    Junit,
+    Checkstyle,
+    /// GitHub Actions annotations
+    Github,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "github" => Ok(Self::Github),
+            _ => Err(format!(
+                "'{s}' is not a known format, expected one of default, json, sarif, junit, checkstyle, github"
+            )),
+        }
+    }
 }
 
 /// Enable Plugins
@@ -214,9 +365,10 @@ mod warning_options {
 mod lint_options {
     use std::path::PathBuf;
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_linter::{AllowWarnDeny, FixKind};
 
     use super::{lint_command, LintOptions, OutputFormat};
+    use crate::git::Changes;
 
     fn get_lint_options(arg: &str) -> LintOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -254,21 +406,41 @@ mod lint_options {
         }
     }
 
+    #[test]
+    fn explain() {
+        let options = get_lint_options("--explain no-debugger");
+        assert_eq!(options.explain, Some("no-debugger".to_string()));
+        assert!(options.paths.is_empty());
+    }
+
     #[test]
     fn fix() {
         let options = get_lint_options("--fix test.js");
         assert!(options.fix_options.fix);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Fix);
+    }
+
+    #[test]
+    fn fix_kind() {
+        let options = get_lint_options("--fix-suggestions test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Fix | FixKind::Suggestion);
+        let options = get_lint_options("--fix-dangerously test.js");
+        assert_eq!(options.fix_options.fix_kind(), FixKind::Fix | FixKind::Dangerous);
+        let options = get_lint_options("test.js");
+        assert!(options.fix_options.fix_kind().is_empty());
     }
 
     #[test]
     fn filter() {
-        let options =
-            get_lint_options("-D suspicious --deny pedantic -A no-debugger --allow no-var src");
+        let options = get_lint_options(
+            "-D suspicious --deny pedantic -W eqeqeq -A no-debugger --allow no-var src",
+        );
         assert_eq!(
             options.filter,
             [
                 (AllowWarnDeny::Deny, "suspicious".into()),
                 (AllowWarnDeny::Deny, "pedantic".into()),
+                (AllowWarnDeny::Warn, "eqeqeq".into()),
                 (AllowWarnDeny::Allow, "no-debugger".into()),
                 (AllowWarnDeny::Allow, "no-var".into())
             ]
@@ -279,6 +451,60 @@ mod lint_options {
     fn format() {
         let options = get_lint_options("-f json");
         assert_eq!(options.output_options.format, OutputFormat::Json);
+        let options = get_lint_options("--format sarif");
+        assert_eq!(options.output_options.format, OutputFormat::Sarif);
+        let options = get_lint_options("--format=github");
+        assert_eq!(options.output_options.format, OutputFormat::Github);
+    }
+
+    #[test]
+    fn unknown_format() {
+        let result = lint_command().run_inner(&["--format", "xml"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn watch() {
+        let options = get_lint_options("--watch src");
+        assert!(options.watch);
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_lint_options("--cache");
+        assert!(options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, PathBuf::from(".oxlintcache"));
+
+        let options = get_lint_options("--cache --cache-location /tmp/cache");
+        assert_eq!(options.cache_options.cache_location, PathBuf::from("/tmp/cache"));
+    }
+
+    #[test]
+    fn git() {
+        let options = get_lint_options("--changed-since main --only-changed-lines");
+        assert!(matches!(options.git_options.changes(), Ok(Some(Changes::Since("main")))));
+        assert!(options.git_options.only_changed_lines);
+
+        let options = get_lint_options("--staged");
+        assert!(matches!(options.git_options.changes(), Ok(Some(Changes::Staged))));
+
+        let options = get_lint_options("--staged --changed-since main");
+        assert!(options.git_options.changes().is_err());
+
+        let options = get_lint_options("--only-changed-lines");
+        assert!(options.git_options.changes().is_err());
+
+        let options = get_lint_options(".");
+        assert!(matches!(options.git_options.changes(), Ok(None)));
+    }
+
+    #[test]
+    fn baseline() {
+        let options = get_lint_options("--baseline baseline.json");
+        assert_eq!(options.baseline_options.baseline, Some(PathBuf::from("baseline.json")));
+        let options = get_lint_options("--write-baseline baseline.json");
+        assert_eq!(options.baseline_options.write_baseline, Some(PathBuf::from("baseline.json")));
     }
 
     #[test]
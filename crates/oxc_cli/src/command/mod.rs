@@ -1,22 +1,27 @@
 mod format;
 mod ignore;
 mod lint;
+mod stdin;
 
 use bpaf::Bpaf;
 
 pub use self::{
-    format::{format_command, FormatOptions},
+    format::{format_command, FormatMode, FormatOptions, PrinterOptions},
     ignore::IgnoreOptions,
-    lint::{lint_command, LintOptions, OutputFormat, OutputOptions, WarningOptions},
+    lint::{
+        lint_command, BaselineOptions, LintOptions, OutputFormat, OutputOptions, WarningOptions,
+    },
+    stdin::StdinOptions,
 };
 
 use self::{format::format_options, lint::lint_options};
 
-const VERSION: &str = match option_env!("OXC_VERSION") {
+pub(crate) const VERSION: &str = match option_env!("OXC_VERSION") {
     Some(v) => v,
     None => "dev",
 };
 
+#[allow(clippy::large_enum_variant)] // Parsed once at startup
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options, version(VERSION))]
 pub enum CliCommand {
@@ -0,0 +1,62 @@
+use std::path::{Path, PathBuf};
+
+use bpaf::Bpaf;
+
+/// Stdin
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Read the source text from stdin instead of the files
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Path of the file the source text from stdin belongs to,
+    /// which determines the source type and the configuration files used
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
+impl StdinOptions {
+    /// The path of the source text read from stdin, `None` if `--stdin` is not given
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if only one of `--stdin` and `--stdin-filename` is given.
+    pub fn path(&self) -> Result<Option<&Path>, &'static str> {
+        match (self.stdin, &self.stdin_filename) {
+            (true, Some(path)) => Ok(Some(path)),
+            (false, None) => Ok(None),
+            (true, None) => Err("--stdin requires --stdin-filename"),
+            (false, Some(_)) => Err("--stdin-filename can only be used with --stdin"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod stdin_options {
+    use std::path::Path;
+
+    use super::{super::lint::lint_command, StdinOptions};
+
+    fn get_stdin_options(arg: &str) -> StdinOptions {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        lint_command().run_inner(args.as_slice()).unwrap().lint_options.stdin_options
+    }
+
+    #[test]
+    fn default() {
+        let options = get_stdin_options(".");
+        assert_eq!(options.path(), Ok(None));
+    }
+
+    #[test]
+    fn stdin_filename() {
+        let options = get_stdin_options("--stdin --stdin-filename src/a.tsx");
+        assert_eq!(options.path(), Ok(Some(Path::new("src/a.tsx"))));
+    }
+
+    #[test]
+    fn missing_stdin() {
+        assert!(get_stdin_options("--stdin").path().is_err());
+        assert!(get_stdin_options("--stdin-filename a.js").path().is_err());
+    }
+}
@@ -1,13 +1,19 @@
-use std::path::Path;
+use std::{
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
 
 use oxc_allocator::Allocator;
+use oxc_diagnostics::{
+    DiagnosticService, Error, FailedToOpenFileError, FailedToWriteFileError, GraphicalReportHandler,
+};
 use oxc_parser::Parser;
-use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_prettier::{Prettier, PrettierConfigResolver, PrettierOptions};
 use oxc_span::SourceType;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 
 use crate::{
-    command::FormatOptions,
+    command::{FormatMode, FormatOptions, PrinterOptions},
     result::{CliRunResult, FormatResult},
     walk::Walk,
     Runner,
@@ -25,7 +31,25 @@ impl Runner for FormatRunner {
     }
 
     fn run(self) -> CliRunResult {
-        let FormatOptions { paths, ignore_options, .. } = &self.options;
+        let FormatOptions {
+            paths,
+            ignore_options,
+            format_output_options,
+            printer_options,
+            stdin_options,
+            ..
+        } = &self.options;
+
+        let mode = match format_output_options.mode() {
+            Ok(mode) => mode,
+            Err(message) => return CliRunResult::InvalidOptions { message: message.to_string() },
+        };
+
+        match stdin_options.path() {
+            Ok(Some(path)) => return Self::run_stdin(path, mode, paths, printer_options),
+            Ok(None) => {}
+            Err(message) => return CliRunResult::InvalidOptions { message: message.to_string() },
+        }
 
         if paths.is_empty() {
             return CliRunResult::InvalidOptions { message: "No paths are provided.".to_string() };
@@ -35,24 +59,202 @@ impl Runner for FormatRunner {
 
         let paths = Walk::new(paths, ignore_options).paths();
 
-        paths.par_iter().for_each(|path| {
-            Self::format(path);
-        });
+        // The flags take precedence over the configuration files
+        let resolver = PrettierConfigResolver::new();
+        let results = paths
+            .par_iter()
+            .map(|path| {
+                let options = printer_options.apply(resolver.resolve(path)?);
+                Self::format(path, mode, options)
+            })
+            .collect::<Vec<_>>();
+
+        let mut stdout = BufWriter::new(std::io::stdout());
+        let mut number_of_unformatted = 0;
+        let mut number_of_errors = 0;
+        for (path, result) in paths.iter().zip(results) {
+            match result {
+                Ok(Formatted { code, .. }) if mode == FormatMode::Stdout => {
+                    stdout.write_all(code.as_bytes()).unwrap();
+                }
+                Ok(Formatted { changed: false, .. }) => {}
+                Ok(Formatted { changed: true, .. }) => {
+                    number_of_unformatted += 1;
+                    match mode {
+                        FormatMode::Check => eprintln!("[warn] {}", path.display()),
+                        FormatMode::ListDifferent => {
+                            writeln!(stdout, "{}", path.display()).unwrap();
+                        }
+                        FormatMode::Write | FormatMode::Stdout => {}
+                    }
+                }
+                Err(diagnostic) => {
+                    number_of_errors += 1;
+                    Self::print_error(&diagnostic);
+                }
+            }
+        }
+        stdout.flush().unwrap();
 
         CliRunResult::FormatResult(FormatResult {
             duration: now.elapsed(),
             number_of_files: paths.len(),
+            mode,
+            number_of_unformatted,
+            number_of_errors,
         })
     }
 }
 
+struct Formatted {
+    code: String,
+    /// Whether the code differs from the file content
+    changed: bool,
+}
+
 impl FormatRunner {
-    fn format(path: &Path) {
-        let source_text = std::fs::read_to_string(path).unwrap();
+    /// Format the source text from stdin as the file at `path`, and print it to stdout
+    fn run_stdin(
+        path: &Path,
+        format_mode: FormatMode,
+        paths: &[PathBuf],
+        printer_options: &PrinterOptions,
+    ) -> CliRunResult {
+        if format_mode != FormatMode::Stdout || !paths.is_empty() {
+            return CliRunResult::InvalidOptions {
+                message: "--stdin cannot be used with --write, --check, --list-different or paths"
+                    .to_string(),
+            };
+        }
+        if SourceType::from_path(path).is_err() {
+            return CliRunResult::InvalidOptions {
+                message: format!("Unsupported file extension of {}", path.display()),
+            };
+        }
+        let now = std::time::Instant::now();
+        let source_text = match std::io::read_to_string(std::io::stdin()) {
+            Ok(source_text) => source_text,
+            Err(error) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to read stdin: {error}"),
+                }
+            }
+        };
+        let result = PrettierConfigResolver::new().resolve(path).and_then(|options| {
+            Self::format_source(path, &source_text, printer_options.apply(options))
+        });
+        let number_of_errors = match result {
+            Ok(code) => {
+                let mut stdout = std::io::stdout().lock();
+                stdout.write_all(code.as_bytes()).unwrap();
+                stdout.flush().unwrap();
+                0
+            }
+            Err(diagnostic) => {
+                Self::print_error(&diagnostic);
+                1
+            }
+        };
+        CliRunResult::FormatResult(FormatResult {
+            duration: now.elapsed(),
+            number_of_files: 1,
+            mode: format_mode,
+            number_of_unformatted: 0,
+            number_of_errors,
+        })
+    }
+
+    /// Format the file at `path`, and write it in [FormatMode::Write]
+    fn format(
+        path: &Path,
+        format_mode: FormatMode,
+        options: PrettierOptions,
+    ) -> Result<Formatted, Error> {
+        let source_text = std::fs::read_to_string(path)
+            .map_err(|e| Error::new(FailedToOpenFileError(path.to_path_buf(), e)))?;
+        let code = Self::format_source(path, &source_text, options)?;
+        let changed = code != source_text;
+        if changed && format_mode == FormatMode::Write {
+            std::fs::write(path, &code)
+                .map_err(|e| Error::new(FailedToWriteFileError(path.to_path_buf(), e)))?;
+        }
+        Ok(Formatted { code, changed })
+    }
+
+    /// Format `source_text` of the file at `path`
+    fn format_source(
+        path: &Path,
+        source_text: &str,
+        options: PrettierOptions,
+    ) -> Result<String, Error> {
         let allocator = Allocator::default();
         let source_type = SourceType::from_path(path).unwrap();
-        let ret = Parser::new(&allocator, &source_text, source_type).preserve_parens(false).parse();
-        let _ = Prettier::new(&allocator, &source_text, ret.trivias, PrettierOptions::default())
-            .build(&ret.program);
+        let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+        // Code with syntax errors would be formatted into something else
+        if let Some(error) = ret.errors.into_iter().next() {
+            let (_, mut errors) =
+                DiagnosticService::wrap_diagnostics(path, source_text, vec![error]);
+            return Err(errors.remove(0));
+        }
+        Ok(Prettier::new(&allocator, source_text, ret.trivias, options).build(&ret.program))
+    }
+
+    fn print_error(diagnostic: &Error) {
+        let handler = GraphicalReportHandler::new();
+        let mut err = String::new();
+        handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+        eprintln!("{err}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FormatRunner;
+    use crate::{format_command, CliRunResult, FormatResult, Runner};
+
+    fn test(args: &[&str]) -> FormatResult {
+        let options = format_command().run_inner(args).unwrap().format_options;
+        match FormatRunner::new(options).run() {
+            CliRunResult::FormatResult(format_result) => format_result,
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn check() {
+        let result = test(&["--check", "fixtures/format"]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_unformatted, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn printer_options() {
+        let result = test(&["--list-different", "--no-semi", "fixtures/format"]);
+        assert_eq!(result.number_of_unformatted, 2);
+    }
+
+    #[test]
+    fn config_files() {
+        let result = test(&["--check", "fixtures/format_config"]);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_unformatted, 0);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn printer_options_override_config_files() {
+        let result = test(&["--check", "--no-semi", "fixtures/format_config"]);
+        assert_eq!(result.number_of_unformatted, 2);
+    }
+
+    #[test]
+    fn stdin_with_check() {
+        let options = format_command()
+            .run_inner(&["--stdin", "--stdin-filename", "a.js", "--check"])
+            .unwrap()
+            .format_options;
+        let result = FormatRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
     }
 }
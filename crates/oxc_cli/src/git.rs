@@ -1,66 +1,192 @@
-use std::path::Path;
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
 
-use git2::Repository;
+use git2::{Diff, DiffOptions, Repository};
 use miette::Diagnostic;
 use oxc_diagnostics::{thiserror::Error, Error};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("No repository found")]
-#[diagnostic(severity(warning), help("Ensure target path(s) belong to a Git repository"))]
+#[diagnostic(help("Ensure the current directory belongs to a Git repository"))]
 struct NoRepositoryFound;
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("Multiple repositories found")]
-#[diagnostic(severity(warning), help("Ensure all paths belong to a single repository"))]
-struct MultipleRepositoriesFound;
+#[error("Revision {0:?} not found")]
+#[diagnostic(help("{1}"))]
+struct RevisionNotFound(String, String);
 
 #[derive(Debug, Error, Diagnostic)]
-#[error("Uncommitted changes")]
-#[diagnostic(severity(warning), help("Commit any changes before linting"))]
-struct UncommittedChanges;
+#[error("Failed to diff the repository")]
+#[diagnostic(help("{0}"))]
+struct FailedToDiff(String);
 
-pub struct Git<'a> {
-    // paths: &'a Vec<Box<Path>>,
-    repos: Vec<Repository>,
+/// The changes to lint
+#[derive(Debug, Clone, Copy)]
+pub enum Changes<'a> {
+    /// Changes in the working tree since a revision, including staged and untracked files
+    Since(&'a str),
+    /// Changes in the index relative to `HEAD`
+    Staged,
 }
 
-impl<'a> Git<'a> {
-    pub fn new() -> Self {
-        // let repos: Vec<Repository> =
-        // paths.iter().filter_map(|path| Repository::discover(path).ok()).collect();
-        Self { repos }
-    }
+pub struct Git {
+    repo: Repository,
+}
 
-    pub fn verify(&'a self) -> Result<&'a Repository, Error> {
-        if self.repos.is_empty() {
+impl Git {
+    /// The repository containing `path`
+    pub fn discover(path: &Path) -> Result<Self, Error> {
+        let repo = Repository::discover(path).map_err(|_| NoRepositoryFound)?;
+        if repo.workdir().is_none() {
             return Err(NoRepositoryFound.into());
         }
-        match self.is_same_repo() {
-            Ok(repo) => {
-                for path in self.paths {
-                    if Self::is_uncommitted(repo, path) {
-                        return Err(UncommittedChanges.into());
+        Ok(Self { repo })
+    }
+
+    pub fn changed_files(&self, changes: Changes) -> Result<ChangedFiles, Error> {
+        let diff = match changes {
+            Changes::Since(revision) => self.diff_since(revision)?,
+            Changes::Staged => self.diff_staged()?,
+        };
+        let workdir = self.repo.workdir().unwrap();
+        // Keyed by the path relative to the working directory
+        let files = RefCell::new(HashMap::<PathBuf, (PathBuf, ChangedFile)>::new());
+        diff.foreach(
+            &mut |delta, _| {
+                // Deleted files cannot be canonicalized, they are not linted anyway
+                if let Some(path) = delta.new_file().path() {
+                    if let Ok(canonicalized) = fs::canonicalize(workdir.join(path)) {
+                        // The staged content is linted instead of the working tree
+                        let staged_source = matches!(changes, Changes::Staged)
+                            .then(|| self.repo.find_blob(delta.new_file().id()).ok())
+                            .flatten()
+                            .and_then(|blob| String::from_utf8(blob.content().to_vec()).ok());
+                        let file = ChangedFile { lines: HashSet::new(), staged_source };
+                        files.borrow_mut().insert(path.to_path_buf(), (canonicalized, file));
                     }
                 }
-                Ok(repo)
-            }
-            err => err,
-        }
+                true
+            },
+            None,
+            None,
+            Some(&mut |delta, _, line| {
+                if line.origin() == '+' {
+                    let mut files = files.borrow_mut();
+                    let lines = delta.new_file().path().and_then(|path| files.get_mut(path));
+                    if let (Some((_, file)), Some(line_number)) = (lines, line.new_lineno()) {
+                        file.lines.insert(line_number as usize);
+                    }
+                }
+                true
+            }),
+        )
+        .map_err(|e| FailedToDiff(e.message().to_string()))?;
+        Ok(ChangedFiles(files.into_inner().into_values().collect()))
     }
 
-    /// Given a list of repositories, verify they're all the same repository.
-    fn is_same_repo(&self) -> Result<&Repository, Error> {
-        assert!(!self.repos.is_empty());
-        let first_repo = self.repos.first().unwrap();
-        for repo in &self.repos[1..] {
-            if repo.path() != first_repo.path() {
-                return Err(MultipleRepositoriesFound.into());
-            }
-        }
-        Ok(first_repo)
+    fn diff_since(&self, revision: &str) -> Result<Diff<'_>, Error> {
+        let tree = self
+            .repo
+            .revparse_single(revision)
+            .and_then(|object| object.peel_to_tree())
+            .map_err(|e| RevisionNotFound(revision.to_string(), e.message().to_string()))?;
+        let mut options = DiffOptions::new();
+        options.include_untracked(true).recurse_untracked_dirs(true).show_untracked_content(true);
+        self.repo
+            .diff_tree_to_workdir_with_index(Some(&tree), Some(&mut options))
+            .map_err(|e| FailedToDiff(e.message().to_string()).into())
+    }
+
+    fn diff_staged(&self) -> Result<Diff<'_>, Error> {
+        // `HEAD` does not exist before the first commit, everything in the index is staged
+        let tree = self.repo.head().and_then(|head| head.peel_to_tree()).ok();
+        self.repo
+            .diff_tree_to_index(tree.as_ref(), None, None)
+            .map_err(|e| FailedToDiff(e.message().to_string()).into())
+    }
+}
+
+#[derive(Debug, Default)]
+struct ChangedFile {
+    /// 1-based added or modified line numbers
+    lines: HashSet<usize>,
+    /// Content of the file in the index, only read for [Changes::Staged]
+    staged_source: Option<String>,
+}
+
+/// The changes of each changed file, keyed by canonicalized path
+#[derive(Debug, Default)]
+pub struct ChangedFiles(HashMap<PathBuf, ChangedFile>);
+
+impl ChangedFiles {
+    /// The 1-based changed line numbers of the file at `path`, `None` if it is not changed
+    pub fn lines(&self, path: &Path) -> Option<&HashSet<usize>> {
+        self.get(path).map(|file| &file.lines)
+    }
+
+    /// The staged content of the file at `path` with [Changes::Staged]
+    pub fn staged_source(&self, path: &Path) -> Option<&str> {
+        self.get(path)?.staged_source.as_deref()
+    }
+
+    fn get(&self, path: &Path) -> Option<&ChangedFile> {
+        self.0.get(&fs::canonicalize(path).ok()?)
     }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashSet, fs, path::Path};
+
+    use git2::{Repository, Signature};
+
+    use super::{Changes, Git};
+
+    #[test]
+    fn changed_files() {
+        let dir = std::env::temp_dir().join("oxc_cli_git_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let repo = Repository::init(&dir).unwrap();
+        fs::write(dir.join("a.js"), "a\nb\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("a.js")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("oxc", "oxc@example.com").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "init", &tree, &[]).unwrap();
+
+        fs::write(dir.join("a.js"), "a\nc\nd\n").unwrap();
+        fs::write(dir.join("b.js"), "b\n").unwrap();
+
+        let git = Git::discover(&dir).unwrap();
+        let changed_files = git.changed_files(Changes::Since("HEAD")).unwrap();
+        assert_eq!(changed_files.lines(&dir.join("a.js")), Some(&HashSet::from([2, 3])));
+        assert_eq!(changed_files.lines(&dir.join("b.js")), Some(&HashSet::from([1])));
+
+        let changed_files = git.changed_files(Changes::Staged).unwrap();
+        assert_eq!(changed_files.lines(&dir.join("a.js")), None);
+        index.add_path(Path::new("a.js")).unwrap();
+        index.write().unwrap();
+        let changed_files = git.changed_files(Changes::Staged).unwrap();
+        assert_eq!(changed_files.lines(&dir.join("a.js")), Some(&HashSet::from([2, 3])));
+        assert_eq!(changed_files.lines(&dir.join("b.js")), None);
+
+        // The staged content is read from the index, not from the working tree
+        fs::write(dir.join("a.js"), "unstaged\n").unwrap();
+        let changed_files = git.changed_files(Changes::Staged).unwrap();
+        assert_eq!(changed_files.staged_source(&dir.join("a.js")), Some("a\nc\nd\n"));
+        assert_eq!(
+            git.changed_files(Changes::Since("HEAD")).unwrap().staged_source(&dir.join("a.js")),
+            None
+        );
+
+        assert!(git.changed_files(Changes::Since("unknown")).is_err());
 
-    fn is_uncommitted(repo: &Repository, path: &Path) -> bool {
-        repo.status_file(path).map_or(true, |status| !matches!(status, git2::Status::CURRENT))
+        fs::remove_dir_all(dir).unwrap();
     }
 }
@@ -1,5 +1,6 @@
 mod command;
 mod format;
+mod git;
 mod lint;
 mod result;
 mod runner;
@@ -9,6 +10,6 @@ pub use crate::{
     command::*,
     format::FormatRunner,
     lint::LintRunner,
-    result::{CliRunResult, LintResult},
+    result::{CliRunResult, FormatResult, LintResult},
     runner::Runner,
 };
@@ -1,18 +1,35 @@
+mod watch;
+
 use ignore::gitignore::Gitignore;
-use std::{env, io::BufWriter, time::Instant, vec::Vec};
+use std::{
+    collections::HashMap,
+    env,
+    io::{BufWriter, Write},
+    path::Path,
+    sync::Arc,
+    time::Instant,
+    vec::Vec,
+};
 
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_diagnostics::{DiagnosticReporter, DiagnosticService, Error, GraphicalReportHandler};
 use oxc_linter::{
-    partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    partial_loader::LINT_PARTIAL_LOADER_EXT, Baseline, ConfigIgnorePatterns, LintCache,
+    LintOptions, LintService, LintServiceOptions, Linter,
 };
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::{
-    command::{LintOptions as CliLintOptions, OutputFormat, OutputOptions, WarningOptions},
+    command::{
+        BaselineOptions, LintOptions as CliLintOptions, OutputFormat, OutputOptions,
+        WarningOptions, VERSION,
+    },
+    git::{Changes, Git},
     walk::{Extensions, Walk},
     CliRunResult, LintResult, Runner,
 };
 
+use self::watch::Watch;
+
 pub struct LintRunner {
     options: CliLintOptions,
 }
@@ -27,7 +44,25 @@ impl Runner for LintRunner {
     fn run(self) -> CliRunResult {
         if self.options.list_rules {
             let mut stdout = BufWriter::new(std::io::stdout());
-            Linter::print_rules(&mut stdout);
+            match self.options.output_options.format {
+                OutputFormat::Default => Linter::print_rules(&mut stdout),
+                OutputFormat::Json => Linter::print_rules_json(&mut stdout),
+                _ => {
+                    return CliRunResult::InvalidOptions {
+                        message: "--rules only supports --format default and json".to_string(),
+                    }
+                }
+            }
+            return CliRunResult::None;
+        }
+
+        if let Some(name) = &self.options.explain {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            if !Linter::print_rule_documentation(&mut stdout, name) {
+                return CliRunResult::InvalidOptions {
+                    message: format!("{name:?} is not a rule, see --rules for the rule names"),
+                };
+            }
             return CliRunResult::None;
         }
 
@@ -41,10 +76,75 @@ impl Runner for LintRunner {
             config,
             tsconfig,
             output_options,
+            cache_options,
+            git_options,
+            baseline_options,
+            stdin_options,
+            watch,
             ..
         } = self.options;
 
+        let changes = match git_options.changes() {
+            Ok(changes) => changes,
+            Err(message) => return CliRunResult::InvalidOptions { message: message.to_string() },
+        };
+
+        // Files fixed or linted only in part would be linted again with stale results in watch mode
+        if watch
+            && (!fix_options.fix_kind().is_empty()
+                || cache_options.cache
+                || changes.is_some()
+                || baseline_options.baseline.is_some()
+                || baseline_options.write_baseline.is_some()
+                || output_options.format != OutputFormat::Default)
+        {
+            return CliRunResult::InvalidOptions {
+                message: "--watch cannot be used with --fix, --cache, --changed-since, --staged, \
+                          --baseline, --write-baseline or --format"
+                    .to_string(),
+            };
+        }
+
+        // The fixed staged content cannot be written back to the index
+        if matches!(changes, Some(Changes::Staged)) && !fix_options.fix_kind().is_empty() {
+            return CliRunResult::InvalidOptions {
+                message: "--staged cannot be used with --fix".to_string(),
+            };
+        }
+
+        let stdin_path = match stdin_options.path() {
+            Ok(path) => path.map(Path::to_path_buf),
+            Err(message) => return CliRunResult::InvalidOptions { message: message.to_string() },
+        };
+        if stdin_path.is_some() && (watch || !paths.is_empty()) {
+            return CliRunResult::InvalidOptions {
+                message: "--stdin cannot be used with --watch or paths".to_string(),
+            };
+        }
+        // The fixed code is printed to stdout, and the diagnostics to stderr
+        let fix_stdin = stdin_path.is_some() && !fix_options.fix_kind().is_empty();
+        if fix_stdin && output_options.format != OutputFormat::Default {
+            return CliRunResult::InvalidOptions {
+                message:
+                    "--stdin with --fix prints the fixed code, it cannot be used with --format"
+                        .to_string(),
+            };
+        }
+        let stdin = if stdin_path.is_some() {
+            match std::io::read_to_string(std::io::stdin()) {
+                Ok(source_text) => Some(source_text),
+                Err(error) => {
+                    return CliRunResult::InvalidOptions {
+                        message: format!("Failed to read stdin: {error}"),
+                    }
+                }
+            }
+        } else {
+            None
+        };
+
         let mut paths = paths;
+        paths.extend(stdin_path);
         let provided_path_count = paths.len();
         let now = Instant::now();
 
@@ -62,9 +162,14 @@ impl Runner for LintRunner {
             // If explicit paths were provided, but all have been
             // filtered, return early.
             if provided_path_count > 0 {
+                // The ignored source text is printed as is
+                if let Some(source_text) = stdin.filter(|_| fix_stdin) {
+                    print!("{source_text}");
+                }
                 return CliRunResult::LintResult(LintResult {
                     duration: now.elapsed(),
                     deny_warnings: warning_options.deny_warnings,
+                    summary_to_stderr: output_options.format != OutputFormat::Default || fix_stdin,
                     ..LintResult::default()
                 });
             }
@@ -84,39 +189,134 @@ impl Runner for LintRunner {
             .copied()
             .collect::<Vec<&'static str>>();
 
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
-
-        let number_of_files = paths.len();
-
+        let extensions = Extensions(extensions);
+        let roots = paths;
         let cwd = std::env::current_dir().unwrap().into_boxed_path();
+        // Look up the nearest configuration file of each file when none is provided
+        let config_discovery = config.is_none();
         let lint_options = LintOptions::default()
             .with_filter(filter)
             .with_config_path(config)
-            .with_fix(fix_options.fix)
+            .with_fix(fix_options.fix_kind())
+            .with_report_fixes(output_options.format == OutputFormat::Json)
+            .with_report_unused_directives(warning_options.report_unused_disable_directives)
             .with_import_plugin(enable_plugins.import_plugin)
             .with_jest_plugin(enable_plugins.jest_plugin)
             .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
             .with_nextjs_plugin(enable_plugins.nextjs_plugin)
-            .with_react_perf_plugin(enable_plugins.react_perf_plugin);
+            .with_react_perf_plugin(enable_plugins.react_perf_plugin)
+            .with_timing(output_options.timing);
+
+        if watch {
+            return Watch {
+                paths: roots,
+                ignore_options,
+                extensions,
+                lint_options,
+                tsconfig,
+                config_discovery,
+                warning_options,
+                output_options,
+            }
+            .run();
+        }
 
         let linter = match Linter::from_options(lint_options) {
             Ok(lint_service) => lint_service,
             Err(diagnostic) => {
-                let handler = GraphicalReportHandler::new();
-                let mut err = String::new();
-                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
-                eprintln!("{err}");
+                Self::print_error(&diagnostic);
                 return CliRunResult::InvalidOptions {
                     message: "Failed to parse configuration file.".to_string(),
                 };
             }
         };
 
-        let options = LintServiceOptions { cwd, paths, tsconfig };
+        // Files matched by `ignorePatterns` of the configuration files are not read
+        let config_ignore_patterns =
+            Arc::new(ConfigIgnorePatterns::new(&linter, &cwd, config_discovery));
+        // The file of the source text from stdin may not exist
+        let mut paths = if stdin.is_some() {
+            roots
+                .iter()
+                .filter(|path| !config_ignore_patterns.is_ignored(path))
+                .map(|path| path.clone().into_boxed_path())
+                .collect()
+        } else {
+            Walk::new(&roots, &ignore_options)
+                .with_extensions(extensions.clone())
+                .with_config_ignore_patterns(config_ignore_patterns)
+                .paths()
+        };
+
+        let mut changed_lines = None;
+        let mut staged = HashMap::new();
+        if let Some(changes) = changes {
+            let cwd = env::current_dir().unwrap();
+            let changed_files = match Git::discover(&cwd).and_then(|git| git.changed_files(changes))
+            {
+                Ok(changed_files) => changed_files,
+                Err(diagnostic) => {
+                    Self::print_error(&diagnostic);
+                    return CliRunResult::InvalidOptions {
+                        message: "Failed to read changes from Git.".to_string(),
+                    };
+                }
+            };
+            paths.retain(|path| changed_files.lines(path).is_some());
+            staged = paths
+                .iter()
+                .filter_map(|path| Some((path.clone(), changed_files.staged_source(path)?.into())))
+                .collect();
+            if git_options.only_changed_lines {
+                changed_lines = Some(
+                    paths
+                        .iter()
+                        .filter_map(|path| Some((path.clone(), changed_files.lines(path)?.clone())))
+                        .collect(),
+                );
+            }
+        }
+
+        let number_of_files = paths.len();
+
+        let cache =
+            cache_options.cache.then(|| LintCache::new(cache_options.cache_location, VERSION));
+        let baseline = match baseline_options {
+            BaselineOptions { baseline: Some(_), write_baseline: Some(_) } => {
+                return CliRunResult::InvalidOptions {
+                    message: "--baseline and --write-baseline cannot be used together".to_string(),
+                };
+            }
+            BaselineOptions { baseline: Some(path), .. } => match Baseline::read(path) {
+                Ok(baseline) => Some(baseline),
+                Err(diagnostic) => {
+                    Self::print_error(&diagnostic);
+                    return CliRunResult::InvalidOptions {
+                        message: "Failed to read baseline file.".to_string(),
+                    };
+                }
+            },
+            BaselineOptions { write_baseline: Some(path), .. } => Some(Baseline::write(path)),
+            BaselineOptions { baseline: None, write_baseline: None } => None,
+        };
+
+        let options = LintServiceOptions {
+            cwd,
+            paths,
+            tsconfig,
+            config_discovery,
+            cache,
+            changed_lines,
+            baseline,
+            stdin: stdin.clone(),
+            staged,
+        };
         let lint_service = LintService::new(linter, options);
         let mut diagnostic_service =
             Self::get_diagnostic_service(&warning_options, &output_options);
+        if fix_stdin {
+            diagnostic_service.set_reporter(DiagnosticReporter::new_graphical_stderr());
+        }
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
@@ -128,6 +328,23 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
+        if output_options.timing {
+            let timings = lint_service.linter().timings();
+            // Keep the output of machine readable formats parseable
+            if output_options.format == OutputFormat::Default && !fix_stdin {
+                timings.print(&mut BufWriter::new(std::io::stdout()));
+            } else {
+                timings.print(&mut BufWriter::new(std::io::stderr()));
+            }
+        }
+
+        if let Some(source_text) = stdin.filter(|_| fix_stdin) {
+            let code = lint_service.fixed_stdin().unwrap_or(source_text);
+            let mut stdout = std::io::stdout().lock();
+            stdout.write_all(code.as_bytes()).unwrap();
+            stdout.flush().unwrap();
+        }
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
@@ -136,11 +353,19 @@ impl Runner for LintRunner {
             number_of_errors: diagnostic_service.errors_count(),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
             deny_warnings: warning_options.deny_warnings,
+            summary_to_stderr: output_options.format != OutputFormat::Default || fix_stdin,
         })
     }
 }
 
 impl LintRunner {
+    fn print_error(diagnostic: &Error) {
+        let handler = GraphicalReportHandler::new();
+        let mut err = String::new();
+        handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+        eprintln!("{err}");
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
@@ -149,10 +374,15 @@ impl LintRunner {
             .with_quiet(warning_options.quiet)
             .with_max_warnings(warning_options.max_warnings);
 
-        match output_options.format {
-            OutputFormat::Default => {}
-            OutputFormat::Json => diagnostic_service.set_json_reporter(),
-        }
+        let reporter = match output_options.format {
+            OutputFormat::Default => DiagnosticReporter::new_graphical(),
+            OutputFormat::Json => DiagnosticReporter::new_json(),
+            OutputFormat::Sarif => DiagnosticReporter::new_sarif(),
+            OutputFormat::Junit => DiagnosticReporter::new_junit(),
+            OutputFormat::Checkstyle => DiagnosticReporter::new_checkstyle(),
+            OutputFormat::Github => DiagnosticReporter::new_github(),
+        };
+        diagnostic_service.set_reporter(reporter);
 
         diagnostic_service
     }
@@ -297,8 +527,8 @@ mod test {
             &["-c", "fixtures/eslintrc_env/eslintrc_no_env.json", "fixtures/eslintrc_env/test.js"];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
-        assert_eq!(result.number_of_warnings, 1);
-        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
     }
 
     #[test]
@@ -340,8 +570,8 @@ mod test {
         ];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
-        assert_eq!(result.number_of_warnings, 1);
-        assert_eq!(result.number_of_errors, 0);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
     }
 
     #[test]
@@ -363,8 +593,302 @@ mod test {
         ];
         let result = test(args);
         assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn eslintrc_severity() {
+        let args = &["-c", "fixtures/severity/eslintrc.json", "fixtures/severity/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
         assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn cli_severity() {
+        // `-W` downgrades the rule the configuration file sets to "error"
+        let args = &[
+            "-W",
+            "no-debugger",
+            "-c",
+            "fixtures/severity/eslintrc.json",
+            "fixtures/severity/test.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
         assert_eq!(result.number_of_errors, 0);
+
+        let args = &["-D", "correctness", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn output_formats() {
+        for format in ["json", "sarif", "junit", "checkstyle", "github"] {
+            let args = &[
+                "--format",
+                format,
+                "-c",
+                "fixtures/severity/eslintrc.json",
+                "fixtures/severity/test.js",
+            ];
+            let result = test(args);
+            assert_eq!(result.number_of_files, 1);
+            assert_eq!(result.number_of_warnings, 1);
+            assert_eq!(result.number_of_errors, 1);
+        }
+    }
+
+    #[test]
+    fn timing() {
+        let args = &["--timing", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn watch_with_fix() {
+        let options = lint_command()
+            .run_inner(&["--watch", "--fix", "fixtures/linter/debugger.js"])
+            .unwrap()
+            .lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn explain() {
+        let options = lint_command().run_inner(&["--explain", "no-debugger"]).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::None));
+        let options =
+            lint_command().run_inner(&["--explain", "no-such-rule"]).unwrap().lint_options;
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn stdin_with_paths() {
+        let options = lint_command()
+            .run_inner(&["--stdin", "--stdin-filename", "a.js", "fixtures/linter/debugger.js"])
+            .unwrap()
+            .lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn stdin_fix_with_format() {
+        let options = lint_command()
+            .run_inner(&["--stdin", "--stdin-filename", "a.js", "--fix", "--format", "json"])
+            .unwrap()
+            .lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn staged_with_fix() {
+        let options = lint_command().run_inner(&["--staged", "--fix"]).unwrap().lint_options;
+        let result = LintRunner::new(options).run();
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn cache() {
+        let cache_location = std::env::temp_dir().join("oxlint_cache_test");
+        let _ = std::fs::remove_file(&cache_location);
+        let args = &[
+            "--cache",
+            "--cache-location",
+            cache_location.to_str().unwrap(),
+            "-c",
+            "fixtures/severity/eslintrc.json",
+            "fixtures/severity/test.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+
+        // The second run reads the diagnostics from the cache, which are changed to tell them apart
+        let cached = std::fs::read_to_string(&cache_location).unwrap();
+        assert!(cached.contains(r#""severity":"Error""#));
+        let cached = cached.replace(r#""severity":"Error""#, r#""severity":"Warning""#);
+        std::fs::write(&cache_location, cached).unwrap();
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+        std::fs::remove_file(cache_location).unwrap();
+    }
+
+    #[test]
+    fn eslintrc_overrides() {
+        let args = &["-c", "fixtures/overrides/eslintrc.json", "fixtures/overrides"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn eslintrc_extends() {
+        let args = &["-c", "fixtures/extends/eslintrc.json", "fixtures/extends/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn eslintrc_discovery() {
+        let args = &["fixtures/config_discovery"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn eslintrc_discovery_disabled_by_config() {
+        // The nested configuration file is not used with `-c`
+        let args = &["-c", "fixtures/config_discovery/.eslintrc.json", "fixtures/config_discovery"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn eslintrc_globals() {
+        let args = &["-c", "fixtures/eslintrc_globals/eslintrc.json", "fixtures/eslintrc_globals"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn eslintrc_ignore_patterns() {
+        let args = &["fixtures/ignore_patterns"];
+        let result = test(args);
+        // Ignored files are skipped while walking the directories
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn eslintrc_ignore_patterns_with_config() {
+        let args = &["-c", "fixtures/ignore_patterns/.eslintrc.json", "fixtures/ignore_patterns"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn oxlintrc() {
+        let args = &["-c", "fixtures/oxlintrc/.oxlintrc.json", "fixtures/oxlintrc"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn oxlintrc_discovery() {
+        let args = &["fixtures/oxlintrc"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn fix_in_multiple_passes() {
+        // The fixes of `eqeqeq` and `valid-typeof` touch each other and are applied in separate passes
+        let dir = std::env::temp_dir().join("oxlint_fix_in_multiple_passes");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test.js");
+        std::fs::copy("fixtures/fix/test.js", &path).unwrap();
+
+        let args = &["--fix", "-c", "fixtures/fix/eslintrc.json", path.to_str().unwrap()];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+        let fixed = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(fixed, "\nif (typeof foo === \"undefined\") {\n}\n");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let args = &["fixtures/unused_directives"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+
+        let args = &["--report-unused-disable-directives", "fixtures/unused_directives"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn fix_unused_disable_directives() {
+        let dir = std::env::temp_dir().join("oxlint_fix_unused_disable_directives");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test.js");
+        std::fs::copy("fixtures/unused_directives/test.js", &path).unwrap();
+
+        let args = &["--report-unused-disable-directives", "--fix", path.to_str().unwrap()];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        let fixed = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            fixed,
+            "// eslint-disable-next-line no-debugger\ndebugger;\n\n// eslint-disable-next-line no-debugger\ndebugger;\n\n\nfoo();\n"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fix_suggestions() {
+        // `no-prototype-builtins` only offers suggestions, `--fix` leaves them alone
+        let dir = std::env::temp_dir().join("oxlint_fix_suggestions");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test.js");
+        std::fs::copy("fixtures/fix_suggestions/test.js", &path).unwrap();
+
+        let args =
+            &["-W", "correctness", "-W", "no-prototype-builtins", "--fix", path.to_str().unwrap()];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "\nfoo.hasOwnProperty(bar);\n");
+
+        // `--fix-suggestions` applies the safe fixes as well
+        std::fs::copy("fixtures/fix_suggestions/test.js", &path).unwrap();
+        let args = &[
+            "-W",
+            "correctness",
+            "-W",
+            "no-prototype-builtins",
+            "--fix-suggestions",
+            path.to_str().unwrap(),
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "\nObject.prototype.hasOwnProperty.call(foo, bar);\n"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 
     #[test]
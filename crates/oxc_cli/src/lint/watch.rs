@@ -0,0 +1,306 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsStr,
+    fs,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    sync::{mpsc, Arc},
+    time::{Duration, Instant},
+};
+
+use notify::{RecursiveMode, Watcher};
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult};
+use oxc_diagnostics::DiagnosticService;
+use oxc_linter::{
+    ConfigIgnorePatterns, LintOptions, LintService, LintServiceOptions, Linter, CONFIG_FILE_NAMES,
+};
+
+use crate::{
+    command::{OutputOptions, WarningOptions},
+    walk::{Extensions, Walk},
+    CliRunResult, IgnoreOptions, LintResult,
+};
+
+use super::LintRunner;
+
+/// Changes within this time are linted together
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// Files which change the module resolution of the linted files besides `tsconfig*.json`
+const RESOLUTION_FILE_NAMES: [&str; 2] = ["package.json", "jsconfig.json"];
+
+/// Lints the files once, then keeps the [LintService] alive and lints the files affected by each change again.
+pub(super) struct Watch {
+    /// Paths walked for the files to lint, also the paths watched for changes
+    pub paths: Vec<PathBuf>,
+    pub ignore_options: IgnoreOptions,
+    pub extensions: Extensions,
+    pub lint_options: LintOptions,
+    pub tsconfig: Option<PathBuf>,
+    pub config_discovery: bool,
+    pub warning_options: WarningOptions,
+    pub output_options: OutputOptions,
+}
+
+impl Watch {
+    pub(super) fn run(self) -> CliRunResult {
+        let cwd = std::env::current_dir().unwrap().into_boxed_path();
+        let (tx, rx) = mpsc::channel::<DebounceEventResult>();
+        let mut debouncer = match new_debouncer(DEBOUNCE_TIMEOUT, tx) {
+            Ok(debouncer) => debouncer,
+            Err(error) => {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to watch files: {error}"),
+                }
+            }
+        };
+        // Event paths are absolute when the watched paths are
+        let watched_paths = self
+            .paths
+            .iter()
+            .map(|path| fs::canonicalize(path).unwrap_or_else(|_| cwd.join(path)))
+            .collect::<Vec<_>>();
+        for path in &watched_paths {
+            if let Err(error) = debouncer.watcher().watch(path, RecursiveMode::Recursive) {
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to watch {path:?}: {error}"),
+                };
+            }
+        }
+        let mut config_dirs = HashSet::new();
+
+        let mut diagnostic_service =
+            LintRunner::get_diagnostic_service(&self.warning_options, &self.output_options)
+                .with_retain(true);
+        let (mut lint_service, mut config_ignore_patterns) = match self.lint_service(&cwd) {
+            Ok(lint_service) => lint_service,
+            Err(result) => return result,
+        };
+        let mut paths = self.walk(&config_ignore_patterns);
+        lint_service.set_paths(paths.clone());
+        self.lint(&lint_service, &mut diagnostic_service, paths.clone());
+        self.watch_config_dirs(
+            debouncer.watcher(),
+            &lint_service,
+            &watched_paths,
+            &mut config_dirs,
+        );
+
+        loop {
+            let changed = match rx.recv() {
+                Ok(Ok(events)) => events.into_iter().map(|event| event.path).collect::<Vec<_>>(),
+                Ok(Err(error)) => {
+                    eprintln!("Failed to watch files: {error}");
+                    continue;
+                }
+                // The watcher is dropped
+                Err(_) => return CliRunResult::None,
+            };
+
+            // Only the changed files and their importers are linted again, unless a configuration changed
+            let config_files = lint_service.config_files().into_iter().collect::<HashSet<_>>();
+            let affected = if changed.iter().any(|path| self.is_config(path, &config_files)) {
+                let Ok((new_lint_service, new_config_ignore_patterns)) = self.lint_service(&cwd)
+                else {
+                    eprintln!("Watching for file changes...");
+                    continue;
+                };
+                lint_service = new_lint_service;
+                config_ignore_patterns = new_config_ignore_patterns;
+                let new_paths = self.walk(&config_ignore_patterns);
+                lint_service.set_paths(new_paths.clone());
+                Self::forget(&mut diagnostic_service, &cwd, &paths);
+                paths = new_paths.clone();
+                new_paths
+            } else {
+                // Files may be created, deleted or no longer ignored
+                let new_paths = self.walk(&config_ignore_patterns);
+                let absolute_paths = new_paths
+                    .iter()
+                    .map(|path| (cwd.join(path), path))
+                    .collect::<HashMap<PathBuf, &Box<Path>>>();
+                let old_paths = paths.iter().collect::<HashSet<_>>();
+                let removed = paths
+                    .iter()
+                    .filter(|path| !absolute_paths.contains_key(&cwd.join(path)))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let added = new_paths.iter().filter(|path| !old_paths.contains(path)).cloned();
+                lint_service.set_paths(new_paths.clone());
+                // Changed files are matched by the path they are linted with and by their absolute path as a dependency
+                let changed = changed
+                    .iter()
+                    .flat_map(|path| {
+                        let linted_path = absolute_paths.get(path).map(|path| path.to_path_buf());
+                        std::iter::once(path.clone()).chain(linted_path)
+                    })
+                    .collect::<Vec<_>>();
+                let mut affected = lint_service.invalidate(&changed);
+                let invalidated = affected.iter().cloned().collect::<HashSet<_>>();
+                affected.extend(added.filter(|path| !invalidated.contains(path)));
+                Self::forget(&mut diagnostic_service, &cwd, &removed);
+                Self::forget(&mut diagnostic_service, &cwd, &affected);
+                paths = new_paths;
+                // Nothing to redraw
+                if affected.is_empty() && removed.is_empty() {
+                    continue;
+                }
+                affected
+            };
+            self.lint(&lint_service, &mut diagnostic_service, affected);
+            self.watch_config_dirs(
+                debouncer.watcher(),
+                &lint_service,
+                &watched_paths,
+                &mut config_dirs,
+            );
+        }
+    }
+
+    /// Watch the directories outside of `watched_paths` which contain configuration files:
+    /// the files given by `--config` and `--tsconfig`, the files extended by the configuration files,
+    /// and with config discovery, the ancestors of the watched paths up to the project root.
+    /// Directories are watched instead of files so that files replaced by editors are still watched.
+    fn watch_config_dirs(
+        &self,
+        watcher: &mut dyn Watcher,
+        lint_service: &LintService,
+        watched_paths: &[PathBuf],
+        config_dirs: &mut HashSet<PathBuf>,
+    ) {
+        let config_paths = [&self.lint_options.config_path, &self.tsconfig]
+            .into_iter()
+            .flatten()
+            .filter_map(|path| fs::canonicalize(path).ok())
+            .chain(lint_service.config_files());
+        let mut dirs = config_paths
+            .filter_map(|path| path.parent().map(Path::to_path_buf))
+            .collect::<Vec<_>>();
+        if self.config_discovery {
+            let project_root = lint_service.project_root();
+            for path in watched_paths {
+                for dir in path.ancestors().skip(1) {
+                    dirs.push(dir.to_path_buf());
+                    if dir == project_root || dir.join(".git").exists() {
+                        break;
+                    }
+                }
+            }
+        }
+        for dir in dirs {
+            // Watching a directory again would replace its recursive watch
+            if watched_paths.iter().any(|path| dir.starts_with(path)) || config_dirs.contains(&dir)
+            {
+                continue;
+            }
+            if let Err(error) = watcher.watch(&dir, RecursiveMode::NonRecursive) {
+                eprintln!("Failed to watch {dir:?}: {error}");
+            }
+            config_dirs.insert(dir);
+        }
+    }
+
+    fn walk(&self, config_ignore_patterns: &Arc<ConfigIgnorePatterns>) -> Vec<Box<Path>> {
+        Walk::new(&self.paths, &self.ignore_options)
+            .with_extensions(self.extensions.clone())
+            .with_config_ignore_patterns(Arc::clone(config_ignore_patterns))
+            .paths()
+    }
+
+    /// A new service with the current configuration files and no paths to lint,
+    /// with the `ignorePatterns` for walking the paths,
+    /// or `Err` with the reason it cannot be created
+    fn lint_service(
+        &self,
+        cwd: &Path,
+    ) -> Result<(LintService, Arc<ConfigIgnorePatterns>), CliRunResult> {
+        let linter = match Linter::from_options(self.lint_options.clone()) {
+            Ok(linter) => linter,
+            Err(diagnostic) => {
+                LintRunner::print_error(&diagnostic);
+                return Err(CliRunResult::InvalidOptions {
+                    message: "Failed to parse configuration file.".to_string(),
+                });
+            }
+        };
+        let config_ignore_patterns =
+            Arc::new(ConfigIgnorePatterns::new(&linter, cwd, self.config_discovery));
+        let options = LintServiceOptions {
+            cwd: cwd.to_path_buf().into_boxed_path(),
+            paths: vec![],
+            tsconfig: self.tsconfig.clone(),
+            config_discovery: self.config_discovery,
+            cache: None,
+            changed_lines: None,
+            baseline: None,
+            stdin: None,
+            staged: HashMap::default(),
+        };
+        Ok((LintService::new(linter, options), config_ignore_patterns))
+    }
+
+    /// Whether a change of `path` may change the rules or module resolution of the linted files:
+    /// a configuration file by its name, one of the `config_files` read by the linter,
+    /// the file given by `--config` or `--tsconfig`, or a file affecting the module resolution.
+    fn is_config(&self, path: &Path, config_files: &HashSet<PathBuf>) -> bool {
+        let is_config_path = |config_path: &Option<PathBuf>| {
+            config_path
+                .as_ref()
+                .and_then(|config_path| fs::canonicalize(config_path).ok())
+                .as_deref()
+                == Some(path)
+        };
+        let is_tsconfig = path.extension().is_some_and(|ext| ext == "json")
+            && path
+                .file_stem()
+                .and_then(OsStr::to_str)
+                .is_some_and(|stem| stem.starts_with("tsconfig"));
+        path.file_name().and_then(OsStr::to_str).is_some_and(|name| {
+            CONFIG_FILE_NAMES.contains(&name) || RESOLUTION_FILE_NAMES.contains(&name)
+        }) || is_tsconfig
+            || config_files.contains(path)
+            || is_config_path(&self.lint_options.config_path)
+            || is_config_path(&self.tsconfig)
+    }
+
+    /// Forget the reported diagnostics of `paths`, which are reported relative to `cwd`
+    fn forget(diagnostic_service: &mut DiagnosticService, cwd: &Path, paths: &[Box<Path>]) {
+        diagnostic_service.forget(paths.iter().map(|path| path.strip_prefix(cwd).unwrap_or(path)));
+    }
+
+    /// Lint `paths`, then redraw the diagnostics of all files and the summary
+    fn lint(
+        &self,
+        lint_service: &LintService,
+        diagnostic_service: &mut DiagnosticService,
+        paths: Vec<Box<Path>>,
+    ) {
+        let now = Instant::now();
+        let number_of_files = paths.len();
+        rayon::spawn({
+            let tx_error = diagnostic_service.sender().clone();
+            let lint_service = lint_service.clone();
+            move || {
+                lint_service.run_paths(&paths, &tx_error);
+            }
+        });
+        // Clear the screen and move the cursor to the top left corner
+        if std::io::stdout().is_terminal() {
+            print!("\x1B[2J\x1B[1;1H");
+        }
+        diagnostic_service.run();
+
+        LintResult {
+            duration: now.elapsed(),
+            number_of_rules: lint_service.linter().number_of_rules(),
+            number_of_files,
+            number_of_warnings: diagnostic_service.warnings_count(),
+            number_of_errors: diagnostic_service.errors_count(),
+            max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
+            deny_warnings: self.warning_options.deny_warnings,
+            summary_to_stderr: false,
+        }
+        .print_summary();
+        println!("Watching for file changes...");
+    }
+}
@@ -1,9 +1,12 @@
 use std::{
+    fmt::Write,
     path::PathBuf,
     process::{ExitCode, Termination},
     time::Duration,
 };
 
+use crate::command::FormatMode;
+
 #[derive(Debug)]
 pub enum CliRunResult {
     None,
@@ -23,12 +26,72 @@ pub struct LintResult {
     pub number_of_errors: usize,
     pub max_warnings_exceeded: bool,
     pub deny_warnings: bool,
+    /// The diagnostics are printed in a machine readable format,
+    /// write the summary to stderr so it does not end up in the report
+    pub summary_to_stderr: bool,
+}
+
+impl LintResult {
+    /// Print the number of files, warnings and errors, returns the exit code of the run
+    pub fn print_summary(&self) -> u8 {
+        let Self {
+            duration,
+            number_of_rules,
+            number_of_files,
+            number_of_warnings,
+            number_of_errors,
+            max_warnings_exceeded,
+            deny_warnings,
+            summary_to_stderr,
+        } = *self;
+        let threads = rayon::current_num_threads();
+        let number_of_diagnostics = number_of_warnings + number_of_errors;
+
+        let mut summary = String::new();
+        if number_of_diagnostics > 0 {
+            summary.push('\n');
+        }
+
+        let time = CliRunResult::get_execution_time(&duration);
+        let s = if number_of_files == 1 { "" } else { "s" };
+        writeln!(
+            summary,
+            "Finished in {time} on {number_of_files} file{s} with {number_of_rules} rules using {threads} threads."
+        ).unwrap();
+
+        let exit_code = if max_warnings_exceeded {
+            writeln!(summary, "Exceeded maximum number of warnings. Found {number_of_warnings}.")
+                .unwrap();
+            1
+        } else {
+            writeln!(
+                summary,
+                "Found {number_of_warnings} warning{} and {number_of_errors} error{}.",
+                if number_of_warnings == 1 { "" } else { "s" },
+                if number_of_errors == 1 { "" } else { "s" }
+            )
+            .unwrap();
+            u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0)
+        };
+
+        if summary_to_stderr {
+            eprint!("{summary}");
+        } else {
+            print!("{summary}");
+        }
+        exit_code
+    }
 }
 
 #[derive(Debug)]
 pub struct FormatResult {
     pub duration: Duration,
     pub number_of_files: usize,
+    pub mode: FormatMode,
+    /// Number of files which are not formatted, or written in [FormatMode::Write]
+    pub number_of_unformatted: usize,
+    /// Number of files which cannot be read, parsed or written
+    pub number_of_errors: usize,
 }
 
 impl Termination for CliRunResult {
@@ -43,51 +106,36 @@ impl Termination for CliRunResult {
                 println!("Path {paths:?} does not exist.");
                 ExitCode::from(1)
             }
-            Self::LintResult(LintResult {
+            Self::LintResult(result) => ExitCode::from(result.print_summary()),
+            Self::FormatResult(FormatResult {
                 duration,
-                number_of_rules,
                 number_of_files,
-                number_of_warnings,
+                mode,
+                number_of_unformatted,
                 number_of_errors,
-                max_warnings_exceeded,
-                deny_warnings,
             }) => {
-                let threads = rayon::current_num_threads();
-                let number_of_diagnostics = number_of_warnings + number_of_errors;
-
-                if number_of_diagnostics > 0 {
-                    println!();
-                }
-
-                let time = Self::get_execution_time(&duration);
-                let s = if number_of_files == 1 { "" } else { "s" };
-                println!(
-                    "Finished in {time} on {number_of_files} file{s} with {number_of_rules} rules using {threads} threads."
-                );
-
-                if max_warnings_exceeded {
-                    println!("Exceeded maximum number of warnings. Found {number_of_warnings}.");
-                    return ExitCode::from(1);
+                match mode {
+                    // Only the formatted code or the paths are printed to stdout
+                    FormatMode::Stdout | FormatMode::ListDifferent => {}
+                    FormatMode::Write => {
+                        let threads = rayon::current_num_threads();
+                        let time = Self::get_execution_time(&duration);
+                        let s = if number_of_files == 1 { "" } else { "s" };
+                        println!(
+                            "Finished in {time} on {number_of_files} file{s} using {threads} threads."
+                        );
+                    }
+                    FormatMode::Check if number_of_unformatted > 0 => {
+                        let s = if number_of_unformatted == 1 { "" } else { "s" };
+                        eprintln!(
+                            "[warn] Code style issues found in {number_of_unformatted} file{s}. Run with --write to fix."
+                        );
+                    }
+                    FormatMode::Check => println!("All matched files use the correct format."),
                 }
-
-                println!(
-                    "Found {number_of_warnings} warning{} and {number_of_errors} error{}.",
-                    if number_of_warnings == 1 { "" } else { "s" },
-                    if number_of_errors == 1 { "" } else { "s" }
-                );
-
-                let exit_code =
-                    u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0);
-                ExitCode::from(exit_code)
-            }
-            Self::FormatResult(FormatResult { duration, number_of_files }) => {
-                let threads = rayon::current_num_threads();
-                let time = Self::get_execution_time(&duration);
-                let s = if number_of_files == 1 { "" } else { "s" };
-                println!(
-                    "Finished in {time} on {number_of_files} file{s} using {threads} threads."
-                );
-                ExitCode::from(0)
+                let unformatted = number_of_unformatted > 0
+                    && matches!(mode, FormatMode::Check | FormatMode::ListDifferent);
+                ExitCode::from(u8::from(unformatted || number_of_errors > 0))
             }
             Self::TypeCheckResult { duration, number_of_diagnostics } => {
                 let time = Self::get_execution_time(&duration);
@@ -1,9 +1,10 @@
 use std::{
     path::{Path, PathBuf},
-    sync::mpsc,
+    sync::{mpsc, Arc},
 };
 
 use ignore::{overrides::OverrideBuilder, DirEntry};
+use oxc_linter::ConfigIgnorePatterns;
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::IgnoreOptions;
@@ -21,11 +22,14 @@ pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    /// `ignorePatterns` of the lint configuration files
+    config_ignore_patterns: Option<Arc<ConfigIgnorePatterns>>,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Box<Path>>>,
     extensions: Extensions,
+    config_ignore_patterns: Option<Arc<ConfigIgnorePatterns>>,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -34,6 +38,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            config_ignore_patterns: self.config_ignore_patterns.clone(),
         })
     }
 }
@@ -42,6 +47,7 @@ struct WalkCollector {
     paths: Vec<Box<Path>>,
     sender: mpsc::Sender<Vec<Box<Path>>>,
     extensions: Extensions,
+    config_ignore_patterns: Option<Arc<ConfigIgnorePatterns>>,
 }
 
 impl Drop for WalkCollector {
@@ -57,6 +63,10 @@ impl ignore::ParallelVisitor for WalkCollector {
             Ok(entry) => {
                 if entry.file_type().is_some_and(|ft| !ft.is_dir())
                     && Walk::is_wanted_entry(&entry, &self.extensions)
+                    && !self
+                        .config_ignore_patterns
+                        .as_ref()
+                        .is_some_and(|patterns| patterns.is_ignored(entry.path()))
                 {
                     self.paths.push(entry.path().to_path_buf().into_boxed_path());
                 }
@@ -105,12 +115,16 @@ impl Walk {
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
         let inner = inner.ignore(false).git_global(false).follow_links(false).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        Self { inner, extensions: Extensions::default(), config_ignore_patterns: None }
     }
 
     pub fn paths(self) -> Vec<Box<Path>> {
         let (sender, receiver) = mpsc::channel::<Vec<Box<Path>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder = WalkBuilder {
+            sender,
+            extensions: self.extensions,
+            config_ignore_patterns: self.config_ignore_patterns,
+        };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
@@ -121,6 +135,15 @@ impl Walk {
         self
     }
 
+    /// Skip the files matched by `ignorePatterns` of the lint configuration files
+    pub fn with_config_ignore_patterns(
+        mut self,
+        config_ignore_patterns: Arc<ConfigIgnorePatterns>,
+    ) -> Self {
+        self.config_ignore_patterns = Some(config_ignore_patterns);
+        self
+    }
+
     fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
@@ -0,0 +1,53 @@
+//! Source text piped to `oxlint --stdin`
+
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+fn oxlint(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_oxlint"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn diagnostics() {
+    let output =
+        oxlint(&["--stdin", "--stdin-filename", "src/a.ts"], "let a: number = 1;\ndebugger;\n");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("eslint(no-debugger)"), "{stdout}");
+    // The path is highlighted
+    assert!(stdout.contains("src/a.ts") && stdout.contains(":2:1]"), "{stdout}");
+    assert!(stdout.contains("Found 1 warning and 0 errors."), "{stdout}");
+}
+
+#[test]
+fn syntax_error() {
+    let output = oxlint(&["--stdin", "--stdin-filename", "a.js"], "let <\n");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.code(), Some(1), "{stdout}");
+    assert!(stdout.contains("Found 0 warnings and 1 error."), "{stdout}");
+}
+
+#[test]
+fn fix() {
+    let source_text = "debugger;\nlet a = 1;\n";
+    let output = oxlint(&["--stdin", "--stdin-filename", "a.js", "--fix"], source_text);
+    // Only the fixed code is printed to stdout, the summary is printed to stderr
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "\nlet a = 1;\n");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Found 0 warnings and 0 errors."), "{stderr}");
+
+    // The source text is printed as is when there is nothing to fix
+    let source_text = "let a = 1;\n";
+    let output = oxlint(&["--stdin", "--stdin-filename", "a.js", "--fix"], source_text);
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), source_text);
+}
@@ -6,8 +6,9 @@ mod graphical_theme;
 mod reporter;
 mod service;
 
-use std::path::PathBuf;
+use std::{fmt, ops::Range, path::PathBuf};
 
+pub use crate::reporter::DiagnosticReporter;
 pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
 pub use graphic_reporter::{GraphicalReportHandler, GraphicalTheme};
 pub use miette;
@@ -19,7 +20,7 @@ pub type Report = miette::Report;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
-use miette::Diagnostic;
+use miette::{Diagnostic, LabeledSpan, SourceCode};
 use thiserror::Error;
 
 #[derive(Debug, Error, Diagnostic)]
@@ -31,3 +32,124 @@ pub struct MinifiedFileError(pub PathBuf);
 #[error("Failed to open file {0:?} with error \"{1}\"")]
 #[diagnostic(help("Failed to open file {0:?} with error \"{1}\""))]
 pub struct FailedToOpenFileError(pub PathBuf, pub std::io::Error);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to write file {0:?} with error \"{1}\"")]
+pub struct FailedToWriteFileError(pub PathBuf, pub std::io::Error);
+
+/// A diagnostic reported by the linter, carrying the rule that reported it,
+/// the severity configured for that rule, and whether `--fix` can fix it
+#[derive(Debug)]
+pub struct LintDiagnostic {
+    error: Error,
+    rule_id: Option<String>,
+    severity: Option<Severity>,
+    fixable: bool,
+    /// Byte range in the source text and its replacement
+    fix: Option<(Range<usize>, String)>,
+}
+
+impl LintDiagnostic {
+    pub fn new(error: Error) -> Self {
+        Self { error, rule_id: None, severity: None, fixable: false, fix: None }
+    }
+
+    /// Set the ESLint rule id, e.g. `no-debugger` or `@typescript-eslint/no-explicit-any`
+    #[must_use]
+    pub fn with_rule_id(mut self, rule_id: String) -> Self {
+        self.rule_id = Some(rule_id);
+        self
+    }
+
+    /// Replace the severity of the wrapped diagnostic
+    #[must_use]
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Mark the diagnostic as fixed by `--fix`,
+    /// counted by `fixableErrorCount` and `fixableWarningCount` of the JSON output
+    #[must_use]
+    pub fn with_fixable(mut self, fixable: bool) -> Self {
+        self.fixable = fixable;
+        self
+    }
+
+    /// Set the fix of the diagnostic, the text replacing the byte `range` of the source text,
+    /// reported as `fix` by the JSON output
+    #[must_use]
+    pub fn with_fix(mut self, range: Range<usize>, text: String) -> Self {
+        self.fix = Some((range, text));
+        self
+    }
+
+    /// Attach source code to the wrapped diagnostic, keeping this wrapper outermost
+    #[must_use]
+    pub fn with_source_code<S: SourceCode + 'static>(self, source: S) -> Self {
+        Self { error: self.error.with_source_code(source), ..self }
+    }
+
+    /// The [LintDiagnostic] of `error`, if it is one
+    pub fn of(error: &Error) -> Option<&Self> {
+        error.downcast_ref::<Self>()
+    }
+
+    pub fn rule_id(&self) -> Option<&str> {
+        self.rule_id.as_deref()
+    }
+
+    pub fn is_fixable(&self) -> bool {
+        self.fixable
+    }
+
+    pub fn fix(&self) -> Option<(&Range<usize>, &str)> {
+        self.fix.as_ref().map(|(range, text)| (range, text.as_str()))
+    }
+}
+
+impl fmt::Display for LintDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for LintDiagnostic {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for LintDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.code()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity.or_else(|| self.error.severity())
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
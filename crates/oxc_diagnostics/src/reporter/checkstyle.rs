@@ -0,0 +1,30 @@
+use std::fmt::Write;
+
+use super::{group_by_path, xml_escape, Info};
+
+/// The same shape as ESLint's `checkstyle` formatter
+pub fn format(diagnostics: &[Info]) -> String {
+    let mut output =
+        String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?><checkstyle version=\"4.3\">");
+    for (path, infos) in group_by_path(diagnostics) {
+        write!(output, "<file name=\"{}\">", xml_escape(path)).unwrap();
+        for info in infos {
+            write!(
+                output,
+                "<error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"{}\" />",
+                info.line,
+                info.column,
+                if info.is_error() { "error" } else { "warning" },
+                xml_escape(&info.message),
+                info.rule_id
+                    .as_deref()
+                    .map(|rule_id| xml_escape(&format!("eslint.rules.{rule_id}")))
+                    .unwrap_or_default(),
+            )
+            .unwrap();
+        }
+        output.push_str("</file>");
+    }
+    output.push_str("</checkstyle>");
+    output
+}
@@ -0,0 +1,25 @@
+use super::Info;
+
+/// <https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message>
+pub fn format(info: &Info) -> String {
+    let command = if info.is_error() { "error" } else { "warning" };
+    let title = info.rule_id.as_deref().unwrap_or("oxlint");
+    format!(
+        "::{command} file={},line={},endLine={},col={},endColumn={},title={}::{}",
+        escape_property(&info.path),
+        info.line,
+        info.end_line,
+        info.column,
+        info.end_column,
+        escape_property(title),
+        escape_data(&info.message),
+    )
+}
+
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
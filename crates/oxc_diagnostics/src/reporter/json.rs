@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use super::{group_by_path, Info};
+
+/// <https://eslint.org/docs/latest/use/formatters/#json>
+///
+/// `files` are all the linted files with the source text of the ones with diagnostics,
+/// files which only appear in `diagnostics` are listed after them.
+/// oxlint does not know `nodeType` and `messageId`, they are `null`.
+pub fn format(files: &[(String, Option<String>)], diagnostics: &[Info]) -> String {
+    // Files with several sources, e.g. Vue files with two `<script>`, are received more than once
+    let mut sources: Vec<(&str, Option<&str>)> = vec![];
+    let mut indices: HashMap<&str, usize> = HashMap::new();
+    for (path, source) in files {
+        let index = *indices.entry(path).or_insert_with(|| {
+            sources.push((path, None));
+            sources.len() - 1
+        });
+        sources[index].1 = sources[index].1.or(source.as_deref());
+    }
+    let mut by_path = group_by_path(diagnostics).into_iter().collect::<HashMap<_, _>>();
+    let mut results = sources
+        .into_iter()
+        .map(|(path, source)| result(path, &by_path.remove(path).unwrap_or_default(), source))
+        .collect::<Vec<_>>();
+    // Keep the order the files were received in
+    results.extend(
+        group_by_path(diagnostics)
+            .into_iter()
+            .filter(|(path, _)| by_path.contains_key(path))
+            .map(|(path, infos)| result(path, &infos, None)),
+    );
+    serde_json::to_string_pretty(&results).unwrap()
+}
+
+/// `LintResult` of ESLint, `source` is only given for files with diagnostics
+fn result(path: &str, infos: &[&Info], source: Option<&str>) -> Value {
+    let error_count = infos.iter().filter(|info| info.is_error()).count();
+    let fatal_error_count = infos.iter().filter(|info| info.fatal).count();
+    let fixable_count = infos.iter().filter(|info| info.fixable).count();
+    let fixable_error_count = infos.iter().filter(|info| info.fixable && info.is_error()).count();
+    let mut result = json!({
+        "filePath": path,
+        "messages": infos.iter().map(|info| message(info)).collect::<Vec<_>>(),
+        "suppressedMessages": [],
+        "errorCount": error_count,
+        "fatalErrorCount": fatal_error_count,
+        "warningCount": infos.len() - error_count,
+        "fixableErrorCount": fixable_error_count,
+        "fixableWarningCount": fixable_count - fixable_error_count,
+    });
+    if let Some(source) = source.filter(|_| !infos.is_empty()) {
+        result["source"] = json!(source);
+    }
+    result["usedDeprecatedRules"] = json!([]);
+    result
+}
+
+/// `LintMessage` of ESLint, fatal errors have no end position like ESLint's parsing errors
+fn message(info: &Info) -> Value {
+    let severity = if info.is_error() { 2 } else { 1 };
+    if info.fatal {
+        return json!({
+            "ruleId": null,
+            "fatal": true,
+            "severity": severity,
+            "message": info.message,
+            "line": info.line,
+            "column": info.column,
+            "nodeType": null,
+        });
+    }
+    let mut message = json!({
+        "ruleId": info.rule_id,
+        "severity": severity,
+        "message": info.message,
+        "line": info.line,
+        "column": info.column,
+        "nodeType": null,
+        "messageId": null,
+        "endLine": info.end_line,
+        "endColumn": info.end_column,
+    });
+    if let Some((range, text)) = &info.fix {
+        message["fix"] = json!({ "range": range, "text": text });
+    }
+    message
+}
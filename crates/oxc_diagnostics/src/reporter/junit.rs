@@ -0,0 +1,37 @@
+use std::fmt::Write;
+
+use super::{group_by_path, xml_escape, Info};
+
+/// The same shape as ESLint's `junit` formatter, one test suite per file and one test case per diagnostic
+pub fn format(diagnostics: &[Info]) -> String {
+    let mut output = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<testsuites>\n");
+    for (path, infos) in group_by_path(diagnostics) {
+        let path = xml_escape(path);
+        let errors = infos.iter().filter(|info| info.is_error()).count();
+        writeln!(
+            output,
+            "<testsuite package=\"org.oxlint\" time=\"0\" tests=\"{}\" errors=\"{errors}\" name=\"{path}\">",
+            infos.len()
+        )
+        .unwrap();
+        for info in infos {
+            let rule_id = info.rule_id.as_deref().unwrap_or("");
+            let severity = if info.is_error() { "Error" } else { "Warning" };
+            let message = xml_escape(&info.message);
+            writeln!(
+                output,
+                "<testcase time=\"0\" name=\"org.oxlint.{}\" classname=\"{path}\"><failure message=\"{message}\"><![CDATA[line {}, col {}, {severity} - {}{}]]></failure></testcase>",
+                xml_escape(rule_id),
+                info.line,
+                info.column,
+                // `]]>` would end the CDATA section
+                info.message.replace("]]>", "]]]]><![CDATA[>"),
+                if rule_id.is_empty() { String::new() } else { format!(" ({rule_id})") },
+            )
+            .unwrap();
+        }
+        output.push_str("</testsuite>\n");
+    }
+    output.push_str("</testsuites>");
+    output
+}
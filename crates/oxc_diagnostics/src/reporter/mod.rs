@@ -0,0 +1,517 @@
+mod checkstyle;
+mod github;
+mod json;
+mod junit;
+mod sarif;
+
+use std::{
+    collections::HashMap,
+    io::{self, BufWriter, Stderr, Stdout, Write},
+    path::Path,
+};
+
+use crate::{
+    miette::{Error, SourceSpan},
+    GraphicalReportHandler, LintDiagnostic, Severity,
+};
+
+#[allow(clippy::large_enum_variant)] // Lerge size is fine because this is a singleton
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum DiagnosticReporter {
+    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+    // See `https://github.com/rust-lang/rust/issues/60673`.
+    Graphical {
+        handler: GraphicalReportHandler,
+        writer: BufWriter<Output>,
+    },
+    /// <https://eslint.org/docs/latest/use/formatters/#json>
+    Json {
+        /// Every linted file, with its source text if it has diagnostics
+        files: Vec<(String, Option<String>)>,
+        diagnostics: Vec<Info>,
+    },
+    /// SARIF 2.1.0, for GitHub code scanning
+    Sarif {
+        diagnostics: Vec<Info>,
+    },
+    /// JUnit XML in the same shape as ESLint's `junit` formatter
+    Junit {
+        diagnostics: Vec<Info>,
+    },
+    /// Checkstyle XML in the same shape as ESLint's `checkstyle` formatter
+    Checkstyle {
+        diagnostics: Vec<Info>,
+    },
+    /// GitHub Actions workflow commands, printed as annotations on pull requests
+    Github {
+        writer: BufWriter<Stdout>,
+    },
+}
+
+impl DiagnosticReporter {
+    pub fn new_graphical() -> Self {
+        Self::Graphical {
+            handler: GraphicalReportHandler::new(),
+            writer: BufWriter::new(Output::Stdout(io::stdout())),
+        }
+    }
+
+    /// Graphical report written to stderr, for when stdout is used for other output,
+    /// e.g. the fixed code of `--stdin --fix`
+    pub fn new_graphical_stderr() -> Self {
+        Self::Graphical {
+            handler: GraphicalReportHandler::new(),
+            writer: BufWriter::new(Output::Stderr(io::stderr())),
+        }
+    }
+
+    pub fn new_json() -> Self {
+        Self::Json { files: vec![], diagnostics: vec![] }
+    }
+
+    pub fn new_sarif() -> Self {
+        Self::Sarif { diagnostics: vec![] }
+    }
+
+    pub fn new_junit() -> Self {
+        Self::Junit { diagnostics: vec![] }
+    }
+
+    pub fn new_checkstyle() -> Self {
+        Self::Checkstyle { diagnostics: vec![] }
+    }
+
+    pub fn new_github() -> Self {
+        Self::Github { writer: BufWriter::new(std::io::stdout()) }
+    }
+
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn finish(&mut self) {
+        let output = match self {
+            Self::Graphical { writer, .. } => {
+                writer.flush().unwrap();
+                return;
+            }
+            Self::Github { writer } => {
+                writer.flush().unwrap();
+                return;
+            }
+            Self::Json { files, diagnostics } => json::format(files, diagnostics),
+            Self::Sarif { diagnostics } => sarif::format(diagnostics),
+            Self::Junit { diagnostics } => junit::format(diagnostics),
+            Self::Checkstyle { diagnostics } => checkstyle::format(diagnostics),
+        };
+        println!("{output}");
+    }
+
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn render_diagnostics(&mut self, s: &[u8]) {
+        match self {
+            Self::Graphical { writer, .. } => {
+                writer.write_all(s).unwrap();
+            }
+            Self::Json { .. }
+            | Self::Sarif { .. }
+            | Self::Junit { .. }
+            | Self::Checkstyle { .. }
+            | Self::Github { .. } => {}
+        }
+    }
+
+    /// Record the linted file at `path`, called before rendering its errors,
+    /// even if it has none
+    pub fn render_file(&mut self, path: &Path) {
+        if let Self::Json { files, .. } = self {
+            files.push((path_string(path), None));
+        }
+    }
+
+    /// Render `error` reported for the file at `path`,
+    /// returns the output if it should be written by [Self::render_diagnostics].
+    ///
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn render_error(&mut self, path: &Path, error: &Error) -> Option<String> {
+        match self {
+            Self::Graphical { handler, .. } => {
+                let mut output = String::new();
+                handler.render_report(&mut output, error.as_ref()).unwrap();
+                Some(output)
+            }
+            Self::Json { files, diagnostics } => {
+                let info = Info::new(path, error);
+                if let Some((file, source)) = files.last_mut() {
+                    if *file == info.path && source.is_none() {
+                        *source = source_text(error).map(ToString::to_string);
+                    }
+                }
+                diagnostics.push(info);
+                None
+            }
+            Self::Sarif { diagnostics }
+            | Self::Junit { diagnostics }
+            | Self::Checkstyle { diagnostics } => {
+                diagnostics.push(Info::new(path, error));
+                None
+            }
+            Self::Github { writer } => {
+                writeln!(writer, "{}", github::format(&Info::new(path, error))).unwrap();
+                None
+            }
+        }
+    }
+}
+
+/// Where the graphical report is written
+#[derive(Debug)]
+pub enum Output {
+    Stdout(Stdout),
+    Stderr(Stderr),
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Stdout(stdout) => stdout.write(buf),
+            Self::Stderr(stderr) => stderr.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Stdout(stdout) => stdout.flush(),
+            Self::Stderr(stderr) => stderr.flush(),
+        }
+    }
+}
+
+/// A diagnostic flattened for the machine readable formats
+#[derive(Debug)]
+pub struct Info {
+    /// Path with `/` separators
+    pub path: String,
+    /// 1-based line of the start of the first label
+    pub line: usize,
+    /// 1-based column in UTF-16 code units, the same as ESLint
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+    /// The message without the rule id prefix
+    pub message: String,
+    pub severity: Severity,
+    /// e.g. `no-debugger`, `@typescript-eslint/no-explicit-any`, `None` for syntax errors
+    pub rule_id: Option<String>,
+    /// Whether `--fix` fixes the diagnostic
+    pub fixable: bool,
+    /// Whether the file could not be linted, e.g. because of a syntax error
+    pub fatal: bool,
+    /// Range in UTF-16 code units of the source text and its replacement, the same as ESLint
+    pub fix: Option<([usize; 2], String)>,
+}
+
+impl Info {
+    fn new(path: &Path, error: &Error) -> Self {
+        let path = path_string(path);
+        let source_text = source_text(error);
+        let span = error
+            .labels()
+            .and_then(|labels| labels.min_by_key(miette::LabeledSpan::offset))
+            .map(|label| *label.inner());
+        let (start, end) = match (span, source_text) {
+            (Some(span), Some(source_text)) => (
+                Position::of(source_text, span.offset()),
+                Position::of(source_text, span.offset() + span.len()),
+            ),
+            _ => (Position::default(), Position::default()),
+        };
+        let lint = LintDiagnostic::of(error);
+        let rule_id = lint.and_then(LintDiagnostic::rule_id).map(ToString::to_string);
+        let message = error.to_string();
+        let message = match rule_id {
+            Some(_) => strip_rule_prefix(&message).to_string(),
+            None => message,
+        };
+        Self {
+            path,
+            line: start.line,
+            column: start.column,
+            end_line: end.line,
+            end_column: end.column,
+            message,
+            // Diagnostics without severity are counted as errors by `DiagnosticService`
+            severity: error.severity().unwrap_or(Severity::Error),
+            rule_id,
+            fixable: lint.is_some_and(LintDiagnostic::is_fixable),
+            fatal: lint.is_none(),
+            fix: lint.and_then(LintDiagnostic::fix).zip(source_text).map(
+                |((range, text), source_text)| {
+                    let offset = |offset: usize| {
+                        source_text[..offset.min(source_text.len())].encode_utf16().count()
+                    };
+                    ([offset(range.start), offset(range.end)], text.to_string())
+                },
+            ),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self { line: 1, column: 1 }
+    }
+}
+
+impl Position {
+    fn of(source_text: &str, offset: usize) -> Self {
+        let offset = offset.min(source_text.len());
+        let before = &source_text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].encode_utf16().count() + 1,
+        }
+    }
+}
+
+/// Path with `/` separators
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// The whole source text attached with `Error::with_source_code`
+fn source_text(error: &Error) -> Option<&str> {
+    // Reading an empty span with unlimited context lines after it yields the text up to the end
+    let contents = error.source_code()?.read_span(&SourceSpan::from((0, 0)), 0, usize::MAX).ok()?;
+    std::str::from_utf8(contents.data()).ok()
+}
+
+/// The message of a rule without its `eslint-plugin-react(jsx-key): ` prefix,
+/// which is given by the rule id instead
+fn strip_rule_prefix(message: &str) -> &str {
+    match message.split_once("): ") {
+        Some((prefix, rest)) if prefix.contains('(') && !prefix.contains(char::is_whitespace) => {
+            rest
+        }
+        _ => message,
+    }
+}
+
+/// Escape `&`, `<`, `>`, `"` and `'` for XML text and attributes
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Diagnostics grouped by file, in the order the files were received
+fn group_by_path(diagnostics: &[Info]) -> Vec<(&str, Vec<&Info>)> {
+    let mut indices: HashMap<&str, usize> = HashMap::new();
+    let mut files: Vec<(&str, Vec<&Info>)> = vec![];
+    for info in diagnostics {
+        let index = *indices.entry(&info.path).or_insert_with(|| {
+            files.push((&info.path, vec![]));
+            files.len() - 1
+        });
+        files[index].1.push(info);
+    }
+    files
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use miette::{LabeledSpan, MietteDiagnostic, NamedSource};
+    use serde_json::Value;
+
+    use super::{checkstyle, github, json, junit, sarif, Info};
+    use crate::{Error, LintDiagnostic, Severity};
+
+    const SOURCE_TEXT: &str = "debugger;\nlet s = '😀'; if (s == b) {}\nlet <\n";
+
+    /// A fixable error, a warning after a character outside of the BMP and a syntax error
+    fn diagnostics() -> Vec<Info> {
+        let span = |text: &str| span(SOURCE_TEXT, text);
+        let errors: Vec<Error> = vec![
+            LintDiagnostic::new(
+                MietteDiagnostic::new("eslint(no-debugger): `debugger` statement is not allowed")
+                    .with_label(span("debugger;"))
+                    .into(),
+            )
+            .with_rule_id("no-debugger".to_string())
+            .with_severity(Severity::Error)
+            .with_fixable(true)
+            .with_fix(0..9, String::new())
+            .into(),
+            LintDiagnostic::new(
+                MietteDiagnostic::new("eslint(eqeqeq): Expected === and instead saw ==")
+                    .with_label(span("=="))
+                    .into(),
+            )
+            .with_rule_id("eqeqeq".to_string())
+            .with_severity(Severity::Warning)
+            .into(),
+            MietteDiagnostic::new("Unexpected token").with_label(span("<")).into(),
+        ];
+        infos("src/test.js", SOURCE_TEXT, errors)
+    }
+
+    fn span(source_text: &str, text: &str) -> LabeledSpan {
+        let start = source_text.find(text).unwrap();
+        LabeledSpan::underline(start..start + text.len())
+    }
+
+    fn infos(path: &str, source_text: &str, errors: Vec<Error>) -> Vec<Info> {
+        let source = Arc::new(NamedSource::new(path, source_text.to_string()));
+        errors
+            .into_iter()
+            .map(|error| {
+                let error = match error.downcast::<LintDiagnostic>() {
+                    Ok(error) => error.with_source_code(Arc::clone(&source)).into(),
+                    Err(error) => error.with_source_code(Arc::clone(&source)),
+                };
+                Info::new(Path::new(path), &error)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn info() {
+        let diagnostics = diagnostics();
+        let debugger = &diagnostics[0];
+        assert_eq!(debugger.rule_id.as_deref(), Some("no-debugger"));
+        assert_eq!(
+            (debugger.line, debugger.column, debugger.end_line, debugger.end_column),
+            (1, 1, 1, 10)
+        );
+        assert!(debugger.is_error() && debugger.fixable && !debugger.fatal);
+        assert_eq!(debugger.fix, Some(([0, 9], String::new())));
+        let eqeqeq = &diagnostics[1];
+        assert_eq!(eqeqeq.rule_id.as_deref(), Some("eqeqeq"));
+        // `😀` is two UTF-16 code units
+        assert_eq!((eqeqeq.line, eqeqeq.column), (2, 21));
+        assert!(!eqeqeq.is_error() && !eqeqeq.fixable);
+        let syntax_error = &diagnostics[2];
+        assert_eq!(syntax_error.rule_id, None);
+        assert_eq!((syntax_error.line, syntax_error.column), (3, 5));
+        assert!(syntax_error.is_error() && syntax_error.fatal);
+    }
+
+    #[test]
+    fn formats() {
+        let diagnostics = diagnostics();
+        let files = [
+            ("src/test.js".to_string(), Some(SOURCE_TEXT.to_string())),
+            ("src/clean.js".to_string(), None),
+        ];
+        insta::assert_snapshot!("json", json::format(&files, &diagnostics));
+        insta::assert_snapshot!("sarif", sarif::format(&diagnostics));
+        insta::assert_snapshot!("junit", junit::format(&diagnostics));
+        insta::assert_snapshot!("checkstyle", checkstyle::format(&diagnostics));
+        let github = diagnostics.iter().map(github::format).collect::<Vec<_>>().join("\n");
+        insta::assert_snapshot!("github", github);
+    }
+
+    /// The JSON output has the shape of the output of `eslint --format json` in `fixtures/eslint.json`,
+    /// for the same files with `no-debugger` as an error and `eqeqeq` as a warning.
+    /// Messages, `nodeType` and `messageId` differ from ESLint, ESLint's paths are absolute.
+    #[test]
+    fn json_matches_eslint() {
+        fn assert_same_shape(ours: &Value, eslint: &Value, at: &str) {
+            match (ours, eslint) {
+                (Value::Object(ours), Value::Object(eslint)) => {
+                    assert_eq!(
+                        ours.keys().collect::<Vec<_>>(),
+                        eslint.keys().collect::<Vec<_>>(),
+                        "keys of {at}"
+                    );
+                    for (key, value) in ours {
+                        let at = format!("{at}.{key}");
+                        match key.as_str() {
+                            "filePath" => assert!(
+                                eslint[key].as_str().unwrap().ends_with(value.as_str().unwrap()),
+                                "{at}"
+                            ),
+                            "message" | "nodeType" | "messageId" => {}
+                            _ => assert_same_shape(value, &eslint[key], &at),
+                        }
+                    }
+                }
+                (Value::Array(ours), Value::Array(eslint)) => {
+                    assert_eq!(ours.len(), eslint.len(), "length of {at}");
+                    for (index, (ours, eslint)) in ours.iter().zip(eslint).enumerate() {
+                        assert_same_shape(ours, eslint, &format!("{at}[{index}]"));
+                    }
+                }
+                _ => assert_eq!(ours, eslint, "{at}"),
+            }
+        }
+
+        let test_source_text = "debugger;\nlet s = '😀'; if (typeof s == 'string') {}\n";
+        let mut diagnostics = infos(
+            "src/test.js",
+            test_source_text,
+            vec![
+                LintDiagnostic::new(
+                    MietteDiagnostic::new("`debugger` statement is not allowed")
+                        .with_label(span(test_source_text, "debugger;"))
+                        .into(),
+                )
+                .with_rule_id("no-debugger".to_string())
+                .with_severity(Severity::Error)
+                .into(),
+                LintDiagnostic::new(
+                    MietteDiagnostic::new("Expected === and instead saw ==")
+                        .with_label(span(test_source_text, "=="))
+                        .into(),
+                )
+                .with_rule_id("eqeqeq".to_string())
+                .with_severity(Severity::Warning)
+                .with_fixable(true)
+                .with_fix(39..41, "===".to_string())
+                .into(),
+            ],
+        );
+        let error_source_text = "let <\n";
+        diagnostics.extend(infos(
+            "src/error.js",
+            error_source_text,
+            vec![MietteDiagnostic::new("Unexpected token")
+                .with_label(span(error_source_text, "<"))
+                .into()],
+        ));
+        let files = [
+            ("src/test.js".to_string(), Some(test_source_text.to_string())),
+            ("src/error.js".to_string(), Some(error_source_text.to_string())),
+            ("src/clean.js".to_string(), None),
+        ];
+        let ours: Value = serde_json::from_str(&json::format(&files, &diagnostics)).unwrap();
+        let eslint: Value =
+            serde_json::from_str(include_str!("../../fixtures/eslint.json")).unwrap();
+        assert_same_shape(&ours, &eslint, "results");
+    }
+}
@@ -0,0 +1,50 @@
+use serde_json::json;
+
+use super::Info;
+
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+pub fn format(diagnostics: &[Info]) -> String {
+    let mut rule_ids =
+        diagnostics.iter().filter_map(|info| info.rule_id.as_ref()).collect::<Vec<_>>();
+    rule_ids.sort_unstable();
+    rule_ids.dedup();
+    let rules = rule_ids.iter().map(|rule_id| json!({ "id": rule_id })).collect::<Vec<_>>();
+
+    let results = diagnostics
+        .iter()
+        .map(|info| {
+            json!({
+                "ruleId": info.rule_id,
+                "level": if info.is_error() { "error" } else { "warning" },
+                "message": { "text": info.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": info.path },
+                        "region": {
+                            "startLine": info.line,
+                            "startColumn": info.column,
+                            "endLine": info.end_line,
+                            "endColumn": info.end_column,
+                        },
+                    },
+                }],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let sarif = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "oxlint",
+                    "informationUri": "https://oxc-project.github.io",
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+    serde_json::to_string_pretty(&sarif).unwrap()
+}
@@ -1,11 +1,13 @@
 use std::{
     cell::Cell,
+    collections::BTreeMap,
     path::{Path, PathBuf},
     sync::{mpsc, Arc},
 };
 
 use crate::{
-    miette::NamedSource, reporter::DiagnosticReporter, Error, MinifiedFileError, Severity,
+    miette::NamedSource, reporter::DiagnosticReporter, Error, LintDiagnostic, MinifiedFileError,
+    Severity,
 };
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
@@ -28,6 +30,9 @@ pub struct DiagnosticService {
     /// Total number of errors received
     errors_count: Cell<usize>,
 
+    /// Diagnostics of each file kept across runs, see [Self::with_retain]
+    retained: Option<BTreeMap<PathBuf, Vec<Error>>>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -41,6 +46,7 @@ impl Default for DiagnosticService {
             max_warnings: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
+            retained: None,
             sender,
             receiver,
         }
@@ -48,8 +54,8 @@ impl Default for DiagnosticService {
 }
 
 impl DiagnosticService {
-    pub fn set_json_reporter(&mut self) {
-        self.reporter = DiagnosticReporter::new_json();
+    pub fn set_reporter(&mut self, reporter: DiagnosticReporter) {
+        self.reporter = reporter;
     }
 
     #[must_use]
@@ -64,6 +70,23 @@ impl DiagnosticService {
         self
     }
 
+    /// Keep the diagnostics of each file across calls of [Self::run], each run reports all of them again.
+    /// Used by watch mode, where only the affected files are linted again, see [Self::forget].
+    #[must_use]
+    pub fn with_retain(mut self, yes: bool) -> Self {
+        self.retained = yes.then(BTreeMap::new);
+        self
+    }
+
+    /// Forget the retained diagnostics of `paths`, e.g. before they are linted again
+    pub fn forget<'a, I: IntoIterator<Item = &'a Path>>(&mut self, paths: I) {
+        if let Some(retained) = &mut self.retained {
+            for path in paths {
+                retained.remove(path);
+            }
+        }
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -88,7 +111,11 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|diagnostic| match diagnostic.downcast::<LintDiagnostic>() {
+                // Keep the lint diagnostic outermost for the reporters to read its rule
+                Ok(diagnostic) => diagnostic.with_source_code(Arc::clone(&source)).into(),
+                Err(diagnostic) => diagnostic.with_source_code(Arc::clone(&source)),
+            })
             .collect();
         (path.to_path_buf(), diagnostics)
     }
@@ -97,42 +124,60 @@ impl DiagnosticService {
     ///
     /// * When the writer fails to write
     pub fn run(&mut self) {
-        while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
-            let mut output = String::new();
-            for diagnostic in diagnostics {
-                let severity = diagnostic.severity();
-                let is_warning = severity == Some(Severity::Warning);
-                let is_error = severity.is_none() || severity == Some(Severity::Error);
-                if is_warning || is_error {
-                    if is_warning {
-                        let warnings_count = self.warnings_count() + 1;
-                        self.warnings_count.set(warnings_count);
-                    }
-                    if is_error {
-                        let errors_count = self.errors_count() + 1;
-                        self.errors_count.set(errors_count);
-                    }
-                    // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
-                    // Note that it does not disable ALL diagnostics, only Warning diagnostics
-                    else if self.quiet {
-                        continue;
-                    }
+        if let Some(mut retained) = self.retained.take() {
+            while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+                retained.entry(path).or_default().extend(diagnostics);
+            }
+            self.warnings_count.set(0);
+            self.errors_count.set(0);
+            for (path, diagnostics) in &retained {
+                self.render(path, diagnostics);
+            }
+            self.retained = Some(retained);
+        } else {
+            while let Ok(Some((path, diagnostics))) = self.receiver.recv() {
+                self.render(&path, &diagnostics);
+            }
+        }
+
+        self.reporter.finish();
+    }
+
+    /// Count and render the diagnostics of the file at `path`
+    fn render(&mut self, path: &Path, diagnostics: &[Error]) {
+        self.reporter.render_file(path);
+        let mut output = String::new();
+        for diagnostic in diagnostics {
+            let severity = diagnostic.severity();
+            let is_warning = severity == Some(Severity::Warning);
+            let is_error = severity.is_none() || severity == Some(Severity::Error);
+            if is_warning || is_error {
+                if is_warning {
+                    let warnings_count = self.warnings_count() + 1;
+                    self.warnings_count.set(warnings_count);
                 }
+                if is_error {
+                    let errors_count = self.errors_count() + 1;
+                    self.errors_count.set(errors_count);
+                }
+                // The --quiet flag follows ESLint's --quiet behavior as documented here: https://eslint.org/docs/latest/use/command-line-interface#--quiet
+                // Note that it does not disable ALL diagnostics, only Warning diagnostics
+                else if self.quiet {
+                    continue;
+                }
+            }
 
-                if let Some(mut err_str) = self.reporter.render_error(diagnostic) {
-                    // Skip large output and print only once
-                    if err_str.lines().any(|line| line.len() >= 400) {
-                        let minified_diagnostic = Error::new(MinifiedFileError(path.clone()));
-                        err_str = format!("{minified_diagnostic:?}");
-                        output = err_str;
-                        break;
-                    }
-                    output.push_str(&err_str);
+            if let Some(mut err_str) = self.reporter.render_error(path, diagnostic) {
+                // Skip large output and print only once
+                if err_str.lines().any(|line| line.len() >= 400) {
+                    let minified_diagnostic = Error::new(MinifiedFileError(path.to_path_buf()));
+                    err_str = format!("{minified_diagnostic:?}");
+                    output = err_str;
+                    break;
                 }
+                output.push_str(&err_str);
             }
-            self.reporter.render_diagnostics(output.as_bytes());
         }
-
-        self.reporter.finish();
+        self.reporter.render_diagnostics(output.as_bytes());
     }
 }
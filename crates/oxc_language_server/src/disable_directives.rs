@@ -0,0 +1,199 @@
+use std::path::Path;
+
+use oxc_ast::AstKind;
+use oxc_semantic::Semantic;
+use oxc_span::GetSpan;
+
+use crate::navigation::{source_at, with_semantic};
+
+/// A disable comment to insert at `offset` of the file
+#[derive(Debug)]
+pub struct Insertion {
+    pub offset: usize,
+    pub text: String,
+}
+
+/// Disable `rule` for the line of `offset` with an `eslint-disable-next-line` comment
+/// above it, written as a JSX expression between JSX children
+pub fn disable_next_line(
+    path: &Path,
+    source_text: &str,
+    offset: usize,
+    rule: &str,
+) -> Option<Insertion> {
+    let ((line_start, text), start) =
+        with_semantic(path, source_text, offset, |semantic, offset| {
+            let source_text = semantic.source_text();
+            let line_start = source_text[..offset as usize].rfind('\n').map_or(0, |i| i + 1);
+            let line = &source_text[line_start..];
+            let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+            #[allow(clippy::cast_possible_truncation)]
+            let comment = if is_jsx_child(semantic, (line_start + indent.len()) as u32) {
+                format!("{{/* eslint-disable-next-line {rule} */}}")
+            } else {
+                format!("// eslint-disable-next-line {rule}")
+            };
+            Some((line_start, format!("{indent}{comment}{}", line_break(source_text))))
+        })?;
+    // The first line of a script block starts after the opening tag
+    let text = if start > 0 && line_start == 0 {
+        format!("{}{text}", line_break(source_text))
+    } else {
+        text
+    };
+    Some(Insertion { offset: start + line_start, text })
+}
+
+/// Disable `rule` for the file, or the script block containing `offset`,
+/// with an `eslint-disable` comment at its start
+pub fn disable_file(
+    path: &Path,
+    source_text: &str,
+    offset: usize,
+    rule: &str,
+) -> Option<Insertion> {
+    let source = source_at(path, source_text, offset)?;
+    let line_break = line_break(source.source_text);
+    let comment = format!("/* eslint-disable {rule} */");
+    if source.start > 0 {
+        // Keep the code after the opening tag on its own line
+        let code_line_break =
+            if source.source_text.starts_with(['\r', '\n']) { "" } else { line_break };
+        let text = format!("{line_break}{comment}{code_line_break}");
+        return Some(Insertion { offset: source.start, text });
+    }
+    // A hashbang must stay at the start of the file
+    let offset = if source_text.starts_with("#!") { source_text.find('\n')? + 1 } else { 0 };
+    Some(Insertion { offset, text: format!("{comment}{line_break}") })
+}
+
+/// Whether the line starting at `offset` is between the children of a JSX element or fragment,
+/// where a line comment would be rendered as text
+fn is_jsx_child(semantic: &Semantic, offset: u32) -> bool {
+    let nodes = semantic.nodes();
+    // The innermost node containing `offset`
+    let Some(mut node) = nodes
+        .iter()
+        .filter(|node| {
+            let span = node.kind().span();
+            span.start <= offset && offset < span.end
+        })
+        .min_by_key(|node| (node.kind().span().size(), std::cmp::Reverse(node.id())))
+    else {
+        return false;
+    };
+    if node.kind().span().start != offset {
+        return matches!(
+            node.kind(),
+            AstKind::JSXElement(_) | AstKind::JSXFragment(_) | AstKind::JSXText(_)
+        );
+    }
+    // The outermost node starting at `offset`
+    while let Some(parent) =
+        nodes.parent_node(node.id()).filter(|parent| parent.kind().span().start == offset)
+    {
+        node = parent;
+    }
+    matches!(nodes.parent_kind(node.id()), Some(AstKind::JSXElement(_) | AstKind::JSXFragment(_)))
+}
+
+fn line_break(source_text: &str) -> &'static str {
+    if source_text.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{disable_file, disable_next_line, Insertion};
+
+    fn insert(source_text: &str, insertion: Option<Insertion>) -> String {
+        let insertion = insertion.unwrap();
+        let mut code = source_text.to_string();
+        code.insert_str(insertion.offset, &insertion.text);
+        code
+    }
+
+    fn next_line(path: &str, source_text: &str, at: &str) -> String {
+        let offset = source_text.find(at).unwrap();
+        let insertion = disable_next_line(Path::new(path), source_text, offset, "no-debugger");
+        insert(source_text, insertion)
+    }
+
+    fn file(path: &str, source_text: &str, at: &str) -> String {
+        let offset = source_text.find(at).unwrap();
+        insert(source_text, disable_file(Path::new(path), source_text, offset, "no-debugger"))
+    }
+
+    #[test]
+    fn javascript() {
+        assert_eq!(
+            next_line("a.js", "if (a) {\n  debugger;\n}\n", "debugger"),
+            "if (a) {\n  // eslint-disable-next-line no-debugger\n  debugger;\n}\n"
+        );
+        assert_eq!(
+            next_line("a.js", "a;\r\ndebugger;\r\n", "debugger"),
+            "a;\r\n// eslint-disable-next-line no-debugger\r\ndebugger;\r\n"
+        );
+        assert_eq!(
+            file("a.js", "debugger;\n", "debugger"),
+            "/* eslint-disable no-debugger */\ndebugger;\n"
+        );
+        // After the hashbang
+        assert_eq!(
+            file("a.js", "#!/usr/bin/env node\ndebugger;\n", "debugger"),
+            "#!/usr/bin/env node\n/* eslint-disable no-debugger */\ndebugger;\n"
+        );
+    }
+
+    #[test]
+    fn jsx() {
+        // Between the children, a line comment would be rendered as text
+        assert_eq!(
+            next_line("a.jsx", "const a = (\n  <div>\n    <span>{b}</span>\n  </div>\n);\n", "<span"),
+            "const a = (\n  <div>\n    {/* eslint-disable-next-line no-debugger */}\n    <span>{b}</span>\n  </div>\n);\n"
+        );
+        assert_eq!(
+            next_line("a.tsx", "const a = (\n  <>\n    {b}\n  </>\n);\n", "{b}"),
+            "const a = (\n  <>\n    {/* eslint-disable-next-line no-debugger */}\n    {b}\n  </>\n);\n"
+        );
+        // Between the attributes and outside of the element
+        assert_eq!(
+            next_line("a.jsx", "const a = (\n  <div\n    onClick={b}\n  />\n);\n", "onClick"),
+            "const a = (\n  <div\n    // eslint-disable-next-line no-debugger\n    onClick={b}\n  />\n);\n"
+        );
+        assert_eq!(
+            next_line("a.jsx", "const a = (\n  <div />\n);\n", "<div"),
+            "const a = (\n  // eslint-disable-next-line no-debugger\n  <div />\n);\n"
+        );
+    }
+
+    #[test]
+    fn vue() {
+        let source_text = "<template><div /></template>\n<script>\ndebugger;\n</script>\n";
+        assert_eq!(
+            next_line("a.vue", source_text, "debugger"),
+            "<template><div /></template>\n<script>\n// eslint-disable-next-line no-debugger\ndebugger;\n</script>\n"
+        );
+        assert_eq!(
+            file("a.vue", source_text, "debugger"),
+            "<template><div /></template>\n<script>\n/* eslint-disable no-debugger */\ndebugger;\n</script>\n"
+        );
+        // The code on the line of the opening tag is moved to its own line
+        let source_text = "<script>debugger;</script>\n";
+        assert_eq!(
+            next_line("a.vue", source_text, "debugger"),
+            "<script>\n// eslint-disable-next-line no-debugger\ndebugger;</script>\n"
+        );
+        assert_eq!(
+            file("a.vue", source_text, "debugger"),
+            "<script>\n/* eslint-disable no-debugger */\ndebugger;</script>\n"
+        );
+        // Outside of the script block
+        assert!(disable_next_line(Path::new("a.vue"), source_text, 0, "no-debugger").is_none());
+    }
+}
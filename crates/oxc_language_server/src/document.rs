@@ -0,0 +1,200 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use oxc_span::Span;
+use ropey::Rope;
+use tower_lsp::lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
+/// Cancels a lint run when a newer version of the document arrives
+#[derive(Debug, Default, Clone)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// An opened document, kept in sync with the language client
+#[derive(Debug)]
+pub struct Document {
+    text: Rope,
+    version: i32,
+    /// The token of the lint run of the current version
+    cancellation: CancellationToken,
+}
+
+impl Document {
+    pub fn new(text: &str, version: i32) -> Self {
+        Self { text: Rope::from_str(text), version, cancellation: CancellationToken::default() }
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    pub fn text(&self) -> String {
+        self.text.to_string()
+    }
+
+    pub fn cancellation(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
+    /// Apply the changes of `version` in order, and cancel the lint run of the previous version
+    pub fn apply_changes(&mut self, changes: Vec<TextDocumentContentChangeEvent>, version: i32) {
+        for change in changes {
+            if let Some(range) = change.range {
+                let start = self.position_to_char(range.start);
+                let end = self.position_to_char(range.end).max(start);
+                self.text.remove(start..end);
+                self.text.insert(start, &change.text);
+            } else {
+                self.text = Rope::from_str(&change.text);
+            }
+        }
+        self.version = version;
+        self.cancellation.cancel();
+        self.cancellation = CancellationToken::default();
+    }
+
+    /// The byte offset of the UTF-16 based `position` of LSP
+    pub fn position_to_offset(&self, position: Position) -> usize {
+        self.text.char_to_byte(self.position_to_char(position))
+    }
+
+    /// The UTF-16 based position of LSP of the byte `offset`
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn offset_to_position(&self, offset: usize) -> Position {
+        let char = self.text.byte_to_char(offset.min(self.text.len_bytes()));
+        let line = self.text.char_to_line(char);
+        let character = self.text.char_to_utf16_cu(char)
+            - self.text.char_to_utf16_cu(self.text.line_to_char(line));
+        Position::new(line as u32, character as u32)
+    }
+
+    pub fn span_to_range(&self, span: Span) -> Range {
+        Range::new(
+            self.offset_to_position(span.start as usize),
+            self.offset_to_position(span.end as usize),
+        )
+    }
+
+    /// Convert the UTF-16 based `position` of LSP to the char index of the rope,
+    /// clamped to the end of the line or the document
+    fn position_to_char(&self, position: Position) -> usize {
+        let line = position.line as usize;
+        if line >= self.text.len_lines() {
+            return self.text.len_chars();
+        }
+        let line_start = self.text.line_to_char(line);
+        // Excluding the line break
+        let line_text = self.text.line(line);
+        let len = line_text.len_chars();
+        let char_from_end = |n: usize| (len >= n).then(|| line_text.char(len - n));
+        let line_break_len = match (char_from_end(2), char_from_end(1)) {
+            (Some('\r'), Some('\n')) => 2,
+            (_, Some('\n' | '\r')) => 1,
+            _ => 0,
+        };
+        let line_end = line_start + line_text.len_chars() - line_break_len;
+        let utf16_offset = self.text.char_to_utf16_cu(line_start) + position.character as usize;
+        let utf16_offset = utf16_offset.min(self.text.char_to_utf16_cu(line_end));
+        self.text.utf16_cu_to_char(utf16_offset).clamp(line_start, line_end)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::Span;
+    use tower_lsp::lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
+    use super::Document;
+
+    fn change(
+        range: Option<((u32, u32), (u32, u32))>,
+        text: &str,
+    ) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: range.map(|((l1, c1), (l2, c2))| {
+                Range::new(Position::new(l1, c1), Position::new(l2, c2))
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn utf16_positions() {
+        // `é` is two bytes and one UTF-16 code unit, `😀` is four bytes and two code units
+        let document = Document::new("é😀a\nb", 0);
+        assert_eq!(document.position_to_offset(Position::new(0, 1)), 2);
+        assert_eq!(document.position_to_offset(Position::new(0, 3)), 6);
+        assert_eq!(document.position_to_offset(Position::new(1, 1)), 9);
+        assert_eq!(document.offset_to_position(6), Position::new(0, 3));
+        assert_eq!(document.offset_to_position(8), Position::new(1, 0));
+        assert_eq!(
+            document.span_to_range(Span::new(2, 9)),
+            Range::new(Position::new(0, 1), Position::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn line_breaks() {
+        // Only `\n`, `\r\n` and `\r` break lines in LSP
+        let document = Document::new("a\r\nb\rc\u{2028}d\u{85}e\nf", 0);
+        assert_eq!(document.offset_to_position(3), Position::new(1, 0));
+        assert_eq!(document.offset_to_position(5), Position::new(2, 0));
+        assert_eq!(document.offset_to_position(14), Position::new(3, 0));
+        assert_eq!(document.position_to_offset(Position::new(2, 2)), 9);
+        assert_eq!(document.position_to_offset(Position::new(3, 0)), 14);
+    }
+
+    #[test]
+    fn out_of_range_positions() {
+        let document = Document::new("ab\r\ncd", 0);
+        // Clamped before the line break, and to the end of the document
+        assert_eq!(document.position_to_offset(Position::new(0, 10)), 2);
+        assert_eq!(document.position_to_offset(Position::new(1, 10)), 6);
+        assert_eq!(document.position_to_offset(Position::new(5, 0)), 6);
+        assert_eq!(document.offset_to_position(100), Position::new(1, 2));
+    }
+
+    #[test]
+    fn apply_changes() {
+        let mut document = Document::new("let a = 1;\nlet b = 2;\n", 0);
+        // Each change applies to the text produced by the previous one
+        document.apply_changes(
+            vec![
+                change(Some(((0, 4), (0, 5))), "foo"),
+                change(Some(((0, 0), (0, 12))), ""),
+                change(Some(((1, 8), (1, 9))), "3"),
+            ],
+            1,
+        );
+        assert_eq!(document.text(), "\nlet b = 3;\n");
+        // A reversed range does not delete anything
+        document.apply_changes(vec![change(Some(((1, 4), (1, 0))), "x")], 2);
+        assert_eq!(document.text(), "\nlet xb = 3;\n");
+        // A change without range replaces the whole text
+        document.apply_changes(vec![change(None, "a\r\nb")], 3);
+        assert_eq!(document.text(), "a\r\nb");
+        assert_eq!(document.version(), 3);
+    }
+
+    #[test]
+    fn cancellation() {
+        let mut document = Document::new("a", 0);
+        let previous = document.cancellation();
+        assert!(!previous.is_cancelled());
+        document.apply_changes(vec![change(None, "b")], 1);
+        assert!(previous.is_cancelled());
+        assert!(!document.cancellation().is_cancelled());
+    }
+}
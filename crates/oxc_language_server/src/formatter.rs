@@ -0,0 +1,260 @@
+use std::path::Path;
+
+use oxc_allocator::{Allocator, Vec};
+use oxc_ast::{
+    ast::{
+        ArrowFunctionExpression, BlockStatement, FunctionBody, Statement, StaticBlock, SwitchCase,
+        TemplateLiteral,
+    },
+    Visit,
+};
+use oxc_parser::Parser;
+use oxc_prettier::{Prettier, PrettierOptions};
+use oxc_span::{GetSpan, SourceType, Span};
+
+/// Format the source text of the file at `path`.
+/// Returns `None` if the file is not JavaScript or TypeScript, or has syntax errors.
+pub fn format(path: &Path, source_text: &str, options: PrettierOptions) -> Option<String> {
+    let source_type = SourceType::from_path(path).ok()?;
+    format_source(source_text, source_type, options)
+}
+
+/// Format the statements intersecting `range` of the innermost statement list containing it,
+/// e.g. the body of a block or function, as one source text at their indentation.
+/// Returns the span of the statements and their formatted code,
+/// `None` if no statement of the list intersects `range` or the file can't be formatted.
+pub fn format_range(
+    path: &Path,
+    source_text: &str,
+    range: Span,
+    mut options: PrettierOptions,
+) -> Option<(Span, String)> {
+    let source_type = SourceType::from_path(path).ok()?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    let mut finder = StatementsInRange { range, statements: None };
+    finder.visit_program(&ret.program);
+    let span = finder.statements?;
+
+    // The first line keeps the indentation in front of the statements
+    let line_start = source_text[..span.start as usize].rfind('\n').map_or(0, |i| i + 1);
+    let indentation = source_text[line_start..]
+        .split(|c: char| !matches!(c, ' ' | '\t'))
+        .next()
+        .unwrap_or_default();
+    let width = indentation.chars().map(|c| if c == '\t' { options.tab_width } else { 1 }).sum();
+    options.print_width = options.print_width.saturating_sub(width);
+
+    let code = format_source(span.source_text(source_text), source_type, options)?;
+    // The statements are followed by the rest of the source text
+    let code = code.trim_end_matches(['\r', '\n']);
+    Some((span, indent(code, source_type, indentation)))
+}
+
+fn format_source(
+    source_text: &str,
+    source_type: SourceType,
+    options: PrettierOptions,
+) -> Option<String> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).preserve_parens(false).parse();
+    // Code with syntax errors would be formatted into something else
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    Some(Prettier::new(&allocator, source_text, ret.trivias, options).build(&ret.program))
+}
+
+/// Indent the lines of `code` after the first one, except empty lines and lines
+/// continuing a template literal, whose content would be changed
+fn indent(code: &str, source_type: SourceType, indentation: &str) -> String {
+    if indentation.is_empty() {
+        return code.to_string();
+    }
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, code, source_type).parse();
+    let mut templates = TemplateLiterals(vec![]);
+    templates.visit_program(&ret.program);
+
+    let mut indented = String::with_capacity(code.len());
+    let mut offset = 0;
+    for (i, line) in code.split_inclusive('\n').enumerate() {
+        #[allow(clippy::cast_possible_truncation)]
+        let in_template =
+            templates.0.iter().any(|span| span.start < offset as u32 && (offset as u32) < span.end);
+        if i > 0 && !line.trim().is_empty() && !in_template {
+            indented.push_str(indentation);
+        }
+        indented.push_str(line);
+        offset += line.len();
+    }
+    indented
+}
+
+/// Finds the innermost statement list containing `range`
+/// and the span of its statements intersecting `range`
+struct StatementsInRange {
+    range: Span,
+    statements: Option<Span>,
+}
+
+impl StatementsInRange {
+    /// `range` is between the delimiters of `span`, e.g. the braces of a block
+    fn is_inside(&self, span: Span) -> bool {
+        span.start < self.range.start && self.range.end < span.end
+    }
+
+    fn contains_range(&self, span: Span) -> bool {
+        span.start <= self.range.start && self.range.end <= span.end
+    }
+
+    fn visit_statement_containing_range(&mut self, stmts: &Vec<'_, Statement<'_>>) {
+        if let Some(stmt) = stmts.iter().find(|stmt| self.contains_range(stmt.span())) {
+            self.visit_statement(stmt);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for StatementsInRange {
+    fn visit_statements(&mut self, stmts: &Vec<'a, Statement<'a>>) {
+        let mut intersecting = stmts
+            .iter()
+            .map(GetSpan::span)
+            .filter(|span| span.start <= self.range.end && self.range.start <= span.end);
+        self.statements = intersecting
+            .next()
+            .map(|first| Span::new(first.start, intersecting.last().unwrap_or(first).end));
+        self.visit_statement_containing_range(stmts);
+    }
+
+    fn visit_block_statement(&mut self, stmt: &BlockStatement<'a>) {
+        if self.is_inside(stmt.span) {
+            self.visit_statements(&stmt.body);
+        }
+    }
+
+    fn visit_function_body(&mut self, body: &FunctionBody<'a>) {
+        if self.is_inside(body.span) {
+            self.visit_statements(&body.statements);
+        }
+    }
+
+    fn visit_static_block(&mut self, block: &StaticBlock<'a>) {
+        if self.is_inside(block.span) {
+            self.visit_statements(&block.body);
+        }
+    }
+
+    /// The statements of a case follow its test, they are formatted with the whole `switch`
+    fn visit_switch_case(&mut self, case: &SwitchCase<'a>) {
+        self.visit_statement_containing_range(&case.consequent);
+    }
+
+    /// The body of `() => expression` is an expression, not a statement
+    fn visit_arrow_expression(&mut self, expr: &ArrowFunctionExpression<'a>) {
+        if !expr.expression {
+            self.visit_function_body(&expr.body);
+        }
+    }
+}
+
+struct TemplateLiterals(std::vec::Vec<Span>);
+
+impl<'a> Visit<'a> for TemplateLiterals {
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        self.0.push(lit.span);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_prettier::PrettierOptions;
+    use oxc_span::Span;
+
+    use super::{format, format_range};
+
+    /// Format the range from the first `start` to the end of the next `end`,
+    /// and apply the edit
+    fn range(
+        source_text: &str,
+        start: &str,
+        end: &str,
+        options: PrettierOptions,
+    ) -> Option<String> {
+        let start = source_text.find(start).unwrap();
+        let end = start + source_text[start..].find(end).unwrap() + end.len();
+        #[allow(clippy::cast_possible_truncation)]
+        let range = Span::new(start as u32, end as u32);
+        let (span, code) = format_range(Path::new("a.js"), source_text, range, options)?;
+        let mut source_text = source_text.to_string();
+        source_text.replace_range(span.start as usize..span.end as usize, &code);
+        Some(source_text)
+    }
+
+    #[test]
+    fn whole_file() {
+        assert_eq!(
+            format(
+                Path::new("a.js"),
+                "let a=1\nfunction f(){return a}",
+                PrettierOptions::default()
+            ),
+            Some("let a = 1;\nfunction f() {\n  return a;\n}\n".to_string())
+        );
+        assert_eq!(format(Path::new("a.txt"), "a", PrettierOptions::default()), None);
+    }
+
+    #[test]
+    fn top_level_range() {
+        let source_text = "a( 1 )\nb( 2 )\nc( 3 )\n";
+        assert_eq!(
+            range(source_text, "1", "b(", PrettierOptions::default()).unwrap(),
+            "a(1);\nb(2);\nc( 3 )\n"
+        );
+    }
+
+    #[test]
+    fn nested_range() {
+        let source_text = "function f() {\n  a( 1 )\n  if (x) {\n\tb( 2 );c( 3 )\n  }\n}\n";
+        // Only the statements of the innermost block, at the indentation of the first one
+        assert_eq!(
+            range(source_text, "b(", "c(", PrettierOptions::default()).unwrap(),
+            "function f() {\n  a( 1 )\n  if (x) {\n\tb(2);\n\tc(3);\n  }\n}\n"
+        );
+        // The statement containing the block
+        assert_eq!(
+            range(source_text, "(x)", "(x)", PrettierOptions::default()).unwrap(),
+            "function f() {\n  a( 1 )\n  if (x) {\n    b(2);\n    c(3);\n  }\n}\n"
+        );
+        // Lines fit in the print width with the indentation, template literals keep their lines
+        let source_text = "{\n  const o = { aaaa: 1, bbbb: 2 };\n  const t = `\nc`;\n}\n";
+        let options = PrettierOptions { print_width: 32, ..PrettierOptions::default() };
+        assert_eq!(
+            range(source_text, "const", "const t", options).unwrap(),
+            "{\n  const o = {\n    aaaa: 1,\n    bbbb: 2,\n  };\n  const t = `\nc`;\n}\n"
+        );
+    }
+
+    #[test]
+    fn no_statements() {
+        let options = PrettierOptions::default;
+        // Between statements
+        assert_eq!(range("a;\n\n  \n\nb;\n", "  ", "  ", options()), None);
+        // In an empty block
+        assert_eq!(range("if (a) {\n  \n}\n", "  ", "  ", options()), None);
+        // The body of an arrow function is an expression
+        assert_eq!(range("f(() => a( 1 ));\n", "a(", "1", options()).unwrap(), "f(() => a(1));\n");
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let source_text = "a( 1 )\nb( 2 ;\n";
+        assert_eq!(format(Path::new("a.js"), source_text, PrettierOptions::default()), None);
+        assert_eq!(range(source_text, "a", "a", PrettierOptions::default()), None);
+    }
+}
@@ -6,30 +6,37 @@ use std::{
     sync::Arc,
 };
 
+use dashmap::DashMap;
 use miette::NamedSource;
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{miette, Error, Severity};
+use oxc_diagnostics::{miette, Error, LintDiagnostic, Report, Severity};
 use oxc_linter::{
+    fix_in_passes,
     partial_loader::{
         AstroPartialLoader, JavaScriptSource, SveltePartialLoader, VuePartialLoader,
         LINT_PARTIAL_LOADER_EXT,
     },
-    LintContext, Linter,
+    Fix, FixKind, LintContext, LintOptions, Linter, Message,
 };
 use oxc_parser::Parser;
+use oxc_resolver::{ResolveOptions, Resolver};
 use oxc_semantic::SemanticBuilder;
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use ropey::Rope;
 use tower_lsp::lsp_types::{
-    self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
+    self, DiagnosticRelatedInformation, DiagnosticSeverity, NumberOrString, Position, Range,
+    TextEdit, Url,
 };
 
+use crate::document::CancellationToken;
+
 #[derive(Debug)]
 struct ErrorWithPosition {
     pub start_pos: Position,
     pub end_pos: Position,
     pub miette_err: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
     pub labels_with_pos: Vec<LabeledSpanWithPosition>,
 }
 
@@ -45,6 +52,7 @@ impl ErrorWithPosition {
         error: Error,
         text: &str,
         fixed_content: Option<FixedContent>,
+        suggestions: Vec<SuggestedContent>,
         start: usize,
     ) -> Self {
         let labels = error.labels().map_or(vec![], Iterator::collect);
@@ -65,7 +73,7 @@ impl ErrorWithPosition {
         let start_pos = labels_with_pos[0].start_pos;
         let end_pos = labels_with_pos[labels_with_pos.len() - 1].end_pos;
 
-        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content }
+        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content, suggestions }
     }
 
     fn to_lsp_diagnostic(&self, path: &PathBuf) -> lsp_types::Diagnostic {
@@ -115,10 +123,15 @@ impl ErrorWithPosition {
             |help| format!("{}\nhelp: {}", self.miette_err, help),
         );
 
+        // The ESLint rule id, also matched by the disable directives
+        let code = LintDiagnostic::of(&self.miette_err)
+            .and_then(LintDiagnostic::rule_id)
+            .map(|rule_id| NumberOrString::String(rule_id.to_string()));
+
         lsp_types::Diagnostic {
             range,
             severity,
-            code: None,
+            code,
             message,
             source: Some("oxc".into()),
             code_description: None,
@@ -132,6 +145,7 @@ impl ErrorWithPosition {
         DiagnosticReport {
             diagnostic: self.to_lsp_diagnostic(path),
             fixed_content: self.fixed_content,
+            suggestions: self.suggestions,
         }
     }
 }
@@ -140,36 +154,84 @@ impl ErrorWithPosition {
 pub struct DiagnosticReport {
     pub diagnostic: lsp_types::Diagnostic,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
 }
 #[derive(Debug)]
 struct ErrorReport {
     pub error: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestedContent>,
+}
+
+impl ErrorReport {
+    fn new(error: Error) -> Self {
+        Self { error, fixed_content: None, suggestions: vec![] }
+    }
 }
 
+/// The edits of a fix, applied together in one `WorkspaceEdit`
 #[derive(Debug, Clone)]
 pub struct FixedContent {
-    pub code: String,
-    pub range: Range,
+    pub edits: Vec<TextEdit>,
+}
+
+impl FixedContent {
+    fn new(fix: &Fix, start: usize, source_text: &str) -> Self {
+        let edits = fix
+            .edits()
+            .iter()
+            .map(|edit| TextEdit {
+                range: Range {
+                    start: offset_to_position(edit.span.start as usize + start, source_text)
+                        .unwrap_or_default(),
+                    end: offset_to_position(edit.span.end as usize + start, source_text)
+                        .unwrap_or_default(),
+                },
+                new_text: edit.content.to_string(),
+            })
+            .collect();
+        Self { edits }
+    }
+}
+
+/// A suggestion of a diagnostic, offered as a separate code action
+#[derive(Debug, Clone)]
+pub struct SuggestedContent {
+    pub title: String,
+    pub fixed_content: FixedContent,
 }
 
 #[derive(Debug)]
 pub struct IsolatedLintHandler {
     linter: Arc<Linter>,
+    /// The graph the imports of the linted file are recorded in
+    module_graph: Option<Arc<ModuleGraph>>,
 }
 
 impl IsolatedLintHandler {
-    pub fn new(linter: Arc<Linter>) -> Self {
-        Self { linter }
+    pub fn new(linter: Arc<Linter>, module_graph: Option<Arc<ModuleGraph>>) -> Self {
+        Self { linter, module_graph }
     }
 
+    /// Lint the file at `path`, `None` if it is not linted or the run is cancelled
     pub fn run_single(
         &self,
         path: &Path,
         content: Option<String>,
+        cancellation: &CancellationToken,
     ) -> Option<Vec<DiagnosticReport>> {
         if Self::is_wanted_ext(path) {
-            Some(Self::lint_path(&self.linter, path, content).map_or(vec![], |(p, errors)| {
+            let result = Self::lint_path(
+                &self.linter,
+                self.module_graph.as_deref(),
+                path,
+                content,
+                cancellation,
+            );
+            if cancellation.is_cancelled() {
+                return None;
+            }
+            Some(result.map_or(vec![], |(p, errors)| {
                 let mut diagnostics: Vec<DiagnosticReport> =
                     errors.into_iter().map(|e| e.into_diagnostic_report(&p)).collect();
                 // a diagnostics connected from related_info to original diagnostic
@@ -203,6 +265,7 @@ impl IsolatedLintHandler {
                                 data: None,
                             },
                             fixed_content: None,
+                            suggestions: vec![],
                         });
                     }
                 }
@@ -254,8 +317,10 @@ impl IsolatedLintHandler {
 
     fn lint_path(
         linter: &Linter,
+        module_graph: Option<&ModuleGraph>,
         path: &Path,
         source_text: Option<String>,
+        cancellation: &CancellationToken,
     ) -> Option<(PathBuf, Vec<ErrorWithPosition>)> {
         let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
         let (source_type, original_source_text) =
@@ -267,7 +332,11 @@ impl IsolatedLintHandler {
 
         debug!("lint {path:?}");
         let mut diagnostics = vec![];
+        let mut specifiers = vec![];
         for source in javascript_sources {
+            if cancellation.is_cancelled() {
+                return None;
+            }
             let JavaScriptSource { source_text: javascript_source_text, source_type, start } =
                 source;
             let allocator = Allocator::default();
@@ -276,11 +345,7 @@ impl IsolatedLintHandler {
                 .parse();
 
             if !ret.errors.is_empty() {
-                let reports = ret
-                    .errors
-                    .into_iter()
-                    .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                    .collect();
+                let reports = ret.errors.into_iter().map(ErrorReport::new).collect();
                 return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
             };
 
@@ -288,17 +353,25 @@ impl IsolatedLintHandler {
             let semantic_ret = SemanticBuilder::new(javascript_source_text, source_type)
                 .with_trivias(ret.trivias)
                 .with_check_syntax_error(true)
+                .build_module_record(path.to_path_buf(), program)
                 .build(program);
 
             if !semantic_ret.errors.is_empty() {
-                let reports = semantic_ret
-                    .errors
-                    .into_iter()
-                    .map(|diagnostic| ErrorReport { error: diagnostic, fixed_content: None })
-                    .collect();
+                let reports = semantic_ret.errors.into_iter().map(ErrorReport::new).collect();
                 return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
             };
+            specifiers.extend(
+                semantic_ret
+                    .semantic
+                    .module_record()
+                    .requested_modules
+                    .keys()
+                    .map(ToString::to_string),
+            );
 
+            if cancellation.is_cancelled() {
+                return None;
+            }
             let lint_ctx = LintContext::new(
                 path.to_path_buf().into_boxed_path(),
                 &Rc::new(semantic_ret.semantic),
@@ -309,23 +382,22 @@ impl IsolatedLintHandler {
             let reports = result
                 .into_iter()
                 .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
-                        code: f.content.to_string(),
-                        range: Range {
-                            start: offset_to_position(
-                                f.span.start as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                            end: offset_to_position(
-                                f.span.end as usize + start,
+                    let fixed_content =
+                        msg.fix.map(|f| FixedContent::new(&f, start, javascript_source_text));
+                    let suggestions = msg
+                        .suggestions
+                        .iter()
+                        .map(|suggestion| SuggestedContent {
+                            title: suggestion.message.to_string(),
+                            fixed_content: FixedContent::new(
+                                &suggestion.fix,
+                                start,
                                 javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                        },
-                    });
+                            ),
+                        })
+                        .collect();
 
-                    ErrorReport { error: msg.error, fixed_content }
+                    ErrorReport { error: msg.error, fixed_content, suggestions }
                 })
                 .collect::<Vec<ErrorReport>>();
             let (_, errors_with_position) =
@@ -333,6 +405,9 @@ impl IsolatedLintHandler {
             diagnostics.extend(errors_with_position);
         }
 
+        if let Some(module_graph) = module_graph {
+            module_graph.update(path, &specifiers);
+        }
         Some((path.to_path_buf(), diagnostics))
     }
 
@@ -346,10 +421,16 @@ impl IsolatedLintHandler {
         let diagnostics = reports
             .into_iter()
             .map(|report| {
+                // Keep the rule id of lint diagnostics reachable, see `to_lsp_diagnostic`
+                let error = match report.error.downcast::<LintDiagnostic>() {
+                    Ok(diagnostic) => diagnostic.with_source_code(Arc::clone(&source)).into(),
+                    Err(error) => error.with_source_code(Arc::clone(&source)),
+                };
                 ErrorWithPosition::new(
-                    report.error.with_source_code(Arc::clone(&source)),
+                    error,
                     source_text,
                     report.fixed_content,
+                    report.suggestions,
                     start,
                 )
             })
@@ -377,24 +458,171 @@ fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
     Some(Position::new(line as u32, column as u32))
 }
 
+/// The imports of the files linted by the server with the import plugin,
+/// to find the modules importing a renamed export.
+/// Only the files linted since the linter was created are known.
 #[derive(Debug)]
+pub struct ModuleGraph {
+    resolver: Resolver,
+    /// The modules requested by each linted file, with their specifiers
+    imports: DashMap<PathBuf, Vec<(String, PathBuf)>>,
+}
+
+impl ModuleGraph {
+    fn new() -> Self {
+        let resolver = Resolver::new(ResolveOptions {
+            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            condition_names: vec!["module".into(), "require".into()],
+            ..ResolveOptions::default()
+        });
+        Self { resolver, imports: DashMap::default() }
+    }
+
+    /// Replace the imports of the file at `path` with the resolved `specifiers`
+    fn update(&self, path: &Path, specifiers: &[String]) {
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        let imports = specifiers
+            .iter()
+            .filter_map(|specifier| {
+                let resolution = self.resolver.resolve(dir, specifier).ok()?;
+                Some((specifier.clone(), resolution.path().to_path_buf()))
+            })
+            .collect();
+        self.imports.insert(path.to_path_buf(), imports);
+    }
+
+    /// The files importing the module at `path`, with the specifiers requesting it
+    fn importers(&self, path: &Path) -> Vec<(PathBuf, Vec<String>)> {
+        // Resolved paths are canonical
+        let path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        self.imports
+            .iter()
+            .filter_map(|entry| {
+                let specifiers = entry
+                    .value()
+                    .iter()
+                    .filter(|(_, resolved)| *resolved == path)
+                    .map(|(specifier, _)| specifier.clone())
+                    .collect::<Vec<_>>();
+                (!specifiers.is_empty()).then(|| (entry.key().clone(), specifiers))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ServerLinter {
     linter: Arc<Linter>,
+    /// Kept with the import plugin, see [Self::importers]
+    module_graph: Option<Arc<ModuleGraph>>,
 }
 
 impl ServerLinter {
     pub fn new() -> Self {
-        let linter = Linter::default().with_fix(true);
-        Self { linter: Arc::new(linter) }
+        Self::from_options(LintOptions::default()).unwrap()
+    }
+
+    /// # Errors
+    ///
+    /// Returns `Err` if there are any errors parsing the configuration file.
+    pub fn from_options(options: LintOptions) -> Result<Self, Report> {
+        let linter = Linter::from_options(options.with_fix(FixKind::all()))?;
+        let module_graph = linter.options().import_plugin.then(|| Arc::new(ModuleGraph::new()));
+        Ok(Self { linter: Arc::new(linter), module_graph })
     }
 
-    pub fn new_with_linter(linter: Linter) -> Self {
-        Self { linter: Arc::new(linter) }
+    /// Whether `path` is matched by `ignorePatterns` of the linter configuration
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.linter.is_ignored(path)
     }
 
-    pub fn run_single(&self, uri: &Url, content: Option<String>) -> Option<Vec<DiagnosticReport>> {
-        IsolatedLintHandler::new(Arc::clone(&self.linter))
-            .run_single(&uri.to_file_path().unwrap(), content)
+    /// The files linted by the server which import the module at `path`,
+    /// with the specifiers requesting it. Empty without the import plugin.
+    pub fn importers(&self, path: &Path) -> Vec<(PathBuf, Vec<String>)> {
+        self.module_graph
+            .as_ref()
+            .map_or_else(Vec::new, |module_graph| module_graph.importers(path))
+    }
+
+    pub fn run_single(
+        &self,
+        uri: &Url,
+        content: Option<String>,
+        cancellation: &CancellationToken,
+    ) -> Option<Vec<DiagnosticReport>> {
+        IsolatedLintHandler::new(Arc::clone(&self.linter), self.module_graph.clone()).run_single(
+            &uri.to_file_path().unwrap(),
+            content,
+            cancellation,
+        )
+    }
+
+    /// The content with the fixes of `--fix` applied to each JavaScript source of the file,
+    /// `None` if nothing is fixed
+    pub fn fix_all(&self, uri: &Url, content: &str) -> Option<String> {
+        let path = uri.to_file_path().ok()?;
+        if !IsolatedLintHandler::is_wanted_ext(&path) {
+            return None;
+        }
+        let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
+        let javascript_sources = IsolatedLintHandler::may_need_extract_js_content(content, ext)
+            .or_else(|| {
+                let source_type = SourceType::from_path(&path).ok()?;
+                Some(vec![JavaScriptSource { source_text: content, source_type, start: 0 }])
+            })?;
+
+        let mut code = content.to_string();
+        // From the last source, so the offsets of the preceding ones still hold
+        for source in javascript_sources.into_iter().rev() {
+            let JavaScriptSource { source_text, source_type, start } = source;
+            let fixed = fix_in_passes(
+                source_text,
+                |allocator, source_text| self.fixes(&path, allocator, source_text, source_type),
+                |_, _, _| {},
+            );
+            if fixed.code != source_text {
+                code.replace_range(start..start + source_text.len(), &fixed.code);
+            }
+        }
+        (code != content).then_some(code)
+    }
+
+    /// The problems of `source_text` with only the fixes applied by `--fix`,
+    /// nothing if it has syntax errors
+    fn fixes<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        source_type: SourceType,
+    ) -> Vec<Message<'a>> {
+        let ret = Parser::new(allocator, source_text, source_type)
+            .allow_return_outside_function(true)
+            .parse();
+        if !ret.errors.is_empty() {
+            return vec![];
+        }
+        let program = allocator.alloc(ret.program);
+        let semantic_ret = SemanticBuilder::new(source_text, source_type)
+            .with_trivias(ret.trivias)
+            .with_check_syntax_error(true)
+            .build(program);
+        if !semantic_ret.errors.is_empty() {
+            return vec![];
+        }
+        let lint_ctx =
+            LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
+        let mut messages = self.linter.run(lint_ctx);
+        // The linter computes every kind of fix for the code actions
+        for message in &mut messages {
+            message.suggestions.clear();
+            if !LintDiagnostic::of(&message.error).is_some_and(LintDiagnostic::is_fixable) {
+                message.fix = None;
+            }
+        }
+        messages
     }
 }
 
@@ -404,3 +632,91 @@ fn cmp_range(first: &Range, other: &Range) -> std::cmp::Ordering {
         o => o,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use oxc_linter::{AllowWarnDeny, LintOptions};
+    use tower_lsp::lsp_types::Url;
+
+    use super::ServerLinter;
+    use crate::document::CancellationToken;
+
+    #[test]
+    fn fix_all() {
+        let filter = ["no-debugger", "no-prototype-builtins"]
+            .map(|rule| (AllowWarnDeny::Deny, rule.to_string()))
+            .to_vec();
+        let server_linter =
+            ServerLinter::from_options(LintOptions::default().with_filter(filter)).unwrap();
+        let fix_all = |path: &str, content: &str| {
+            let uri = Url::from_file_path(std::env::temp_dir().join(path)).unwrap();
+            server_linter.fix_all(&uri, content)
+        };
+
+        // Suggestions are not applied
+        let content = "debugger;\na.hasOwnProperty(b);\n";
+        assert_eq!(fix_all("a.js", content).as_deref(), Some("\na.hasOwnProperty(b);\n"));
+        assert_eq!(fix_all("a.js", "a.hasOwnProperty(b);\n"), None);
+        // Each script block is fixed
+        let content = "<script>\ndebugger;\n</script>\n<script setup>\ndebugger;\n</script>\n";
+        assert_eq!(
+            fix_all("a.vue", content).as_deref(),
+            Some("<script>\n\n</script>\n<script setup>\n\n</script>\n")
+        );
+    }
+
+    #[test]
+    fn importers() {
+        let dir = std::env::temp_dir().join("oxc_language_server_importers_test");
+        fs::create_dir_all(&dir).unwrap();
+        let dir = fs::canonicalize(dir).unwrap();
+        let files = [
+            ("a.js", "export const a = 1;"),
+            ("b.js", "import { a } from './a.js';\nimport './a';"),
+            ("c.js", "export { a } from './a';"),
+            ("d.js", "import { a } from './other.js';"),
+            ("other.js", "export const a = 1;"),
+        ];
+        for (name, source_text) in files {
+            fs::write(dir.join(name), source_text).unwrap();
+        }
+        let lint = |server_linter: &ServerLinter, name: &str| {
+            let uri = Url::from_file_path(dir.join(name)).unwrap();
+            server_linter.run_single(&uri, None, &CancellationToken::default());
+        };
+
+        let server_linter =
+            ServerLinter::from_options(LintOptions::default().with_import_plugin(true)).unwrap();
+        // Only the linted files are known
+        lint(&server_linter, "b.js");
+        assert_eq!(
+            server_linter.importers(&dir.join("a.js")),
+            [(dir.join("b.js"), vec!["./a.js".to_string(), "./a".to_string()])]
+        );
+        for (name, _) in files {
+            lint(&server_linter, name);
+        }
+        let mut importers = server_linter.importers(&dir.join("a.js"));
+        importers.sort_unstable();
+        assert_eq!(
+            importers.into_iter().map(|(path, _)| path).collect::<Vec<_>>(),
+            [dir.join("b.js"), dir.join("c.js")]
+        );
+        // The imports of a file are replaced when it is linted again
+        fs::write(dir.join("b.js"), "import { a } from './other.js';").unwrap();
+        lint(&server_linter, "b.js");
+        assert_eq!(
+            server_linter.importers(&dir.join("a.js")),
+            [(dir.join("c.js"), vec!["./a".to_string()])]
+        );
+
+        // No module graph without the import plugin
+        let server_linter = ServerLinter::from_options(LintOptions::default()).unwrap();
+        lint(&server_linter, "c.js");
+        assert!(server_linter.importers(&dir.join("a.js")).is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,16 +1,25 @@
+mod disable_directives;
+mod document;
+mod formatter;
 mod linter;
+mod navigation;
 mod options;
 
-use crate::linter::{DiagnosticReport, ServerLinter};
+use crate::document::{CancellationToken, Document};
+use crate::linter::{DiagnosticReport, FixedContent, ServerLinter};
 use globset::Glob;
 use ignore::gitignore::Gitignore;
 use log::{debug, error, info};
-use oxc_linter::{LintOptions, Linter};
+use oxc_linter::LintOptions;
+use oxc_prettier::{PrettierConfigResolver, PrettierOptions};
+use oxc_span::Span;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::collections::HashMap;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 
 use dashmap::DashMap;
 use futures::future::join_all;
@@ -20,10 +29,15 @@ use tower_lsp::lsp_types::{
     CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
     CodeActionProviderCapability, CodeActionResponse, ConfigurationItem, Diagnostic,
     DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-    DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-    InitializedParams, OneOf, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-    TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
-    WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+    DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentFormattingParams,
+    DocumentHighlight, DocumentHighlightKind, DocumentHighlightParams,
+    DocumentRangeFormattingParams, FormattingOptions, GotoDefinitionParams, GotoDefinitionResponse,
+    Hover, HoverContents, HoverParams, HoverProviderCapability, InitializeParams, InitializeResult,
+    InitializedParams, Location, MarkupContent, MarkupKind, NumberOrString, OneOf, Position,
+    PrepareRenameResponse, Range, ReferenceParams, RenameOptions, RenameParams, ServerCapabilities,
+    ServerInfo, TextDocumentPositionParams, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit, WorkspaceFoldersServerCapabilities,
+    WorkspaceServerCapabilities,
 };
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
@@ -33,9 +47,18 @@ struct Backend {
     root_uri: OnceCell<Option<Url>>,
     server_linter: RwLock<ServerLinter>,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    /// The opened documents, synced incrementally
+    documents: DashMap<String, Document>,
     options: Mutex<Options>,
     gitignore_glob: Mutex<Option<Gitignore>>,
 }
+
+/// How long to wait for more changes before linting the changed document
+const LINT_DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Applies the fixes of `--fix` to the whole file, e.g. on save
+const SOURCE_FIX_ALL_OXC: CodeActionKind = CodeActionKind::new("source.fixAll.oxc");
+
 #[derive(Debug, Serialize, Deserialize, Default, PartialEq, PartialOrd, Clone, Copy)]
 #[serde(rename_all = "camelCase")]
 enum Run {
@@ -105,7 +128,7 @@ impl LanguageServer for Backend {
             offset_encoding: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 workspace: Some(WorkspaceServerCapabilities {
                     workspace_folders: Some(WorkspaceFoldersServerCapabilities {
@@ -116,13 +139,23 @@ impl LanguageServer for Backend {
                 }),
                 code_action_provider: Some(CodeActionProviderCapability::Options(
                     CodeActionOptions {
-                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX, SOURCE_FIX_ALL_OXC]),
                         work_done_progress_options: WorkDoneProgressOptions {
                             work_done_progress: None,
                         },
-                        resolve_provider: None,
+                        resolve_provider: Some(true),
                     },
                 )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                document_highlight_provider: Some(OneOf::Left(true)),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
                 ..ServerCapabilities::default()
             },
         })
@@ -192,89 +225,368 @@ impl LanguageServer for Backend {
         if self.is_ignored(&uri).await {
             return;
         }
-        self.handle_file_update(uri, None, None).await;
+        self.handle_file_update(uri).await;
     }
 
     /// When the document changed, it may not be written to disk, so we should
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        let uri = params.text_document.uri;
+        // Apply the changes before awaiting anything, so that they are applied in order
+        let cancellation = {
+            let Some(mut document) = self.documents.get_mut(&uri.to_string()) else {
+                error!("changed document is not opened: {uri}");
+                return;
+            };
+            document.apply_changes(params.content_changes, params.text_document.version);
+            document.cancellation()
+        };
+
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
         }
-
-        let uri = &params.text_document.uri;
-        if self.is_ignored(uri).await {
+        if self.is_ignored(&uri).await {
             return;
         }
-        let content = params.content_changes.first().map(|c| c.text.clone());
-        self.handle_file_update(
-            params.text_document.uri,
-            content,
-            Some(params.text_document.version),
-        )
-        .await;
+        // Wait for more changes, which cancel this run
+        tokio::time::sleep(LINT_DEBOUNCE).await;
+        if cancellation.is_cancelled() {
+            return;
+        }
+        self.handle_file_update(uri).await;
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        let uri = params.text_document.uri;
+        self.documents.insert(
+            uri.to_string(),
+            Document::new(&params.text_document.text, params.text_document.version),
+        );
+
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
         }
-        if self.is_ignored(&params.text_document.uri).await {
+        if self.is_ignored(&uri).await {
             return;
         }
-        self.handle_file_update(params.text_document.uri, None, Some(params.text_document.version))
-            .await;
+        self.handle_file_update(uri).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri.to_string();
+        if let Some((_, document)) = self.documents.remove(&uri) {
+            document.cancellation().cancel();
+        }
         self.diagnostics_report_map.remove(&uri);
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
-
-        if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
-                .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
+        let only = params.context.only.as_deref();
+        let reports = self
+            .diagnostics_report_map
+            .get(&uri.to_string())
+            .map(|reports| reports.value().clone())
+            .unwrap_or_default();
+        let mut code_actions = vec![];
+
+        if is_requested(only, &CodeActionKind::QUICKFIX) {
+            let reports: Vec<&DiagnosticReport> =
+                reports.iter().filter(|r| r.diagnostic.range == params.range).collect();
+            if let Some(report) =
+                reports.iter().find(|r| r.fixed_content.is_some() || !r.suggestions.is_empty())
             {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
+                if let Some(fixed_content) = &report.fixed_content {
+                    let title = report.diagnostic.message.split(':').next().map_or_else(
                         || "Fix this problem".into(),
                         |s| format!("Fix this {s} problem"),
                     );
+                    code_actions.push(quick_fix(&uri, title, fixed_content.clone(), true));
+                }
+
+                // Every suggestion is a separate code action, none of them is preferred
+                for suggestion in &report.suggestions {
+                    code_actions.push(quick_fix(
+                        &uri,
+                        suggestion.title.clone(),
+                        suggestion.fixed_content.clone(),
+                        false,
+                    ));
+                }
+            }
+            code_actions.extend(self.disable_actions(&uri, params.range.start, &reports));
+        }
 
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
-                        ..WorkspaceEdit::default()
-                    }),
-                    disabled: None,
-                    data: None,
-                    diagnostics: None,
-                    command: None,
-                })]));
+        // Computed right away when requested, e.g. on save. Along with the quick fixes,
+        // it is only offered when there is something to fix and computed when it is resolved.
+        if only.is_some() && is_requested(only, &SOURCE_FIX_ALL_OXC) {
+            code_actions.extend(self.fix_all(&uri).await.map(CodeActionOrCommand::CodeAction));
+        } else if only.is_none() && reports.iter().any(|r| r.fixed_content.is_some()) {
+            let action = CodeAction { data: Some(json!(uri)), ..fix_all_action(&uri, vec![]) };
+            code_actions.push(CodeActionOrCommand::CodeAction(action));
+        }
+
+        Ok((!code_actions.is_empty()).then_some(code_actions))
+    }
+
+    async fn code_action_resolve(&self, params: CodeAction) -> Result<CodeAction> {
+        if params.kind != Some(SOURCE_FIX_ALL_OXC) || params.edit.is_some() {
+            return Ok(params);
+        }
+        let Some(uri) = params.data.clone().and_then(|data| serde_json::from_value(data).ok())
+        else {
+            return Ok(params);
+        };
+        Ok(self.fix_all(&uri).await.unwrap_or(params))
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> Result<Option<GotoDefinitionResponse>> {
+        let position = params.text_document_position_params;
+        let uri = position.text_document.uri;
+        Ok(self.with_document(&uri, |path, document| {
+            let offset = document.position_to_offset(position.position);
+            let span = navigation::definition(path, &document.text(), offset)?;
+            Some(GotoDefinitionResponse::Scalar(Location::new(
+                uri.clone(),
+                document.span_to_range(span),
+            )))
+        }))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let position = params.text_document_position;
+        let uri = position.text_document.uri;
+        Ok(self.with_document(&uri, |path, document| {
+            let offset = document.position_to_offset(position.position);
+            let occurrences = navigation::occurrences(path, &document.text(), offset)?;
+            Some(
+                occurrences
+                    .into_iter()
+                    .filter(|o| params.context.include_declaration || !o.is_declaration)
+                    .map(|o| Location::new(uri.clone(), document.span_to_range(o.span)))
+                    .collect(),
+            )
+        }))
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let position = params.text_document_position_params;
+        Ok(self.with_document(&position.text_document.uri, |path, document| {
+            let offset = document.position_to_offset(position.position);
+            let occurrences = navigation::occurrences(path, &document.text(), offset)?;
+            Some(
+                occurrences
+                    .into_iter()
+                    .map(|o| DocumentHighlight {
+                        range: document.span_to_range(o.span),
+                        kind: Some(if o.is_write {
+                            DocumentHighlightKind::WRITE
+                        } else {
+                            DocumentHighlightKind::READ
+                        }),
+                    })
+                    .collect(),
+            )
+        }))
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params;
+        Ok(self.with_document(&position.text_document.uri, |path, document| {
+            let offset = document.position_to_offset(position.position);
+            let (span, contents) = navigation::hover(path, &document.text(), offset)?;
+            Some(Hover {
+                contents: HoverContents::Markup(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: contents,
+                }),
+                range: Some(document.span_to_range(span)),
+            })
+        }))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        Ok(self.with_document(&params.text_document.uri, |path, document| {
+            let offset = document.position_to_offset(params.position);
+            let (span, name) = navigation::prepare_rename(path, &document.text(), offset)?;
+            Some(PrepareRenameResponse::RangeWithPlaceholder {
+                range: document.span_to_range(span),
+                placeholder: name,
+            })
+        }))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let position = params.text_document_position;
+        let uri = position.text_document.uri;
+        let new_name = params.new_name;
+        let Some(rename) = self.with_document(&uri, |path, document| {
+            let offset = document.position_to_offset(position.position);
+            let rename = navigation::rename(path, &document.text(), offset, &new_name)?;
+            Some(rename.map(|rename| {
+                let edits = text_edits(document, &rename.edits);
+                (rename, edits)
+            }))
+        }) else {
+            return Ok(None);
+        };
+        let (rename, edits) = rename.map_err(Error::invalid_params)?;
+        let mut changes = HashMap::from([(uri.clone(), edits)]);
+
+        // Follow the exported binding into the importing modules linted by the server,
+        // they are only known with the import plugin
+        let importers = match uri.to_file_path() {
+            Ok(path) if rename.is_exported => self.server_linter.read().await.importers(&path),
+            _ => vec![],
+        };
+        if !importers.is_empty() {
+            let documents = self
+                .documents
+                .iter()
+                .filter_map(|document| {
+                    let path = Url::parse(document.key()).ok()?.to_file_path().ok()?;
+                    Some((path, document.text()))
+                })
+                .collect::<HashMap<_, _>>();
+            let importers = tokio::task::spawn_blocking(move || {
+                navigation::rename_importers(importers, &rename.name, &new_name, &documents)
+            })
+            .await
+            .map_err(|_| Error::internal_error())?;
+            let importers = importers.map_err(Error::invalid_params)?;
+            for (path, source_text, edits) in importers {
+                let Ok(uri) = Url::from_file_path(&path) else {
+                    continue;
+                };
+                let document = Document::new(&source_text, 0);
+                changes.insert(uri, text_edits(&document, &edits));
             }
         }
 
-        Ok(None)
+        Ok(Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }))
+    }
+
+    async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+        let Ok(path) = uri.to_file_path() else {
+            return Ok(None);
+        };
+        let options = prettier_options(&path, &params.options)?;
+        Ok(self.with_document(&uri, |path, document| {
+            let source_text = document.text();
+            let code = formatter::format(path, &source_text, options)?;
+            #[allow(clippy::cast_possible_truncation)]
+            let span = Span::new(0, source_text.len() as u32);
+            (code != source_text)
+                .then(|| vec![TextEdit { range: document.span_to_range(span), new_text: code }])
+        }))
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let uri = params.text_document.uri;
+        let Ok(path) = uri.to_file_path() else {
+            return Ok(None);
+        };
+        let options = prettier_options(&path, &params.options)?;
+        Ok(self.with_document(&uri, |path, document| {
+            #[allow(clippy::cast_possible_truncation)]
+            let range = Span::new(
+                document.position_to_offset(params.range.start) as u32,
+                document.position_to_offset(params.range.end) as u32,
+            );
+            let source_text = document.text();
+            let (span, code) = formatter::format_range(path, &source_text, range, options)?;
+            (code != span.source_text(&source_text))
+                .then(|| vec![TextEdit { range: document.span_to_range(span), new_text: code }])
+        }))
     }
 }
 
+/// The options of the client, with the Prettier configuration and `.editorconfig` files
+/// of the project applied over them
+fn prettier_options(path: &Path, options: &FormattingOptions) -> Result<PrettierOptions> {
+    let options = PrettierOptions {
+        tab_width: options.tab_size as usize,
+        use_tabs: !options.insert_spaces,
+        ..PrettierOptions::default()
+    };
+    // Read the configuration files on every request, as they may have been changed
+    PrettierConfigResolver::new().resolve_with(path, options).map_err(|error| Error {
+        code: ErrorCode::InternalError,
+        message: error.to_string().into(),
+        data: None,
+    })
+}
+
+fn text_edits(document: &Document, edits: &[navigation::Edit]) -> Vec<TextEdit> {
+    edits
+        .iter()
+        .map(|(span, new_text)| TextEdit {
+            range: document.span_to_range(*span),
+            new_text: new_text.clone(),
+        })
+        .collect()
+}
+
+/// Whether `kind` is requested by the `only` filter of the client, which may be a parent kind
+fn is_requested(only: Option<&[CodeActionKind]>, kind: &CodeActionKind) -> bool {
+    only.map_or(true, |only| {
+        only.iter().any(|requested| {
+            kind.as_str()
+                .strip_prefix(requested.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+        })
+    })
+}
+
+/// The `source.fixAll.oxc` action, without edit until it is resolved when `edits` is empty
+fn fix_all_action(uri: &Url, edits: Vec<TextEdit>) -> CodeAction {
+    CodeAction {
+        title: "Fix all auto-fixable problems".into(),
+        kind: Some(SOURCE_FIX_ALL_OXC),
+        edit: (!edits.is_empty()).then(|| WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        ..CodeAction::default()
+    }
+}
+
+fn quick_fix(
+    uri: &Url,
+    title: String,
+    fixed_content: FixedContent,
+    is_preferred: bool,
+) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(is_preferred),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), fixed_content.edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        disabled: None,
+        data: None,
+        diagnostics: None,
+        command: None,
+    })
+}
+
 impl Backend {
     fn init(&self, root_uri: Option<Url>) -> Result<()> {
         self.root_uri.set(root_uri).map_err(|err| {
@@ -344,29 +656,130 @@ impl Backend {
         }
         if let Some(config_path) = config_path {
             let mut linter = self.server_linter.write().await;
-            *linter = ServerLinter::new_with_linter(
-                Linter::from_options(
-                    LintOptions::default().with_fix(true).with_config_path(Some(config_path)),
-                )
-                .expect("should have initialized linter with new options"),
-            );
+            *linter = ServerLinter::from_options(
+                LintOptions::default().with_config_path(Some(config_path)),
+            )
+            .expect("should have initialized linter with new options");
         }
     }
 
-    async fn handle_file_update(&self, uri: Url, content: Option<String>, version: Option<i32>) {
-        if let Some(Some(_root_uri)) = self.root_uri.get() {
-            if let Some(diagnostics) = self.server_linter.read().await.run_single(&uri, content) {
-                self.client
-                    .publish_diagnostics(
-                        uri.clone(),
-                        diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
-                        version,
-                    )
-                    .await;
+    /// Lint the current version of the document, or the file on disk if it is not opened
+    async fn handle_file_update(&self, uri: Url) {
+        let Some(Some(_root_uri)) = self.root_uri.get() else {
+            return;
+        };
+        let key = uri.to_string();
+        let (content, version, cancellation) =
+            self.documents.get(&key).map_or((None, None, CancellationToken::default()), |d| {
+                (Some(d.text()), Some(d.version()), d.cancellation())
+            });
+
+        let server_linter = self.server_linter.read().await.clone();
+        let run = {
+            let uri = uri.clone();
+            let cancellation = cancellation.clone();
+            tokio::task::spawn_blocking(move || {
+                server_linter.run_single(&uri, content, &cancellation)
+            })
+        };
+        let Ok(Some(diagnostics)) = run.await else {
+            return;
+        };
+
+        // The diagnostics of an outdated version would overwrite the ones of the newer version
+        if cancellation.is_cancelled() || self.documents.get(&key).map(|d| d.version()) != version {
+            debug!("discard outdated diagnostics of {uri}");
+            return;
+        }
+        self.client
+            .publish_diagnostics(
+                uri,
+                diagnostics.clone().into_iter().map(|d| d.diagnostic).collect(),
+                version,
+            )
+            .await;
 
-                self.diagnostics_report_map.insert(uri.to_string(), diagnostics);
+        self.diagnostics_report_map.insert(key, diagnostics);
+    }
+
+    /// The actions disabling the rules of `reports` for the line of `position` or the file
+    fn disable_actions(
+        &self,
+        uri: &Url,
+        position: Position,
+        reports: &[&DiagnosticReport],
+    ) -> Vec<CodeActionOrCommand> {
+        let mut rules: Vec<String> = vec![];
+        for report in reports {
+            let Some(NumberOrString::String(rule)) = &report.diagnostic.code else {
+                continue;
+            };
+            if !rules.contains(rule) {
+                rules.push(rule.clone());
             }
         }
+        self.with_document(uri, |path, document| {
+            let source_text = document.text();
+            let offset = document.position_to_offset(position);
+            let actions = rules
+                .iter()
+                .flat_map(|rule| {
+                    [
+                        disable_directives::disable_next_line(path, &source_text, offset, rule)
+                            .map(|insertion| (format!("Disable {rule} for this line"), insertion)),
+                        disable_directives::disable_file(path, &source_text, offset, rule).map(
+                            |insertion| (format!("Disable {rule} for the entire file"), insertion),
+                        ),
+                    ]
+                })
+                .flatten()
+                .map(|(title, insertion)| {
+                    let position = document.offset_to_position(insertion.offset);
+                    let edit = TextEdit {
+                        range: Range::new(position, position),
+                        new_text: insertion.text,
+                    };
+                    quick_fix(uri, title, FixedContent { edits: vec![edit] }, false)
+                })
+                .collect();
+            Some(actions)
+        })
+        .unwrap_or_default()
+    }
+
+    /// The action applying the fixes of `--fix` to the opened document
+    async fn fix_all(&self, uri: &Url) -> Option<CodeAction> {
+        let (content, version) =
+            self.documents.get(&uri.to_string()).map(|d| (d.text(), d.version()))?;
+        let server_linter = self.server_linter.read().await.clone();
+        let fixed_code = {
+            let uri = uri.clone();
+            let content = content.clone();
+            tokio::task::spawn_blocking(move || server_linter.fix_all(&uri, &content))
+                .await
+                .ok()??
+        };
+
+        // The edit of an outdated version would overwrite the newer changes
+        let document = self.documents.get(&uri.to_string())?;
+        if document.version() != version {
+            return None;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(0, content.len() as u32);
+        let edit = TextEdit { range: document.span_to_range(span), new_text: fixed_code };
+        Some(fix_all_action(uri, vec![edit]))
+    }
+
+    /// Call `f` with the path and the current version of the opened document
+    fn with_document<T>(
+        &self,
+        uri: &Url,
+        f: impl FnOnce(&Path, &Document) -> Option<T>,
+    ) -> Option<T> {
+        let path = uri.to_file_path().ok()?;
+        let document = self.documents.get(&uri.to_string())?;
+        f(&path, &document)
     }
 
     async fn is_ignored(&self, uri: &Url) -> bool {
@@ -378,11 +791,11 @@ impl Backend {
         if !uri.path().starts_with(root_uri.path()) {
             return false;
         }
-        let Some(ref gitignore_globs) = *self.gitignore_glob.lock().await else {
-            return false;
-        };
         let path = PathBuf::from(uri.path());
-        let ignored = gitignore_globs.matched_path_or_any_parents(&path, path.is_dir()).is_ignore();
+        let ignored = self.server_linter.read().await.is_ignored(&path)
+            || self.gitignore_glob.lock().await.as_ref().is_some_and(|gitignore_globs| {
+                gitignore_globs.matched_path_or_any_parents(&path, path.is_dir()).is_ignore()
+            });
         if ignored {
             debug!("ignored: {uri}");
         }
@@ -405,6 +818,7 @@ async fn main() {
         root_uri: OnceCell::new(),
         server_linter: RwLock::new(server_linter),
         diagnostics_report_map,
+        documents: DashMap::new(),
         options: Mutex::new(Options::default()),
         gitignore_glob: Mutex::new(None),
     })
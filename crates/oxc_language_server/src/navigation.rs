@@ -0,0 +1,623 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{AssignmentTarget, AssignmentTargetPattern, AssignmentTargetProperty},
+    AstKind,
+};
+use oxc_linter::partial_loader::{JavaScriptSource, PartialLoader};
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    module_record::{ExportImportName, ImportImportName},
+};
+
+/// An occurrence of a symbol
+#[derive(Debug, Clone, Copy)]
+pub struct Occurrence {
+    pub span: Span,
+    pub is_declaration: bool,
+    /// Whether the symbol is declared or written here
+    pub is_write: bool,
+}
+
+/// The span of the declaration of the symbol at `offset`
+pub fn definition(path: &Path, source_text: &str, offset: usize) -> Option<Span> {
+    with_semantic(path, source_text, offset, |semantic, offset| {
+        let (symbol_id, _) = symbol_at(semantic, offset)?;
+        Some(semantic.symbols().get_span(symbol_id))
+    })
+    .map(|(span, start)| shift(span, start))
+}
+
+/// The declaration and the references of the symbol at `offset`, in source order
+pub fn occurrences(path: &Path, source_text: &str, offset: usize) -> Option<Vec<Occurrence>> {
+    let (occurrences, start) = with_semantic(path, source_text, offset, |semantic, offset| {
+        let (symbol_id, _) = symbol_at(semantic, offset)?;
+        let declaration = Occurrence {
+            span: semantic.symbols().get_span(symbol_id),
+            is_declaration: true,
+            is_write: true,
+        };
+        let references = semantic.symbol_references(symbol_id).map(|reference| Occurrence {
+            span: reference.span(),
+            is_declaration: false,
+            is_write: reference.is_write(),
+        });
+        let mut occurrences = std::iter::once(declaration).chain(references).collect::<Vec<_>>();
+        occurrences.sort_unstable_by_key(|occurrence| occurrence.span.start);
+        occurrences.dedup_by_key(|occurrence| occurrence.span);
+        Some(occurrences)
+    })?;
+    Some(
+        occurrences
+            .into_iter()
+            .map(|occurrence| Occurrence { span: shift(occurrence.span, start), ..occurrence })
+            .collect(),
+    )
+}
+
+/// The span of the identifier at `offset` and the Markdown describing its symbol:
+/// the flags of the symbol and the JSDoc of its declaration
+pub fn hover(path: &Path, source_text: &str, offset: usize) -> Option<(Span, String)> {
+    with_semantic(path, source_text, offset, |semantic, offset| {
+        let (symbol_id, span) = symbol_at(semantic, offset)?;
+        let symbols = semantic.symbols();
+        let flags = symbols
+            .get_flag(symbol_id)
+            .iter_names()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let mut contents = format!("```typescript\n({flags}) {}\n```", symbols.get_name(symbol_id));
+
+        let nodes = semantic.nodes();
+        // The JSDoc of `const a = 1` is attached to the declaration of the declarator,
+        // and the one of `export const a = 1` to the export declaration
+        let jsdoc = nodes
+            .ancestors(symbols.get_declaration(symbol_id))
+            .take_while(|node_id| {
+                *node_id == symbols.get_declaration(symbol_id)
+                    || matches!(
+                        nodes.kind(*node_id),
+                        AstKind::VariableDeclaration(_)
+                            | AstKind::ModuleDeclaration(_)
+                            | AstKind::ExportNamedDeclaration(_)
+                            | AstKind::ExportDefaultDeclaration(_)
+                    )
+            })
+            .find_map(|node_id| semantic.jsdoc().get_one_by_node(nodes.get_node(node_id)));
+        if let Some(jsdoc) = jsdoc {
+            if !jsdoc.comment().is_empty() {
+                contents.push_str("\n\n");
+                contents.push_str(jsdoc.comment());
+            }
+            for tag in jsdoc.tags() {
+                contents.push_str(&format!("\n\n*@{}* {}", tag.tag_name(), tag.comment));
+            }
+        }
+        Some((span, contents))
+    })
+    .map(|((span, contents), start)| (shift(span, start), contents))
+}
+
+/// The span of the identifier at `offset` and the name of its symbol, if it can be renamed
+pub fn prepare_rename(path: &Path, source_text: &str, offset: usize) -> Option<(Span, String)> {
+    with_semantic(path, source_text, offset, |semantic, offset| {
+        let (symbol_id, span) = symbol_at(semantic, offset)?;
+        Some((span, semantic.symbols().get_name(symbol_id).to_string()))
+    })
+    .map(|((span, name), start)| (shift(span, start), name))
+}
+
+/// A span to replace and its new text
+pub type Edit = (Span, String);
+
+/// The renaming of a symbol in a file
+#[derive(Debug)]
+pub struct Rename {
+    /// The name before the renaming
+    pub name: String,
+    /// The spans to replace and their new text, in source order.
+    /// The text is the new name, or `name: new_name` for a shorthand property.
+    pub edits: Vec<Edit>,
+    /// Whether the symbol is exported under its own name, so the importing modules are affected
+    pub is_exported: bool,
+}
+
+/// Rename the symbol at `offset` and its references to `new_name`.
+/// Returns `None` if there is no symbol at `offset`.
+///
+/// # Errors
+///
+/// * When `new_name` is not a valid binding name
+/// * When the symbol or its references would be shadowed by another binding of `new_name`,
+///   or a declaration of `new_name` would shadow other references
+pub fn rename(
+    path: &Path,
+    source_text: &str,
+    offset: usize,
+    new_name: &str,
+) -> Option<Result<Rename, String>> {
+    let source_type = SourceType::from_path(path).unwrap_or_default();
+    if !is_binding_name(new_name, source_type) {
+        return Some(Err(format!("`{new_name}` is not a valid name")));
+    }
+    let (rename, start) = with_semantic(path, source_text, offset, |semantic, offset| {
+        let (symbol_id, _) = symbol_at(semantic, offset)?;
+        Some(rename_symbol(semantic, symbol_id, new_name))
+    })?;
+    Some(rename.map(|rename| Rename {
+        edits: rename.edits.into_iter().map(|(span, text)| (shift(span, start), text)).collect(),
+        ..rename
+    }))
+}
+
+/// The edits renaming the exported binding `name` to `new_name` in `importers`,
+/// the modules importing it with the specifiers requesting it, see `ServerLinter::importers`.
+/// The source text of an opened document is taken from `documents` instead of the file.
+///
+/// `import { name } from` is renamed together with the local binding and its references,
+/// `import { name as alias } from` and `export { name } from` are renamed without following the alias.
+///
+/// # Errors
+///
+/// * When the local binding of an import can't be renamed, see [rename]
+pub fn rename_importers(
+    importers: Vec<(PathBuf, Vec<String>)>,
+    name: &str,
+    new_name: &str,
+    documents: &HashMap<PathBuf, String>,
+) -> Result<Vec<(PathBuf, String, Vec<Edit>)>, String> {
+    let mut renamed = vec![];
+    for (path, specifiers) in importers {
+        let Ok(source_type) = SourceType::from_path(&path) else {
+            continue;
+        };
+        let Some(source_text) =
+            documents.get(&path).cloned().or_else(|| fs::read_to_string(&path).ok())
+        else {
+            continue;
+        };
+
+        let mut edits = {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source_text, source_type).parse();
+            let program = allocator.alloc(ret.program);
+            let semantic = SemanticBuilder::new(&source_text, source_type)
+                .with_trivias(ret.trivias)
+                .build_module_record(path.clone(), program)
+                .build(program)
+                .semantic;
+
+            let is_exporter = |specifier: &str| specifiers.iter().any(|s| s == specifier);
+            let module_record = semantic.module_record();
+            let mut edits = vec![];
+            for import_entry in &module_record.import_entries {
+                let ImportImportName::Name(import_name) = &import_entry.import_name else {
+                    continue;
+                };
+                if import_name.name().as_str() != name
+                    || !is_exporter(import_entry.module_request.name())
+                {
+                    continue;
+                }
+                edits.push((import_name.span(), new_name.to_string()));
+                let Some(symbol_id) = semantic.scopes().get_root_binding(name) else {
+                    continue;
+                };
+                if import_entry.local_name.span() == import_name.span() {
+                    let rename = rename_symbol(&semantic, symbol_id, new_name)
+                        .map_err(|message| format!("{}: {message}", path.display()))?;
+                    edits.extend(rename.edits);
+                }
+            }
+            for export_entry in &module_record.indirect_export_entries {
+                let ExportImportName::Name(import_name) = &export_entry.import_name else {
+                    continue;
+                };
+                if import_name.name().as_str() == name
+                    && export_entry
+                        .module_request
+                        .as_ref()
+                        .is_some_and(|module_request| is_exporter(module_request.name()))
+                {
+                    edits.push((import_name.span(), new_name.to_string()));
+                }
+            }
+            edits
+        };
+        if !edits.is_empty() {
+            edits.sort_unstable_by_key(|(span, _)| span.start);
+            edits.dedup_by_key(|(span, _)| *span);
+            renamed.push((path, source_text, edits));
+        }
+    }
+    Ok(renamed)
+}
+
+/// Rename the declaration, the references and the `export { name }` specifiers of `symbol_id`
+fn rename_symbol(
+    semantic: &Semantic,
+    symbol_id: SymbolId,
+    new_name: &str,
+) -> Result<Rename, String> {
+    let symbols = semantic.symbols();
+    let scopes = semantic.scopes();
+    let nodes = semantic.nodes();
+    let name = symbols.get_name(symbol_id);
+    let symbol_scope = symbols.get_scope_id(symbol_id);
+    let reference_scope = |node_id| nodes.get_node(node_id).scope_id();
+
+    if name == new_name {
+        return Err("The new name is the same as the current name".to_string());
+    }
+    if scopes.has_binding(symbol_scope, new_name) {
+        return Err(format!("`{new_name}` is already declared in the scope of `{name}`"));
+    }
+    // A reference would resolve to a binding of `new_name` between the reference and the symbol
+    for reference in semantic.symbol_references(symbol_id) {
+        let mut scope_ids = scopes
+            .ancestors(reference_scope(reference.node_id()))
+            .take_while(|scope_id| *scope_id != symbol_scope);
+        if scope_ids.any(|scope_id| scopes.has_binding(scope_id, new_name)) {
+            return Err(format!("A reference to `{name}` would be shadowed by `{new_name}`"));
+        }
+    }
+    // A reference to `new_name` declared outside of the scope of the symbol would resolve to the symbol
+    for reference in
+        symbols.references.iter().filter(|reference| reference.name().as_str() == new_name)
+    {
+        let mut inner_scopes = vec![];
+        let mut is_in_symbol_scope = false;
+        for scope_id in scopes.ancestors(reference_scope(reference.node_id())) {
+            if scope_id == symbol_scope {
+                is_in_symbol_scope = true;
+                break;
+            }
+            inner_scopes.push(scope_id);
+        }
+        let is_resolved_inside = reference.symbol_id().is_some_and(|reference_symbol| {
+            inner_scopes.contains(&symbols.get_scope_id(reference_symbol))
+        });
+        if is_in_symbol_scope && !is_resolved_inside {
+            return Err(format!(
+                "`{name}` renamed to `{new_name}` would shadow a reference to `{new_name}`"
+            ));
+        }
+    }
+
+    let mut spans = std::iter::once(symbols.get_span(symbol_id))
+        .chain(semantic.symbol_references(symbol_id).map(oxc_semantic::Reference::span))
+        .collect::<Vec<_>>();
+    let mut is_exported = semantic
+        .module_record()
+        .exported_bindings
+        .get(name)
+        .is_some_and(|span| *span == symbols.get_span(symbol_id));
+    // `export { name }` refers to the binding of the root scope without a reference
+    if symbol_scope == scopes.root_scope_id() {
+        for node in nodes.iter() {
+            let AstKind::ExportNamedDeclaration(decl) = node.kind() else {
+                continue;
+            };
+            if decl.source.is_some() {
+                continue;
+            }
+            for specifier in decl.specifiers.iter().filter(|s| s.local.name().as_str() == name) {
+                spans.push(specifier.local.span());
+                is_exported |= specifier.exported.span() == specifier.local.span();
+            }
+        }
+    }
+    spans.sort_unstable_by_key(|span| span.start);
+    spans.dedup();
+    // The property of a shorthand keeps its name
+    let shorthands = shorthand_spans(semantic);
+    let edits = spans
+        .into_iter()
+        .map(|span| {
+            let text = if shorthands.contains(&span) {
+                format!("{name}: {new_name}")
+            } else {
+                new_name.to_string()
+            };
+            (span, text)
+        })
+        .collect();
+    Ok(Rename { name: name.to_string(), edits, is_exported })
+}
+
+/// The spans of the identifiers of shorthand properties, which are both the key and the value:
+/// `{ a }`, `const { a } = obj` and `({ a } = obj)`
+fn shorthand_spans(semantic: &Semantic) -> HashSet<Span> {
+    let mut spans = HashSet::new();
+    for node in semantic.nodes().iter() {
+        match node.kind() {
+            AstKind::ObjectProperty(property) if property.shorthand => {
+                spans.insert(property.key.span());
+            }
+            AstKind::ObjectPattern(pattern) => {
+                spans.extend(
+                    pattern
+                        .properties
+                        .iter()
+                        .filter(|property| property.shorthand)
+                        .map(|property| property.key.span()),
+                );
+            }
+            AstKind::AssignmentTarget(AssignmentTarget::AssignmentTargetPattern(
+                AssignmentTargetPattern::ObjectAssignmentTarget(target),
+            )) => {
+                spans.extend(target.properties.iter().filter_map(|property| match property {
+                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property) => {
+                        Some(property.binding.span)
+                    }
+                    AssignmentTargetProperty::AssignmentTargetPropertyProperty(_) => None,
+                }));
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+/// Whether `name` can be declared with `let` in the source type
+fn is_binding_name(name: &str, source_type: SourceType) -> bool {
+    if !is_identifier_name(name) {
+        return false;
+    }
+    let source_text = format!("let {name};");
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    ret.errors.is_empty()
+}
+
+/// The symbol declared or referenced by the identifier at `offset`, and the span of the identifier
+fn symbol_at(semantic: &Semantic, offset: u32) -> Option<(SymbolId, Span)> {
+    let symbols = semantic.symbols();
+    let declarations = symbols.iter().map(|symbol_id| (symbol_id, symbols.get_span(symbol_id)));
+    let references = symbols
+        .references
+        .iter()
+        .filter_map(|reference| Some((reference.symbol_id()?, reference.span())));
+    // The end is included for the cursor right after the identifier
+    declarations.chain(references).find(|(_, span)| span.start <= offset && offset <= span.end)
+}
+
+/// Build the [Semantic] of the JavaScript source of the file at `path` containing `offset`,
+/// and call `f` with it and the offset in the JavaScript source.
+/// Returns the result and the start of the JavaScript source in the file.
+pub fn with_semantic<T, F>(
+    path: &Path,
+    source_text: &str,
+    offset: usize,
+    f: F,
+) -> Option<(T, usize)>
+where
+    F: FnOnce(&Semantic, u32) -> Option<T>,
+{
+    let source = source_at(path, source_text, offset)?;
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source.source_text, source.source_type)
+        .allow_return_outside_function(true)
+        .parse();
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source.source_text, source.source_type)
+        .with_trivias(ret.trivias)
+        .build_module_record(path.to_path_buf(), program)
+        .build(program)
+        .semantic;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let offset = (offset - source.start) as u32;
+    f(&semantic, offset).map(|result| (result, source.start))
+}
+
+/// The JavaScript source containing `offset`, the script block of Vue, Astro and Svelte files
+pub fn source_at<'a>(
+    path: &Path,
+    source_text: &'a str,
+    offset: usize,
+) -> Option<JavaScriptSource<'a>> {
+    let ext = path.extension().and_then(std::ffi::OsStr::to_str)?;
+    if let Some(sources) = PartialLoader::parse(ext, source_text) {
+        sources.into_iter().find(|source| {
+            source.start <= offset && offset <= source.start + source.source_text.len()
+        })
+    } else {
+        let source_type = SourceType::from_path(path).ok()?;
+        Some(JavaScriptSource { source_text, source_type, start: 0 })
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn shift(span: Span, start: usize) -> Span {
+    Span::new(span.start + start as u32, span.end + start as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::HashMap, fs, path::Path};
+
+    use oxc_span::Span;
+
+    use super::{definition, hover, occurrences, rename, rename_importers, Edit};
+
+    /// The offset of the `n`th occurrence of `text` in `source_text`
+    fn offset(source_text: &str, text: &str, n: usize) -> usize {
+        source_text.match_indices(text).nth(n).unwrap().0
+    }
+
+    fn span_of(source_text: &str, text: &str, n: usize) -> Span {
+        let start = offset(source_text, text, n);
+        #[allow(clippy::cast_possible_truncation)]
+        Span::new(start as u32, (start + text.len()) as u32)
+    }
+
+    fn apply(source_text: &str, edits: &[Edit]) -> String {
+        let mut code = source_text.to_string();
+        for (span, text) in edits.iter().rev() {
+            code.replace_range(span.start as usize..span.end as usize, text);
+        }
+        code
+    }
+
+    fn rename_at(source_text: &str, offset: usize, new_name: &str) -> String {
+        let rename = rename(Path::new("a.js"), source_text, offset, new_name).unwrap().unwrap();
+        apply(source_text, &rename.edits)
+    }
+
+    #[test]
+    fn unresolved_reference() {
+        let source_text = "foo(); const a = 1;";
+        let path = Path::new("a.js");
+        assert_eq!(definition(path, source_text, 1), None);
+        assert!(occurrences(path, source_text, 1).is_none());
+        assert_eq!(hover(path, source_text, 1), None);
+        assert!(rename(path, source_text, 1, "bar").is_none());
+    }
+
+    #[test]
+    fn shadowed_binding() {
+        let source_text = "const a = 1; function f() { const a = 2; return a; } a;";
+        let path = Path::new("a.js");
+        // The inner reference resolves to the inner binding, the outer one to the outer binding
+        let inner = offset(source_text, "a;", 0);
+        assert_eq!(definition(path, source_text, inner), Some(span_of(source_text, "a", 1)));
+        let outer = offset(source_text, "a;", 1);
+        assert_eq!(definition(path, source_text, outer), Some(span_of(source_text, "a", 0)));
+        let spans = |offset| {
+            occurrences(path, source_text, offset)
+                .unwrap()
+                .into_iter()
+                .map(|occurrence| occurrence.span)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(spans(inner), [span_of(source_text, "a", 1), span_of(source_text, "a", 2)]);
+        assert_eq!(spans(outer), [span_of(source_text, "a", 0), span_of(source_text, "a", 3)]);
+    }
+
+    #[test]
+    fn import_specifier() {
+        let source_text = "import { a, b as c } from './a';\na; c;";
+        let path = Path::new("a.js");
+        let a = offset(source_text, "a;", 0);
+        assert_eq!(definition(path, source_text, a), Some(span_of(source_text, "a", 0)));
+        // The definition of an alias is the alias, not the imported name
+        let c = offset(source_text, "c;", 0);
+        assert_eq!(definition(path, source_text, c), Some(span_of(source_text, "c", 0)));
+        let (_, contents) = hover(path, source_text, c).unwrap();
+        assert!(contents.contains("(ImportBinding) c"), "{contents}");
+    }
+
+    /// `documentHighlight` is made of the occurrences, written ones are highlighted as writes
+    #[test]
+    fn highlight() {
+        let source_text = "let a = 1; a = 2; a;";
+        let occurrences = occurrences(Path::new("a.js"), source_text, 4).unwrap();
+        let kinds = occurrences
+            .iter()
+            .map(|occurrence| {
+                (occurrence.span.start, occurrence.is_declaration, occurrence.is_write)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(kinds, [(4, true, true), (11, false, true), (18, false, false)]);
+    }
+
+    #[test]
+    fn hover_declaration_and_reference() {
+        let source_text = "/**\n * The answer\n * @see question\n */\nconst answer = 42;\nanswer;";
+        let path = Path::new("a.js");
+        let declaration = offset(source_text, "answer", 1);
+        let reference = offset(source_text, "answer", 2);
+        let (declaration_span, declaration_contents) =
+            hover(path, source_text, declaration).unwrap();
+        let (reference_span, reference_contents) = hover(path, source_text, reference).unwrap();
+        // The span is the hovered identifier, the contents describe the same symbol
+        assert_eq!(declaration_span, span_of(source_text, "answer", 1));
+        assert_eq!(reference_span, span_of(source_text, "answer", 2));
+        assert_eq!(declaration_contents, reference_contents);
+        assert!(declaration_contents.starts_with("```typescript\n("), "{declaration_contents}");
+        assert!(declaration_contents.contains(") answer\n```"), "{declaration_contents}");
+        assert!(declaration_contents.contains("The answer"), "{declaration_contents}");
+        assert!(declaration_contents.contains("*@see* question"), "{declaration_contents}");
+        // No JSDoc
+        let (_, contents) = hover(path, "let a; a;", 7).unwrap();
+        assert!(!contents.contains("\n\n"), "{contents}");
+    }
+
+    #[test]
+    fn shorthand_object_literal() {
+        assert_eq!(
+            rename_at("const a = 1; const o = { a, c: a };", 6, "b"),
+            "const b = 1; const o = { a: b, c: b };"
+        );
+    }
+
+    #[test]
+    fn shorthand_destructuring() {
+        assert_eq!(rename_at("const { a } = obj; a;", 8, "b"), "const { a: b } = obj; b;");
+        assert_eq!(rename_at("const { a = 1 } = obj; a;", 8, "b"), "const { a: b = 1 } = obj; b;");
+        assert_eq!(rename_at("let a; ({ a } = obj);", 4, "b"), "let b; ({ a: b } = obj);");
+        assert_eq!(
+            rename_at("let a; ({ x: { a = 1 } } = obj);", 4, "b"),
+            "let b; ({ x: { a: b = 1 } } = obj);"
+        );
+        // Not a shorthand
+        assert_eq!(rename_at("const { x: a } = obj; a;", 11, "b"), "const { x: b } = obj; b;");
+    }
+
+    #[test]
+    fn importers() {
+        let dir = std::env::temp_dir().join("oxc_language_server_rename_importers_test");
+        fs::create_dir_all(&dir).unwrap();
+        let files = [
+            ("b.js", "import { a } from './a.js';\nconst o = { a };"),
+            ("c.js", "import { a as x } from './a';\nx;"),
+            ("d.js", "export { a } from './a';"),
+            ("e.js", "import { a } from './other.js';\nimport './a';\na;"),
+        ];
+        for (name, source_text) in files {
+            fs::write(dir.join(name), source_text).unwrap();
+        }
+        let importers = [("b.js", "./a.js"), ("c.js", "./a"), ("d.js", "./a"), ("e.js", "./a")]
+            .map(|(name, specifier)| (dir.join(name), vec![specifier.to_string()]))
+            .to_vec();
+        // The opened document is renamed instead of the file
+        let documents =
+            HashMap::from([(dir.join("c.js"), "import { a as y } from './a';\ny;".to_string())]);
+
+        let importers = rename_importers(importers, "a", "b", &documents)
+            .unwrap()
+            .into_iter()
+            .map(|(path, source_text, edits)| {
+                (
+                    path.file_name().unwrap().to_string_lossy().to_string(),
+                    apply(&source_text, &edits),
+                )
+            })
+            .collect::<Vec<_>>();
+        // `a` of `e.js` is imported from another module
+        assert_eq!(
+            importers,
+            [
+                (
+                    "b.js".to_string(),
+                    "import { b } from './a.js';\nconst o = { a: b };".to_string()
+                ),
+                ("c.js".to_string(), "import { b as y } from './a';\ny;".to_string()),
+                ("d.js".to_string(), "export { b } from './a';".to_string()),
+            ]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
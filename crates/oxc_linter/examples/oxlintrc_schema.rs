@@ -0,0 +1,9 @@
+//! Print the JSON schema of the oxlint configuration file
+//!
+//! Run `just linter-schema-json` to update `npm/oxlint/configuration_schema.json`
+
+use oxc_linter::Oxlintrc;
+
+fn main() {
+    print!("{}", Oxlintrc::json_schema());
+}
@@ -0,0 +1,248 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+    FailedToOpenFileError, FailedToWriteFileError, LintDiagnostic, Report,
+};
+
+use crate::cache::stable_hash;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse baseline file {0:?}")]
+#[diagnostic(help("{1}"))]
+struct FailedToParseBaselineError(PathBuf, String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("{2} baselined problem(s) of {1} in {0} no longer occur")]
+#[diagnostic(
+    severity(warning),
+    help("Run with `--write-baseline` to remove them from the baseline")
+)]
+struct StaleBaselineEntry(String, String, usize);
+
+/// Number of problems with each fingerprint, keyed by ESLint rule id
+type FileEntries = BTreeMap<String, BTreeMap<String, usize>>;
+
+/// Keyed by file path relative to the current working directory
+type Entries = BTreeMap<String, FileEntries>;
+
+/// Problems which existed when the baseline was written, they are suppressed until fixed.
+///
+/// A problem is identified by its rule, file and a fingerprint of the source lines it is reported on,
+/// so the baseline survives unrelated changes shifting these lines.
+pub struct Baseline {
+    path: PathBuf,
+    /// Record the problems of this run instead of suppressing the recorded ones
+    write: bool,
+    /// Number of problems left to be suppressed in each file
+    entries: DashMap<String, FileEntries>,
+    /// Problems of this run in write mode
+    recorded: DashMap<String, FileEntries>,
+}
+
+impl Baseline {
+    /// Suppress the problems recorded in the baseline file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the file cannot be read or parsed.
+    pub fn read(path: PathBuf) -> Result<Self, Report> {
+        let entries = Self::read_entries(&path)?;
+        Ok(Self {
+            path,
+            write: false,
+            entries: entries.into_iter().collect(),
+            recorded: DashMap::default(),
+        })
+    }
+
+    /// Record all problems to the baseline file at `path` instead of reporting them.
+    /// Entries of files which are not linted in this run are kept.
+    pub fn write(path: PathBuf) -> Self {
+        let entries = Self::read_entries(&path).unwrap_or_default();
+        Self {
+            path,
+            write: true,
+            entries: entries.into_iter().collect(),
+            recorded: DashMap::default(),
+        }
+    }
+
+    fn read_entries(path: &Path) -> Result<Entries, Report> {
+        let text =
+            fs::read_to_string(path).map_err(|e| FailedToOpenFileError(path.to_path_buf(), e))?;
+        serde_json::from_str(&text)
+            .map_err(|e| FailedToParseBaselineError(path.to_path_buf(), e.to_string()).into())
+    }
+
+    /// Whether `error` reported in `source_text` of the file `path` is in the baseline.
+    /// In write mode, all problems of lint rules are recorded and suppressed.
+    pub(crate) fn suppress(&self, path: &str, source_text: &str, error: &Report) -> bool {
+        // Syntax errors and other problems which are not reported by a rule are never suppressed
+        let Some(rule) = LintDiagnostic::of(error).and_then(LintDiagnostic::rule_id) else {
+            return false;
+        };
+        let fingerprint = Self::fingerprint(source_text, error);
+        if self.write {
+            let mut recorded = self.recorded.entry(path.to_string()).or_default();
+            *recorded.entry(rule.to_string()).or_default().entry(fingerprint).or_default() += 1;
+            return true;
+        }
+        let Some(mut entries) = self.entries.get_mut(path) else { return false };
+        let Some(count) =
+            entries.get_mut(rule).and_then(|fingerprints| fingerprints.get_mut(&fingerprint))
+        else {
+            return false;
+        };
+        if *count == 0 {
+            return false;
+        }
+        *count -= 1;
+        true
+    }
+
+    /// Hash of the trimmed source lines of the first label of `error`, joined by `\n`
+    fn fingerprint(source_text: &str, error: &Report) -> String {
+        let mut lines = String::new();
+        if let Some(label) = error.labels().and_then(|mut labels| labels.next()) {
+            let start = label.offset().min(source_text.len());
+            let end = (label.offset() + label.len()).min(source_text.len());
+            let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = source_text[end..].find('\n').map_or(source_text.len(), |i| end + i);
+            lines = source_text[line_start..line_end]
+                .lines()
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+        format!("{:016x}", stable_hash(lines.as_bytes()))
+    }
+
+    /// Write the baseline in write mode, otherwise return the entries which were not used
+    /// of `linted_paths` and of files which no longer exist.
+    /// Paths are relative to `cwd`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the baseline file cannot be written.
+    pub(crate) fn finish<'a>(
+        &self,
+        cwd: &Path,
+        linted_paths: impl Iterator<Item = &'a str>,
+    ) -> Result<Vec<(PathBuf, Vec<Report>)>, Report> {
+        let exists = |path: &str| cwd.join(path).exists();
+        if self.write {
+            let mut entries = self.entries.clone().into_iter().collect::<Entries>();
+            for path in linted_paths {
+                entries.remove(path);
+            }
+            entries.retain(|path, _| exists(path));
+            entries.extend(self.recorded.clone());
+            let write = serde_json::to_string_pretty(&entries)
+                .map_err(std::io::Error::from)
+                .and_then(|text| fs::write(&self.path, text + "\n"));
+            write.map_err(|e| FailedToWriteFileError(self.path.clone(), e))?;
+            return Ok(vec![]);
+        }
+        let mut paths = linted_paths.map(ToString::to_string).collect::<Vec<_>>();
+        paths.extend(
+            self.entries.iter().map(|entry| entry.key().clone()).filter(|path| !exists(path)),
+        );
+        paths.sort_unstable();
+        paths.dedup();
+        let stale = paths
+            .into_iter()
+            .filter_map(|path| {
+                let entries = self.entries.get(&path)?;
+                let errors = entries
+                    .iter()
+                    .filter_map(|(rule, fingerprints)| {
+                        let count = fingerprints.values().sum::<usize>();
+                        (count > 0)
+                            .then(|| StaleBaselineEntry(path.clone(), rule.clone(), count).into())
+                    })
+                    .collect::<Vec<_>>();
+                (!errors.is_empty()).then(|| (PathBuf::from(&path), errors))
+            })
+            .collect();
+        Ok(stale)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use oxc_diagnostics::{
+        miette::{LabeledSpan, MietteDiagnostic},
+        LintDiagnostic, Report,
+    };
+
+    use super::Baseline;
+
+    fn debugger(source_text: &str) -> Report {
+        let offset = source_text.find("debugger").unwrap();
+        let diagnostic =
+            MietteDiagnostic::new("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_label(LabeledSpan::at(offset..offset + 8, "debugger"));
+        LintDiagnostic::new(diagnostic.into()).with_rule_id("no-debugger".to_string()).into()
+    }
+
+    #[test]
+    fn suppress_until_fixed() {
+        let dir = std::env::temp_dir().join("oxc_linter_baseline_test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("a.js"), "").unwrap();
+        let path = dir.join("baseline.json");
+        let _ = fs::remove_file(&path);
+
+        let source_text = "debugger;\nfoo();";
+        let baseline = Baseline::write(path.clone());
+        assert!(baseline.suppress("a.js", source_text, &debugger(source_text)));
+        assert!(baseline.suppress("removed.js", source_text, &debugger(source_text)));
+        assert!(!baseline.suppress("a.js", source_text, &Report::msg("Unexpected token")));
+        // Not reported by a rule even though the message looks like it
+        let message = Report::msg("eslint(no-debugger): `debugger` statement is not allowed");
+        assert!(!baseline.suppress("a.js", source_text, &message));
+        assert!(baseline.finish(&dir, ["a.js", "removed.js"].into_iter()).unwrap().is_empty());
+
+        // Lines shifted, another `debugger` is added
+        let source_text = "foo();\n\n  debugger;\ndebugger; bar();";
+        let baseline = Baseline::read(path.clone()).unwrap();
+        assert!(baseline.suppress("a.js", source_text, &debugger(source_text)));
+        assert!(!baseline.suppress("a.js", source_text, &debugger(source_text)));
+        assert!(!baseline.suppress("b.js", source_text, &debugger(source_text)));
+        // Entries of files which no longer exist are stale even if they are not linted
+        let stale = baseline.finish(&dir, ["a.js"].into_iter()).unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(
+            stale[0].1[0].to_string(),
+            "1 baselined problem(s) of no-debugger in removed.js no longer occur"
+        );
+
+        // Fixed
+        let baseline = Baseline::read(path.clone()).unwrap();
+        let stale = baseline.finish(&dir, ["a.js", "b.js"].into_iter()).unwrap();
+        assert_eq!(stale.len(), 2);
+        assert_eq!(
+            stale[0].1[0].to_string(),
+            "1 baselined problem(s) of no-debugger in a.js no longer occur"
+        );
+
+        // Entries of files which no longer exist are removed
+        Baseline::write(path.clone()).finish(&dir, std::iter::empty()).unwrap();
+        let baseline = Baseline::read(path.clone()).unwrap();
+        let stale = baseline.finish(&dir, ["a.js"].into_iter()).unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].0.to_string_lossy(), "a.js");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
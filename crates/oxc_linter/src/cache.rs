@@ -0,0 +1,254 @@
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use dashmap::{DashMap, DashSet};
+use miette::MietteDiagnostic;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use oxc_diagnostics::{Error, FailedToWriteFileError, LintDiagnostic};
+
+/// Diagnostics of each linted file from a previous run, stored in the file at `path`.
+///
+/// A file is skipped when its content, the configuration it is linted with and,
+/// with the import plugin enabled, the content of the modules it imports are unchanged.
+pub struct LintCache {
+    path: PathBuf,
+    version: String,
+    /// Entries read from the cache file
+    previous: FxHashMap<PathBuf, CacheEntry>,
+    /// Entries of the files linted in this run
+    linted: DashMap<Box<Path>, CacheEntry>,
+    /// Files whose diagnostics were read from the cache in this run
+    hits: DashSet<Box<Path>>,
+    /// Memoized content hashes, `None` for files which cannot be read
+    content_hashes: DashMap<Box<Path>, Option<u64>>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    files: FxHashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Hash of the file content and the configuration
+    key: u64,
+    /// Content hashes of the modules imported directly or transitively
+    dependencies: Vec<(PathBuf, u64)>,
+    /// Diagnostics of each source in the file, e.g. each `<script>` of a Vue file
+    diagnostics: Vec<Vec<CachedDiagnostic>>,
+}
+
+/// A diagnostic stored in the cache
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedDiagnostic {
+    #[serde(flatten)]
+    diagnostic: MietteDiagnostic,
+    /// See [LintDiagnostic], the severity is stored in `diagnostic`
+    rule_id: Option<String>,
+    fixable: bool,
+    #[serde(default)]
+    fix: Option<(Range<usize>, String)>,
+}
+
+impl CachedDiagnostic {
+    fn to_error(&self) -> Error {
+        let error = Error::new(self.diagnostic.clone());
+        if self.rule_id.is_none() && !self.fixable {
+            return error;
+        }
+        let mut diagnostic = LintDiagnostic::new(error).with_fixable(self.fixable);
+        if let Some(rule_id) = &self.rule_id {
+            diagnostic = diagnostic.with_rule_id(rule_id.clone());
+        }
+        if let Some((range, text)) = &self.fix {
+            diagnostic = diagnostic.with_fix(range.clone(), text.clone());
+        }
+        diagnostic.into()
+    }
+}
+
+impl LintCache {
+    /// Read the cache file at `path`.
+    /// The cache is empty if the file does not exist, is invalid or written by another `version`.
+    pub fn new(path: PathBuf, version: &str) -> Self {
+        let previous = fs::read(&path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<CacheFile>(&bytes).ok())
+            .filter(|cache_file| cache_file.version == version)
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+        Self {
+            path,
+            version: version.to_string(),
+            previous,
+            linted: DashMap::default(),
+            hits: DashSet::default(),
+            content_hashes: DashMap::default(),
+        }
+    }
+
+    /// The key of `path` with `source_text`, linted by a linter with `config_hash`
+    pub(crate) fn key(&self, path: &Path, source_text: &str, config_hash: u64) -> u64 {
+        let content_hash = stable_hash(source_text.as_bytes());
+        self.content_hashes.insert(path.to_path_buf().into_boxed_path(), Some(content_hash));
+        stable_hash(&[content_hash.to_le_bytes(), config_hash.to_le_bytes()].concat())
+    }
+
+    /// The diagnostics of each source of `path` from the previous run, if they are still valid for `key`
+    pub(crate) fn get(&self, path: &Path, key: u64) -> Option<Vec<Vec<Error>>> {
+        let entry = self.previous.get(path).filter(|entry| entry.key == key)?;
+        let dependencies_unchanged = entry
+            .dependencies
+            .iter()
+            .all(|(dependency, hash)| self.content_hash(dependency) == Some(*hash));
+        if !dependencies_unchanged {
+            return None;
+        }
+        self.hits.insert(path.to_path_buf().into_boxed_path());
+        let diagnostics = entry
+            .diagnostics
+            .iter()
+            .map(|diagnostics| diagnostics.iter().map(CachedDiagnostic::to_error).collect())
+            .collect();
+        Some(diagnostics)
+    }
+
+    /// Whether the diagnostics of `path` were read from the cache in this run
+    pub(crate) fn is_hit(&self, path: &Path) -> bool {
+        self.hits.contains(path)
+    }
+
+    /// Store the diagnostics of each source of `path`
+    pub(crate) fn insert(&self, path: &Path, key: u64, diagnostics: Vec<Vec<CachedDiagnostic>>) {
+        let entry = CacheEntry { key, dependencies: vec![], diagnostics };
+        self.linted.insert(path.to_path_buf().into_boxed_path(), entry);
+    }
+
+    /// A copy of `error` which can be stored in the cache
+    pub(crate) fn to_cached(error: &Error) -> CachedDiagnostic {
+        let diagnostic = MietteDiagnostic {
+            message: error.to_string(),
+            code: error.code().map(|code| code.to_string()),
+            severity: error.severity(),
+            help: error.help().map(|help| help.to_string()),
+            url: error.url().map(|url| url.to_string()),
+            labels: error.labels().map(Iterator::collect),
+        };
+        let lint = LintDiagnostic::of(error);
+        CachedDiagnostic {
+            diagnostic,
+            rule_id: lint.and_then(LintDiagnostic::rule_id).map(ToString::to_string),
+            fixable: lint.is_some_and(LintDiagnostic::is_fixable),
+            fix: lint
+                .and_then(LintDiagnostic::fix)
+                .map(|(range, text)| (range.clone(), text.to_string())),
+        }
+    }
+
+    /// Write the entries of this run and the previous entries of files which still exist.
+    ///
+    /// `dependencies` returns the modules imported by a linted file,
+    /// it is called after all files are linted so the module graph is complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the cache file cannot be written.
+    pub(crate) fn save<F>(&self, dependencies: F) -> Result<(), Error>
+    where
+        F: Fn(&Path) -> Vec<PathBuf>,
+    {
+        let mut files = self.previous.clone();
+        files.retain(|path, _| path.is_file());
+        for linted in &self.linted {
+            let dependencies = dependencies(linted.key())
+                .into_iter()
+                .filter_map(|dependency| {
+                    let hash = self.content_hash(&dependency)?;
+                    Some((dependency, hash))
+                })
+                .collect();
+            let entry = CacheEntry { dependencies, ..linted.value().clone() };
+            files.insert(linted.key().to_path_buf(), entry);
+        }
+        let cache_file = CacheFile { version: self.version.clone(), files };
+        let write = serde_json::to_vec(&cache_file)
+            .map_err(std::io::Error::from)
+            .and_then(|bytes| fs::write(&self.path, bytes));
+        write.map_err(|e| Error::new(FailedToWriteFileError(self.path.clone(), e)))
+    }
+
+    fn content_hash(&self, path: &Path) -> Option<u64> {
+        *self.content_hashes.entry(path.to_path_buf().into_boxed_path()).or_insert_with(|| {
+            let source_text = fs::read(path).ok()?;
+            Some(stable_hash(&source_text))
+        })
+    }
+}
+
+/// The first 8 bytes of the SHA-256 of `bytes`,
+/// unlike `DefaultHasher` it is the same across runs, platforms and Rust versions
+pub(crate) fn stable_hash(bytes: &[u8]) -> u64 {
+    let digest = Sha256::digest(bytes);
+    u64::from_le_bytes(digest[..8].try_into().unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use miette::MietteDiagnostic;
+    use oxc_diagnostics::LintDiagnostic;
+
+    use super::{CachedDiagnostic, LintCache};
+
+    #[test]
+    fn invalidate_by_content_config_and_dependencies() {
+        let dir = std::env::temp_dir().join("oxc_linter_cache_test");
+        fs::create_dir_all(&dir).unwrap();
+        let cache_path = dir.join("cache");
+        let file = dir.join("file.js");
+        let dependency = dir.join("dependency.js");
+        fs::write(&file, "import foo from './dependency'").unwrap();
+        fs::write(&dependency, "export default 1").unwrap();
+        let _ = fs::remove_file(&cache_path);
+
+        let cache = LintCache::new(cache_path.clone(), "test");
+        let key = cache.key(&file, "import foo from './dependency'", 0);
+        assert!(cache.get(&file, key).is_none());
+        let diagnostic = CachedDiagnostic {
+            diagnostic: MietteDiagnostic::new("message"),
+            rule_id: Some("no-debugger".to_string()),
+            fixable: true,
+            fix: Some((0..9, String::new())),
+        };
+        cache.insert(&file, key, vec![vec![diagnostic]]);
+        cache.save(|_| vec![dependency.clone()]).unwrap();
+
+        let cache = LintCache::new(cache_path.clone(), "test");
+        let diagnostics = cache.get(&file, key).unwrap();
+        assert_eq!(diagnostics[0][0].to_string(), "message");
+        let diagnostic = LintDiagnostic::of(&diagnostics[0][0]).unwrap();
+        assert_eq!(diagnostic.rule_id(), Some("no-debugger"));
+        assert!(diagnostic.is_fixable());
+        assert_eq!(diagnostic.fix(), Some((&(0..9), "")));
+        assert!(cache.is_hit(&file));
+        assert!(cache.get(&file, cache.key(&file, "changed", 0)).is_none());
+        assert!(cache.get(&file, cache.key(&file, "import foo from './dependency'", 1)).is_none());
+
+        let cache = LintCache::new(cache_path.clone(), "another version");
+        assert!(cache.get(&file, key).is_none());
+
+        fs::write(&dependency, "export default 2").unwrap();
+        let cache = LintCache::new(cache_path, "test");
+        assert!(cache.get(&file, key).is_none());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,4 +1,5 @@
 use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 /// Environment
@@ -6,7 +7,7 @@ use serde::Deserialize;
 ///
 /// TS type is `Record<string, boolean>`
 /// https://github.com/eslint/eslint/blob/ce838adc3b673e52a151f36da0eedf5876977514/lib/shared/types.js#L40
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct ESLintEnv(FxHashMap<String, bool>);
 
 impl ESLintEnv {
@@ -16,6 +17,11 @@ impl ESLintEnv {
         Self(map)
     }
 
+    /// Enable an environment, e.g. the `es20XX` environment of `parserOptions.ecmaVersion`.
+    pub fn enable(&mut self, env: String) {
+        self.0.insert(env, true);
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
         // Filter out false values
         self.0.iter().filter(|(_, v)| **v).map(|(k, _)| k.as_str())
@@ -44,3 +44,20 @@ pub struct FailedToParseAllowWarnDenyFromJsonValueError(pub String);
 #[error("Failed to parse jsonc file {0:?}")]
 #[diagnostic()]
 pub struct FailedToParseJsonc(pub PathBuf);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(r#"Failed to resolve "extends", expected a string or an array of strings, but got {0:?}"#)]
+#[diagnostic()]
+pub struct FailedToResolveExtendsError(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(r#"Failed to resolve unknown preset {0:?} in "extends""#)]
+#[diagnostic(help(
+    r#"Supported presets are "oxc:recommended", "oxc:all", "oxc:<category>", "eslint:recommended", "plugin:<plugin>/recommended" and "plugin:<plugin>/all""#
+))]
+pub struct FailedToResolveExtendsPresetError(pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error(r#"Circular "extends" detected in config {0:?}"#)]
+#[diagnostic()]
+pub struct CircularExtendsError(pub PathBuf);
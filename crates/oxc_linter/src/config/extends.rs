@@ -0,0 +1,400 @@
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::Error;
+use serde_json::{Map, Value};
+
+use super::errors::{FailedToResolveExtendsError, FailedToResolveExtendsPresetError};
+use crate::{rules::RULES, RuleCategory, RuleEnum};
+
+/// Resolve the `extends` field of an ESLint config
+/// <https://eslint.org/docs/latest/use/configure/configuration-files#extending-configuration-files>
+///
+/// TS type is `string | string[]`
+///
+/// * Relative or absolute paths are loaded as json configuration files, relative to `dir`
+/// * Built-in presets such as `"oxc:recommended"` and `"plugin:react/recommended"` are expanded into rules
+/// * Other shareable configs and configs of plugins oxlint does not implement cannot be resolved,
+///   they are skipped with a warning
+///
+/// The extended configurations are applied in order, the configuration containing `extends` is applied last.
+pub fn resolve_extends<F>(mut config: Value, dir: &Path, mut load: F) -> Result<Value, Error>
+where
+    F: FnMut(&Path) -> Result<Value, Error>,
+{
+    let extends = match config.as_object_mut().and_then(|config| config.remove("extends")) {
+        None | Some(Value::Null) => return Ok(config),
+        Some(Value::String(name)) => vec![name],
+        Some(Value::Array(names)) => names
+            .into_iter()
+            .map(|name| match name {
+                Value::String(name) => Ok(name),
+                value => Err(FailedToResolveExtendsError(value.to_string()).into()),
+            })
+            .collect::<Result<Vec<_>, Error>>()?,
+        Some(value) => return Err(FailedToResolveExtendsError(value.to_string()).into()),
+    };
+
+    let mut base = Value::Object(Map::default());
+    for name in extends {
+        let extended = if is_file_path(&name) {
+            load(&dir.join(&name))?
+        } else if let Some(preset) = resolve_preset(&name)? {
+            preset
+        } else {
+            eprintln!("Warning: {name:?} in \"extends\" is not built into oxlint and is skipped");
+            continue;
+        };
+        merge_config(&mut base, extended);
+    }
+    merge_config(&mut base, config);
+    Ok(base)
+}
+
+fn is_file_path(name: &str) -> bool {
+    name.starts_with("./")
+        || name.starts_with("../")
+        || Path::new(name).is_absolute()
+        || Path::new(name).extension().is_some_and(|ext| ext == "json")
+}
+
+/// Path of an extended configuration file, used for detecting circular `extends`.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Built-in presets
+///
+/// * `oxc:recommended` - rules in the `correctness` category as warnings,
+///   the same as the default `-W correctness`
+/// * `oxc:all` - all rules as warnings
+/// * `oxc:<category>` - rules in the given category as warnings, e.g. `oxc:suspicious`
+/// * `eslint:recommended` - ESLint rules in the `correctness` category as errors, like ESLint
+/// * `plugin:<plugin>/recommended` - rules of the plugin in the `correctness` category as errors
+/// * `plugin:<plugin>/all` - all rules of the plugin as errors
+///
+/// Plugin presets also turn on the plugin if it is off by default, such as `jest`.
+///
+/// Returns `Ok(None)` for configs that are not built in, such as shareable configs from npm
+/// and configs of plugins oxlint does not implement.
+///
+/// # Errors
+///
+/// When the preset is unknown for `oxc:` or a plugin oxlint implements
+fn resolve_preset(name: &str) -> Result<Option<Value>, Error> {
+    let mut plugins = Map::default();
+    let mut severity = "error";
+    let rules: Vec<&RuleEnum> = if let Some(preset) = name.strip_prefix("oxc:") {
+        severity = "warn";
+        match preset {
+            "recommended" => filter_rules(None, Some(RuleCategory::Correctness)),
+            "all" => filter_rules(None, None),
+            category => match RuleCategory::from(category) {
+                Some(category) => filter_rules(None, Some(category)),
+                None => return Err(FailedToResolveExtendsPresetError(name.to_string()).into()),
+            },
+        }
+    } else if name == "eslint:recommended" {
+        filter_rules(Some("eslint"), Some(RuleCategory::Correctness))
+    } else if name == "eslint:all" {
+        filter_rules(Some("eslint"), None)
+    } else if let Some(plugin_config) = name.strip_prefix("plugin:") {
+        let Some((plugin_name, preset)) = plugin_config.rsplit_once('/') else {
+            return Err(FailedToResolveExtendsPresetError(name.to_string()).into());
+        };
+        let Some(plugin_name) = oxlint_plugin_name(plugin_name) else {
+            // Not a plugin we implement, e.g. `plugin:prettier/recommended`
+            return Ok(None);
+        };
+        let rules = match preset {
+            "recommended" => filter_rules(Some(plugin_name), Some(RuleCategory::Correctness)),
+            "all" => filter_rules(Some(plugin_name), None),
+            _ => return Err(FailedToResolveExtendsPresetError(name.to_string()).into()),
+        };
+        if let Some(toggle) = plugin_toggle(plugin_name) {
+            plugins.insert(toggle.to_string(), Value::Bool(true));
+        }
+        rules
+    } else {
+        return Ok(None);
+    };
+
+    let rules = rules
+        .into_iter()
+        .map(|rule| (eslint_rule_key(rule), Value::String(severity.to_string())))
+        .collect::<Map<_, _>>();
+    Ok(Some(serde_json::json!({ "rules": rules, "plugins": plugins })))
+}
+
+/// The key of the plugin in the `plugins` toggles of oxlintrc,
+/// `None` for the plugins which are always on.
+fn plugin_toggle(plugin_name: &str) -> Option<&'static str> {
+    let toggle = match plugin_name {
+        "import" => "import",
+        "jest" => "jest",
+        "jsx_a11y" => "jsx-a11y",
+        "nextjs" => "nextjs",
+        "react_perf" => "react-perf",
+        _ => return None,
+    };
+    Some(toggle)
+}
+
+fn filter_rules(
+    plugin_name: Option<&str>,
+    category: Option<RuleCategory>,
+) -> Vec<&'static RuleEnum> {
+    RULES
+        .iter()
+        .filter(|rule| plugin_name.map_or(true, |name| rule.plugin_name() == name))
+        .filter(|rule| category.map_or(true, |category| rule.category() == category))
+        .collect()
+}
+
+/// ESLint plugin name to oxlint plugin name, the reverse of [eslint_rule_key].
+fn oxlint_plugin_name(plugin_name: &str) -> Option<&'static str> {
+    let name = match plugin_name {
+        "@typescript-eslint" => "typescript",
+        "jsx-a11y" => "jsx_a11y",
+        "react-perf" => "react_perf",
+        "@next/next" | "@next" => "nextjs",
+        "import" => "import",
+        "jest" => "jest",
+        "react" => "react",
+        "unicorn" => "unicorn",
+        "oxc" => "oxc",
+        "deepscan" => "deepscan",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// The rule key as written in ESLint config, so it can be read back by `parse_rule_key`.
+pub(crate) fn eslint_rule_key(rule: &RuleEnum) -> String {
+    eslint_rule_id(rule.plugin_name(), rule.name())
+}
+
+/// ESLint rule id of the rule `name` of the oxlint plugin `plugin_name`
+pub(crate) fn eslint_rule_id(plugin_name: &str, name: &str) -> String {
+    match plugin_name {
+        "eslint" => name.to_string(),
+        "typescript" => format!("@typescript-eslint/{name}"),
+        "jsx_a11y" => format!("jsx-a11y/{name}"),
+        "react_perf" => format!("react-perf/{name}"),
+        "nextjs" => format!("@next/next/{name}"),
+        plugin_name => format!("{plugin_name}/{name}"),
+    }
+}
+
+/// Merge `config` into `base` following ESLint's rules
+/// <https://eslint.org/docs/latest/use/configure/configuration-files#extending-configuration-files>
+///
+/// * `rules`: rules in `config` replace rules in `base`,
+///   but a severity without options keeps the options from `base`
+/// * `settings`: deep merged
+/// * `env`, `globals`, `categories` and `plugins`: merged by key
+/// * `overrides` and `ignorePatterns`: concatenated
+/// * anything else: replaced
+pub fn merge_config(base: &mut Value, config: Value) {
+    let (Some(base), Value::Object(config)) = (base.as_object_mut(), config) else {
+        return;
+    };
+    for (key, value) in config {
+        match (key.as_str(), base.get_mut(&key)) {
+            ("rules", Some(Value::Object(base_rules))) => {
+                let Value::Object(rules) = value else { continue };
+                for (name, rule) in rules {
+                    merge_rule(base_rules, name, rule);
+                }
+            }
+            ("settings", Some(base_settings)) => merge_deep(base_settings, value),
+            // ESLint's list of plugin packages does not toggle any plugin
+            ("plugins", Some(Value::Object(_))) if value.is_array() => {}
+            ("env" | "globals" | "categories" | "plugins", Some(Value::Object(base_map))) => {
+                let Value::Object(map) = value else { continue };
+                base_map.extend(map);
+            }
+            ("overrides", Some(Value::Array(base_overrides))) => {
+                let Value::Array(overrides) = value else { continue };
+                base_overrides.extend(overrides);
+            }
+            ("ignorePatterns", Some(base_patterns)) => {
+                // `string | string[]`
+                let to_vec = |patterns: Value| match patterns {
+                    Value::Array(patterns) => patterns,
+                    pattern => vec![pattern],
+                };
+                let mut patterns = to_vec(base_patterns.take());
+                patterns.extend(to_vec(value));
+                *base_patterns = Value::Array(patterns);
+            }
+            _ => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn merge_rule(base_rules: &mut Map<String, Value>, name: String, rule: Value) {
+    let is_severity_only = match &rule {
+        Value::String(_) | Value::Number(_) => true,
+        Value::Array(values) => values.len() == 1,
+        _ => false,
+    };
+    if is_severity_only {
+        if let Some(Value::Array(base_rule)) = base_rules.get_mut(&name) {
+            if base_rule.len() > 1 {
+                let severity = match rule {
+                    Value::Array(mut values) => values.remove(0),
+                    severity => severity,
+                };
+                base_rule[0] = severity;
+                return;
+            }
+        }
+    }
+    base_rules.insert(name, rule);
+}
+
+fn merge_deep(base: &mut Value, value: Value) {
+    match (base, value) {
+        (Value::Object(base), Value::Object(map)) => {
+            for (key, value) in map {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_deep(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::{merge_config, resolve_extends};
+
+    #[test]
+    fn test_merge_config() {
+        let mut base = json!({
+            "rules": { "eqeqeq": ["error", "always"], "no-console": "warn" },
+            "settings": { "react": { "formComponents": ["Form"] }, "next": { "rootDir": "app" } },
+            "env": { "browser": true },
+            "globals": { "foo": "readonly" },
+            "overrides": [{ "files": "*.ts", "rules": {} }],
+            "ignorePatterns": "dist"
+        });
+        merge_config(
+            &mut base,
+            json!({
+                "rules": { "eqeqeq": "warn", "no-console": ["off"] },
+                "settings": { "react": { "linkComponents": ["Link"] } },
+                "env": { "node": true },
+                "globals": { "bar": "writable" },
+                "overrides": [{ "files": "*.js", "rules": {} }],
+                "ignorePatterns": ["build", "coverage"]
+            }),
+        );
+        assert_eq!(
+            base,
+            json!({
+                "rules": { "eqeqeq": ["warn", "always"], "no-console": ["off"] },
+                "settings": {
+                    "react": { "formComponents": ["Form"], "linkComponents": ["Link"] },
+                    "next": { "rootDir": "app" }
+                },
+                "env": { "browser": true, "node": true },
+                "globals": { "foo": "readonly", "bar": "writable" },
+                "overrides": [{ "files": "*.ts", "rules": {} }, { "files": "*.js", "rules": {} }],
+                "ignorePatterns": ["dist", "build", "coverage"]
+            })
+        );
+    }
+
+    #[test]
+    fn test_resolve_extends() {
+        let config = json!({
+            "extends": ["./base.json", "plugin:react/recommended", "prettier"],
+            "rules": { "no-debugger": "off" }
+        });
+        let config = resolve_extends(config, Path::new("foo"), |path| {
+            assert_eq!(path, Path::new("foo/./base.json"));
+            Ok(json!({ "rules": { "no-debugger": "error", "no-console": "warn" } }))
+        })
+        .unwrap();
+
+        let rules = config["rules"].as_object().unwrap();
+        assert!(config.get("extends").is_none());
+        assert_eq!(rules["no-debugger"], "off");
+        assert_eq!(rules["no-console"], "warn");
+        assert_eq!(rules["react/jsx-key"], "error");
+        assert!(rules.keys().all(|key| key.starts_with("react/") || key.starts_with("no-")));
+    }
+
+    #[test]
+    fn test_resolve_extends_preset() {
+        let config = resolve_extends(
+            json!({ "extends": "oxc:recommended" }),
+            Path::new(""),
+            |_| unreachable!(),
+        )
+        .unwrap();
+        let rules = config["rules"].as_object().unwrap();
+        assert_eq!(rules["no-debugger"], "warn");
+        assert_eq!(rules["@typescript-eslint/no-extra-non-null-assertion"], "warn");
+        assert!(!rules.contains_key("no-console"));
+
+        let config = resolve_extends(
+            json!({ "extends": "eslint:recommended" }),
+            Path::new(""),
+            |_| unreachable!(),
+        )
+        .unwrap();
+        assert_eq!(config["rules"]["no-debugger"], "error");
+
+        for name in ["oxc:unknown", "plugin:react/strict", "plugin:jest"] {
+            let config =
+                resolve_extends(json!({ "extends": name }), Path::new(""), |_| unreachable!());
+            assert!(config.is_err(), "{name}");
+        }
+        // Not built in, skipped with a warning
+        for name in ["plugin:prettier/recommended", "airbnb"] {
+            let config =
+                resolve_extends(json!({ "extends": name }), Path::new(""), |_| unreachable!());
+            assert_eq!(config.unwrap(), json!({}), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_resolve_extends_plugin_preset_enables_plugin() {
+        let config = resolve_extends(
+            json!({ "extends": "plugin:jest/recommended", "plugins": ["jest"] }),
+            Path::new(""),
+            |_| unreachable!(),
+        )
+        .unwrap();
+        assert_eq!(config["plugins"], json!({ "jest": true }));
+        assert_eq!(config["rules"]["jest/no-focused-tests"], "error");
+
+        let config = resolve_extends(
+            json!({ "extends": "plugin:jsx-a11y/all" }),
+            Path::new(""),
+            |_| unreachable!(),
+        )
+        .unwrap();
+        assert_eq!(config["plugins"], json!({ "jsx-a11y": true }));
+
+        let config = resolve_extends(
+            json!({ "extends": "plugin:react/recommended" }),
+            Path::new(""),
+            |_| unreachable!(),
+        )
+        .unwrap();
+        assert_eq!(config["plugins"], json!({}));
+    }
+}
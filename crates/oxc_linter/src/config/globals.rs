@@ -0,0 +1,119 @@
+use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de, Deserialize, Deserializer};
+
+/// The `globals` field from ESLint config
+/// <https://eslint.org/docs/latest/use/configure/language-options#using-configuration-files-1>
+///
+/// TS type is `Record<string, GlobalConf>`
+///   - type GlobalConf = boolean | "off" | "readable" | "readonly" | "writable" | "writeable";
+/// https://github.com/eslint/eslint/blob/ce838adc3b673e52a151f36da0eedf5876977514/lib/shared/types.js#L32
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct ESLintGlobals(FxHashMap<String, GlobalValue>);
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum GlobalValue {
+    Readonly,
+    Writeable,
+    /// Disables a global, e.g. one that is provided by an `env`
+    Off,
+}
+
+impl ESLintGlobals {
+    pub fn get(&self, name: &str) -> Option<GlobalValue> {
+        self.0.get(name).copied()
+    }
+}
+
+impl GlobalValue {
+    pub fn is_enabled(self) -> bool {
+        self != Self::Off
+    }
+}
+
+impl<'de> Deserialize<'de> for GlobalValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match serde_json::Value::deserialize(deserializer)? {
+            // `true` and `false` are the deprecated forms of "writable" and "readonly"
+            serde_json::Value::Bool(true) => Ok(Self::Writeable),
+            serde_json::Value::Bool(false) => Ok(Self::Readonly),
+            serde_json::Value::String(s) => match s.as_str() {
+                "readonly" | "readable" => Ok(Self::Readonly),
+                "writable" | "writeable" => Ok(Self::Writeable),
+                "off" => Ok(Self::Off),
+                _ => Err(de::Error::custom(format!(
+                    r#"expected one of "readonly", "writable" or "off" for global, but got {s:?}"#
+                ))),
+            },
+            value => Err(de::Error::custom(format!(
+                "expected a string or a boolean for global, but got {value}"
+            ))),
+        }
+    }
+}
+
+impl JsonSchema for GlobalValue {
+    fn schema_name() -> String {
+        "GlobalValue".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        /// `true` and `false` are the deprecated forms of "writable" and "readonly"
+        #[allow(dead_code)]
+        #[derive(JsonSchema)]
+        #[serde(untagged)]
+        enum GlobalConf {
+            Boolean(bool),
+            Value(GlobalConfValue),
+        }
+
+        #[allow(dead_code)]
+        #[derive(JsonSchema)]
+        #[serde(rename_all = "lowercase")]
+        enum GlobalConfValue {
+            Readonly,
+            Readable,
+            Writable,
+            Writeable,
+            Off,
+        }
+
+        GlobalConf::json_schema(gen)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::{ESLintGlobals, GlobalValue};
+
+    #[test]
+    fn test_parse_globals() {
+        let globals = ESLintGlobals::deserialize(&serde_json::json!({
+            "foo": "readonly",
+            "bar": "readable",
+            "baz": "writable",
+            "qux": true,
+            "quux": false,
+            "Promise": "off"
+        }))
+        .unwrap();
+        assert_eq!(globals.get("foo"), Some(GlobalValue::Readonly));
+        assert_eq!(globals.get("bar"), Some(GlobalValue::Readonly));
+        assert_eq!(globals.get("baz"), Some(GlobalValue::Writeable));
+        assert_eq!(globals.get("qux"), Some(GlobalValue::Writeable));
+        assert_eq!(globals.get("quux"), Some(GlobalValue::Readonly));
+        assert_eq!(globals.get("Promise"), Some(GlobalValue::Off));
+        assert_eq!(globals.get("window"), None);
+    }
+
+    #[test]
+    fn test_parse_globals_invalid() {
+        assert!(ESLintGlobals::deserialize(&serde_json::json!({ "foo": "on" })).is_err());
+        assert!(ESLintGlobals::deserialize(&serde_json::json!({ "foo": 1 })).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use dashmap::DashMap;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use serde::Deserialize;
+
+use super::{project_root, relative_path, ESLintConfig};
+use crate::Linter;
+
+/// The `ignorePatterns` field from ESLint config
+/// <https://eslint.org/docs/latest/use/configure/ignore-deprecated#ignorepatterns-in-config-files>
+///
+/// TS type is `string | string[]`
+///
+/// Patterns follow the `.gitignore` syntax and are relative to the directory of the configuration file,
+/// so the patterns of each configuration file in a cascade are kept separately.
+#[derive(Debug, Clone, Default)]
+pub struct ESLintIgnorePatterns(Vec<Gitignore>);
+
+impl ESLintIgnorePatterns {
+    /// Add the `ignorePatterns` of the configuration file in `base_dir`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Err` if `value` is not a string or an array of strings, or a pattern is invalid.
+    pub fn add(&mut self, base_dir: &Path, value: &serde_json::Value) -> Result<(), String> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum OneOrMany {
+            One(String),
+            Many(Vec<String>),
+        }
+
+        let patterns = match OneOrMany::deserialize(value).map_err(|err| err.to_string())? {
+            OneOrMany::One(pattern) => vec![pattern],
+            OneOrMany::Many(patterns) => patterns,
+        };
+        let mut builder = GitignoreBuilder::new(base_dir);
+        for pattern in patterns {
+            builder.add_line(None, &pattern).map_err(|err| err.to_string())?;
+        }
+        self.0.push(builder.build().map_err(|err| err.to_string())?);
+        Ok(())
+    }
+
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.0.iter().any(|gitignore| {
+            // `Gitignore` panics on paths outside of its root.
+            relative_path(path, gitignore.path())
+                .is_some_and(|path| gitignore.matched_path_or_any_parents(path, false).is_ignore())
+        })
+    }
+}
+
+/// Matches files with the `ignorePatterns` of their configuration file while walking directories,
+/// so the ignored files are never read.
+///
+/// With config discovery, the patterns of the nearest configuration file of each file are used,
+/// otherwise the ones of the configuration file of the linter.
+pub struct ConfigIgnorePatterns {
+    /// Of the configuration file of the linter, also used for files without a nearest configuration file
+    ignore_patterns: ESLintIgnorePatterns,
+    /// Of the nearest configuration file, keyed by directory, only present with config discovery
+    directories: Option<DashMap<Box<Path>, Option<ESLintIgnorePatterns>>>,
+    cwd: Box<Path>,
+    project_root: Box<Path>,
+}
+
+impl ConfigIgnorePatterns {
+    pub fn new(linter: &Linter, cwd: &Path, config_discovery: bool) -> Self {
+        Self {
+            ignore_patterns: linter.ignore_patterns.clone(),
+            directories: config_discovery.then(DashMap::default),
+            cwd: cwd.into(),
+            project_root: project_root(cwd),
+        }
+    }
+
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let Some((directories, dir)) = self.directories.as_ref().zip(path.parent()) else {
+            return self.ignore_patterns.is_ignored(path);
+        };
+        if let Some(ignore_patterns) = directories.get(dir) {
+            return ignore_patterns.as_ref().unwrap_or(&self.ignore_patterns).is_ignored(path);
+        }
+        // Invalid configuration files are reported when the files are linted
+        let ignore_patterns = ESLintConfig::from_directory(&self.cwd.join(dir), &self.project_root)
+            .ok()
+            .flatten()
+            .map(|config| config.ignore_patterns);
+        let is_ignored = ignore_patterns.as_ref().unwrap_or(&self.ignore_patterns).is_ignored(path);
+        directories.insert(dir.into(), ignore_patterns);
+        is_ignored
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::ESLintIgnorePatterns;
+
+    #[test]
+    fn test_ignore_patterns() {
+        let mut ignore_patterns = ESLintIgnorePatterns::default();
+        ignore_patterns.add(Path::new("root"), &serde_json::json!("dist")).unwrap();
+        ignore_patterns
+            .add(Path::new("root/nested"), &serde_json::json!(["/*.config.js", "!keep.config.js"]))
+            .unwrap();
+
+        assert!(ignore_patterns.is_ignored(Path::new("root/dist/index.js")));
+        assert!(ignore_patterns.is_ignored(Path::new("root/nested/dist/index.js")));
+        assert!(ignore_patterns.is_ignored(Path::new("root/nested/vite.config.js")));
+        assert!(!ignore_patterns.is_ignored(Path::new("root/nested/keep.config.js")));
+        assert!(!ignore_patterns.is_ignored(Path::new("root/nested/src/vite.config.js")));
+        assert!(!ignore_patterns.is_ignored(Path::new("root/vite.config.js")));
+        assert!(!ignore_patterns.is_ignored(Path::new("other/dist/index.js")));
+    }
+
+    #[test]
+    fn test_invalid_ignore_patterns() {
+        let mut ignore_patterns = ESLintIgnorePatterns::default();
+        assert!(ignore_patterns.add(Path::new(""), &serde_json::json!({ "dist": true })).is_err());
+    }
+}
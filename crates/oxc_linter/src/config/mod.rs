@@ -1,36 +1,198 @@
 mod env;
 pub mod errors;
+mod extends;
+mod globals;
+mod ignore_patterns;
+mod overrides;
+mod oxlintrc;
+mod parser_options;
 mod rules;
 mod settings;
 
-use std::path::Path;
+use std::{
+    borrow::Cow,
+    path::{Path, PathBuf},
+};
 
 use oxc_diagnostics::{Error, FailedToOpenFileError, Report};
 use rustc_hash::FxHashSet;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
-use crate::{rules::RuleEnum, AllowWarnDeny};
+use crate::{rule::RuleWithSeverity, rules::RuleEnum};
 
 use self::errors::{
-    FailedToParseConfigError, FailedToParseConfigJsonError, FailedToParseConfigPropertyError,
-    FailedToParseJsonc,
+    CircularExtendsError, FailedToParseConfigError, FailedToParseConfigJsonError,
+    FailedToParseConfigPropertyError, FailedToParseJsonc,
+};
+pub use self::{
+    env::ESLintEnv,
+    globals::{ESLintGlobals, GlobalValue},
+    ignore_patterns::{ConfigIgnorePatterns, ESLintIgnorePatterns},
+    overrides::ESLintOverrides,
+    oxlintrc::{OxlintCategories, OxlintPlugins, Oxlintrc},
+    parser_options::ESLintParserOptions,
+    rules::ESLintRules,
+    settings::ESLintSettings,
 };
-pub use self::{env::ESLintEnv, rules::ESLintRules, settings::ESLintSettings};
+pub(crate) use self::{
+    extends::{eslint_rule_id, eslint_rule_key},
+    rules::parse_rule_key,
+};
+
+/// Configuration file names looked up in each directory, in order of precedence
+pub const CONFIG_FILE_NAMES: [&str; 3] = [".oxlintrc.json", ".eslintrc.json", ".eslintrc"];
 
 /// ESLint Config
 /// <https://eslint.org/docs/latest/use/configure/configuration-files-new#configuration-objects>
-#[derive(Debug, Deserialize)]
+///
+/// Also the merged form of [Oxlintrc], which adds `categories` and `plugins`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ESLintConfig {
     #[serde(default)]
-    rules: ESLintRules,
+    pub(crate) rules: ESLintRules,
+    #[serde(default)]
+    pub(crate) settings: ESLintSettings,
+    #[serde(default)]
+    pub(crate) env: ESLintEnv,
+    #[serde(default)]
+    pub(crate) globals: ESLintGlobals,
     #[serde(default)]
-    settings: ESLintSettings,
+    pub(crate) parser_options: ESLintParserOptions,
     #[serde(default)]
-    env: ESLintEnv,
+    pub(crate) categories: OxlintCategories,
+    #[serde(default, deserialize_with = "deserialize_plugins")]
+    pub(crate) plugins: OxlintPlugins,
+    #[serde(default)]
+    pub(crate) overrides: ESLintOverrides,
+    #[serde(skip)]
+    pub(crate) ignore_patterns: ESLintIgnorePatterns,
+    /// The configuration files with their `extends` resolved, see [crate::Linter::config_hash]
+    #[serde(skip)]
+    pub(crate) sources: Vec<serde_json::Value>,
+    /// Canonical paths of the configuration files read, including the extended ones
+    #[serde(skip)]
+    pub(crate) files: Vec<PathBuf>,
 }
 
 impl ESLintConfig {
     pub fn from_file(path: &Path) -> Result<Self, Report> {
+        let mut files = vec![];
+        let json = Self::read_json_with_extends(path, &mut vec![], &mut files)?;
+        let mut config = Self::from_layers(vec![(path.to_path_buf(), json)])?;
+        config.files = files;
+        Ok(config)
+    }
+
+    /// Find the configuration for files in `dir` with ESLint's cascading rules
+    /// <https://eslint.org/docs/latest/use/configure/configuration-files#cascading-and-hierarchy>
+    ///
+    /// The nearest configuration file takes precedence over the ones in ancestor directories,
+    /// the lookup stops at a configuration file with `"root": true`, at `root_dir`
+    /// or at the root of a git repository, so files outside of the project are not read.
+    /// Returns `Ok(None)` when there are no configuration files.
+    pub fn from_directory(dir: &Path, root_dir: &Path) -> Result<Option<Self>, Report> {
+        let mut layers = vec![];
+        let mut files = vec![];
+        for dir in dir.ancestors() {
+            if let Some(path) =
+                CONFIG_FILE_NAMES.iter().map(|name| dir.join(name)).find(|path| path.is_file())
+            {
+                let json = Self::read_json_with_extends(&path, &mut vec![], &mut files)?;
+                let is_root = json.get("root").is_some_and(|root| *root == true);
+                layers.push((path, json));
+                if is_root {
+                    break;
+                }
+            }
+            if dir == root_dir || dir.join(".git").exists() {
+                break;
+            }
+        }
+        if layers.is_empty() {
+            return Ok(None);
+        }
+        layers.reverse();
+        let mut config = Self::from_layers(layers)?;
+        config.files = files;
+        Ok(Some(config))
+    }
+
+    /// Merge configuration files in order, later ones take precedence.
+    fn from_layers(layers: Vec<(PathBuf, serde_json::Value)>) -> Result<Self, Report> {
+        let to_error = |err: serde_json::Error| {
+            FailedToParseConfigError(vec![Error::new(FailedToParseConfigPropertyError(
+                err.to_string(),
+            ))])
+        };
+
+        let sources = layers
+            .iter()
+            .map(|(path, json)| serde_json::json!({ "path": path.to_string_lossy(), "config": json }))
+            .collect();
+        let mut merged = serde_json::Value::Object(serde_json::Map::default());
+        let mut overrides = ESLintOverrides::default();
+        let mut ignore_patterns = ESLintIgnorePatterns::default();
+        for (path, mut json) in layers {
+            // `overrides` globs and `ignorePatterns` are relative to the directory containing the configuration file
+            let base_dir = path.parent().unwrap_or_else(|| Path::new(""));
+            if let Some(layer_overrides) =
+                json.as_object_mut().and_then(|json| json.remove("overrides"))
+            {
+                let layer_overrides =
+                    ESLintOverrides::deserialize(&layer_overrides).map_err(to_error)?;
+                overrides.extend(layer_overrides.with_base_dir(base_dir));
+            }
+            if let Some(layer_ignore_patterns) =
+                json.as_object_mut().and_then(|json| json.remove("ignorePatterns"))
+            {
+                ignore_patterns.add(base_dir, &layer_ignore_patterns).map_err(|err| {
+                    FailedToParseConfigError(vec![Error::new(FailedToParseConfigPropertyError(
+                        format!("ignorePatterns: {err}"),
+                    ))])
+                })?;
+            }
+            extends::merge_config(&mut merged, json);
+        }
+
+        let mut config = Self::deserialize(&merged).map_err(to_error)?;
+        config.overrides = overrides;
+        config.ignore_patterns = ignore_patterns;
+        config.sources = sources;
+        if let Some(env) = config.parser_options.ecma_version_env() {
+            config.env.enable(env);
+        }
+        Ok(config)
+    }
+
+    /// Read the configuration file and the configuration files it `extends`, merged into one json value.
+    /// `files` collects the paths of the configuration files read
+    fn read_json_with_extends(
+        path: &Path,
+        visited: &mut Vec<PathBuf>,
+        files: &mut Vec<PathBuf>,
+    ) -> Result<serde_json::Value, Report> {
+        let normalized_path = extends::normalize_path(path);
+        files.push(normalized_path.clone());
+        if visited.contains(&normalized_path) {
+            return Err(FailedToParseConfigError(vec![Error::new(CircularExtendsError(
+                path.to_path_buf(),
+            ))])
+            .into());
+        }
+        visited.push(normalized_path);
+
+        let json = Self::read_json(path)?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let json = extends::resolve_extends(json, dir, |path| {
+            Self::read_json_with_extends(path, visited, files)
+        })?;
+
+        visited.pop();
+        Ok(json)
+    }
+
+    fn read_json(path: &Path) -> Result<serde_json::Value, Report> {
         let mut string = std::fs::read_to_string(path).map_err(|e| {
             FailedToParseConfigError(vec![Error::new(FailedToOpenFileError(path.to_path_buf(), e))])
         })?;
@@ -57,91 +219,78 @@ impl ESLintConfig {
             ))])
         })?;
 
-        let config = Self::deserialize(&json).map_err(|err| {
-            FailedToParseConfigError(vec![Error::new(FailedToParseConfigPropertyError(
-                err.to_string(),
-            ))])
-        })?;
-
-        Ok(config)
-    }
+        if is_oxlintrc(path) {
+            // Validate the native format, it is then merged the same way as ESLint configuration
+            Oxlintrc::deserialize(&json).map_err(|err| {
+                FailedToParseConfigError(vec![Error::new(FailedToParseConfigJsonError(
+                    path.to_path_buf(),
+                    err.to_string(),
+                ))])
+            })?;
+        }
 
-    pub fn properties(self) -> (ESLintSettings, ESLintEnv) {
-        (self.settings, self.env)
+        Ok(json)
     }
 
-    #[allow(clippy::option_if_let_else)]
     pub fn override_rules(
         &self,
-        rules_for_override: &mut FxHashSet<RuleEnum>,
+        rules_for_override: &mut FxHashSet<RuleWithSeverity>,
         all_rules: &[RuleEnum],
     ) {
-        use itertools::Itertools;
-        let mut rules_to_replace = vec![];
-        let mut rules_to_remove = vec![];
-
-        // Rules can have the same name but different plugin names
-        let lookup = self.rules.iter().into_group_map_by(|r| r.rule_name.as_str());
-
-        for (name, rule_configs) in &lookup {
-            match rule_configs.len() {
-                0 => unreachable!(),
-                1 => {
-                    let rule_config = &rule_configs[0];
-                    let rule_name = &rule_config.rule_name;
-                    let plugin_name = &rule_config.plugin_name;
-                    match rule_config.severity {
-                        AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
-                            if let Some(rule) = all_rules
-                                .iter()
-                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
-                            {
-                                rules_to_replace.push(rule.read_json(rule_config.config.clone()));
-                            }
-                        }
-                        AllowWarnDeny::Allow => {
-                            if let Some(rule) = rules_for_override
-                                .iter()
-                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
-                            {
-                                rules_to_remove.push(rule.clone());
-                            }
-                        }
-                    }
-                }
-                _ => {
-                    // For overlapping rule names, use the "error" one
-                    // "no-loss-of-precision": "off",
-                    // "@typescript-eslint/no-loss-of-precision": "error"
-                    if let Some(rule_config) =
-                        rule_configs.iter().find(|r| r.severity.is_warn_deny())
-                    {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            rules_to_replace.push(rule.read_json(rule_config.config.clone()));
-                        }
-                    } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            rules_to_remove.push(rule.clone());
-                        }
-                    }
-                }
-            }
-        }
+        self.categories.override_rules(rules_for_override, all_rules);
+        self.rules.override_rules(rules_for_override, all_rules);
+    }
+}
 
-        for rule in rules_to_remove {
-            rules_for_override.remove(&rule);
-        }
-        for rule in rules_to_replace {
-            rules_for_override.replace(rule);
-        }
+/// Files named `.oxlintrc.json`, whether discovered or passed with `-c`
+fn is_oxlintrc(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == ".oxlintrc.json")
+}
+
+/// `plugins` toggles of [Oxlintrc].
+/// ESLint's `plugins` is a list of packages which only load rule definitions, it is ignored.
+fn deserialize_plugins<'de, D>(deserializer: D) -> Result<OxlintPlugins, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Plugins {
+        Toggles(OxlintPlugins),
+        ESLint(Vec<String>),
+    }
+
+    Ok(match Plugins::deserialize(deserializer)? {
+        Plugins::Toggles(plugins) => plugins,
+        Plugins::ESLint(_) => OxlintPlugins::default(),
+    })
+}
+
+/// The root of the git repository containing `cwd`, or `cwd`,
+/// where the lookup of configuration files stops.
+pub(crate) fn project_root(cwd: &Path) -> Box<Path> {
+    cwd.ancestors().find(|dir| dir.join(".git").exists()).unwrap_or(cwd).into()
+}
+
+/// `path` relative to `base_dir`, `None` if `path` is not inside `base_dir`.
+fn relative_path<'p>(path: &'p Path, base_dir: &Path) -> Option<Cow<'p, Path>> {
+    if path.is_absolute() == base_dir.is_absolute() {
+        return path.strip_prefix(base_dir).ok().map(Cow::Borrowed);
     }
+    // Mixed absolute and relative paths, resolve both against the working directory.
+    let cwd = std::env::current_dir().ok()?;
+    cwd.join(path)
+        .strip_prefix(cwd.join(base_dir))
+        .ok()
+        .map(|relative| Cow::Owned(relative.to_path_buf()))
 }
 
 #[cfg(test)]
 mod test {
-    use super::ESLintConfig;
+    use super::{is_oxlintrc, ESLintConfig, GlobalValue};
+    use crate::LintOptions;
     use serde::Deserialize;
-    use std::env;
+    use std::{env, path::Path};
 
     #[test]
     fn test_from_file() {
@@ -150,6 +299,106 @@ mod test {
         assert!(!config.rules.is_empty());
     }
 
+    #[test]
+    fn test_from_file_with_extends() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/eslintrc.json");
+        let config = ESLintConfig::from_file(&fixture_path).unwrap();
+        let no_console = config.rules.iter().find(|r| r.rule_name == "no-console").unwrap();
+        assert!(no_console.severity.is_allow());
+        let eqeqeq = config.rules.iter().find(|r| r.rule_name == "eqeqeq").unwrap();
+        assert!(eqeqeq.severity.is_warn_deny());
+        assert_eq!(eqeqeq.config, Some(serde_json::json!(["always"])));
+        assert!(config.rules.iter().any(|r| r.rule_name == "jsx-key" && r.plugin_name == "react"));
+        assert!(config.env.iter().any(|env| env == "browser"));
+        assert!(config.env.iter().any(|env| env == "node"));
+    }
+
+    #[test]
+    fn test_from_directory() {
+        let root_dir = env::current_dir().unwrap();
+        let fixture_path = root_dir.join("fixtures/config_discovery");
+        let config = ESLintConfig::from_directory(&fixture_path, &root_dir).unwrap().unwrap();
+        let no_console = config.rules.iter().find(|r| r.rule_name == "no-console").unwrap();
+        assert!(no_console.severity.is_warn_deny());
+        assert_eq!(config.overrides.len(), 1);
+
+        let config =
+            ESLintConfig::from_directory(&fixture_path.join("nested"), &root_dir).unwrap().unwrap();
+        let no_console = config.rules.iter().find(|r| r.rule_name == "no-console").unwrap();
+        assert!(no_console.severity.is_allow());
+        assert!(config.env.iter().any(|env| env == "browser"));
+        assert_eq!(config.overrides.len(), 2);
+        assert_eq!(
+            config.overrides.matched_indices(&fixture_path.join("nested/scripts/foo.js")),
+            vec![1]
+        );
+    }
+
+    #[test]
+    fn test_from_directory_stops_at_root_dir() {
+        let nested = env::current_dir().unwrap().join("fixtures/config_discovery/nested");
+        let config = ESLintConfig::from_directory(&nested, &nested).unwrap().unwrap();
+        assert!(!config.env.iter().any(|env| env == "browser"));
+        assert_eq!(config.overrides.len(), 1);
+
+        let scripts = nested.join("scripts");
+        let config = ESLintConfig::from_directory(&scripts, &scripts).unwrap();
+        assert!(config.is_none());
+    }
+
+    #[test]
+    fn test_from_file_with_language_options() {
+        let fixture_path =
+            env::current_dir().unwrap().join("fixtures/language_options/eslintrc.json");
+        let config = ESLintConfig::from_file(&fixture_path).unwrap();
+        assert_eq!(config.globals.get("foo"), Some(GlobalValue::Readonly));
+        assert!(config.env.iter().any(|env| env == "es2020"));
+        assert!(config.ignore_patterns.is_ignored(&fixture_path.with_file_name("dist/index.js")));
+        assert!(!config.ignore_patterns.is_ignored(&fixture_path.with_file_name("src/index.js")));
+    }
+
+    #[test]
+    fn test_from_file_oxlintrc() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/oxlintrc/.oxlintrc.json");
+        let config = ESLintConfig::from_file(&fixture_path).unwrap();
+        assert!(config.plugins.react_perf && !config.plugins.jest);
+        let eqeqeq = config.rules.iter().find(|r| r.rule_name == "eqeqeq").unwrap();
+        assert_eq!(eqeqeq.config, Some(serde_json::json!(["always"])));
+        assert!(config.env.iter().any(|env| env == "browser"));
+
+        let dir = fixture_path.parent().unwrap();
+        let config = ESLintConfig::from_directory(dir, dir).unwrap().unwrap();
+        assert!(config.plugins.react_perf);
+
+        let fixture_path = dir.join("invalid/.oxlintrc.json");
+        assert!(ESLintConfig::from_file(&fixture_path).is_err());
+    }
+
+    #[test]
+    fn test_is_oxlintrc() {
+        assert!(is_oxlintrc(Path::new(".oxlintrc.json")));
+        assert!(is_oxlintrc(Path::new("fixtures/.oxlintrc.json")));
+        assert!(!is_oxlintrc(Path::new("notoxlintrc.json")));
+        assert!(!is_oxlintrc(Path::new("fixtures/.oxlintrc.json.bak")));
+        assert!(!is_oxlintrc(Path::new(".eslintrc.json")));
+    }
+
+    #[test]
+    fn test_from_file_extends_plugin_preset() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/jest.json");
+        let config = ESLintConfig::from_file(&fixture_path).unwrap();
+        assert!(config.plugins.jest);
+        let rules =
+            LintOptions::default().with_config_plugins(config.plugins).derive_rules(&config);
+        assert!(rules.iter().any(|rule| rule.name() == "no-focused-tests"));
+    }
+
+    #[test]
+    fn test_from_file_with_circular_extends() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/circular_a.json");
+        assert!(ESLintConfig::from_file(&fixture_path).is_err());
+    }
+
     #[test]
     fn test_deserialize() {
         let config = ESLintConfig::deserialize(&serde_json::json!({
@@ -177,13 +426,21 @@ mod test {
                     }
                 },
             },
-            "env": { "browser": true, }
+            "env": { "browser": true, },
+            "overrides": [
+                {
+                    "files": ["scripts/**", "*.test.ts"],
+                    "excludedFiles": "fixtures/**",
+                    "rules": { "no-console": "off" }
+                }
+            ]
         }));
         assert!(config.is_ok());
 
-        let ESLintConfig { rules, settings, env } = config.unwrap();
+        let ESLintConfig { rules, settings, env, overrides, .. } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
+        assert_eq!(overrides.len(), 1);
     }
 }
@@ -0,0 +1,166 @@
+use std::{
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use serde::{de, Deserialize, Deserializer};
+
+use super::{relative_path, ESLintRules};
+
+/// The `overrides` field from ESLint config
+/// <https://eslint.org/docs/latest/use/configure/configuration-files#how-do-overrides-work>
+///
+/// TS type is `ConfigOverride[]`
+/// https://github.com/eslint/eslint/blob/ce838adc3b673e52a151f36da0eedf5876977514/lib/shared/types.js#L61
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ESLintOverrides(Vec<ESLintOverride>);
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ESLintOverride {
+    /// Directory of the configuration file, which the glob patterns are relative to
+    #[serde(skip)]
+    base_dir: PathBuf,
+    #[serde(deserialize_with = "deserialize_globs")]
+    files: GlobSet,
+    #[serde(default, deserialize_with = "deserialize_globs")]
+    excluded_files: GlobSet,
+    #[serde(default)]
+    pub rules: ESLintRules,
+}
+
+impl ESLintOverrides {
+    #[must_use]
+    pub fn with_base_dir(mut self, base_dir: &Path) -> Self {
+        for r#override in &mut self.0 {
+            r#override.base_dir = base_dir.to_path_buf();
+        }
+        self
+    }
+
+    /// Append the overrides of a configuration file which takes precedence over this one.
+    pub fn extend(&mut self, overrides: Self) {
+        self.0.extend(overrides.0);
+    }
+
+    /// Indices of the override blocks which apply to `path`, in declaration order.
+    pub fn matched_indices(&self, path: &Path) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, r#override)| r#override.is_match(path))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+impl ESLintOverride {
+    fn is_match(&self, path: &Path) -> bool {
+        let Some(path) = relative_path(path, &self.base_dir) else { return false };
+        self.files.is_match(&path) && !self.excluded_files.is_match(&path)
+    }
+}
+
+impl Deref for ESLintOverrides {
+    type Target = Vec<ESLintOverride>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// `files` and `excludedFiles` are `string | string[]`.
+/// Patterns without a slash match the file name in any directory, the same as minimatch's `matchBase`.
+fn deserialize_globs<'de, D>(deserializer: D) -> Result<GlobSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    let patterns = match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(pattern) => vec![pattern],
+        OneOrMany::Many(patterns) => patterns,
+    };
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.trim_start_matches("./");
+        let pattern =
+            if pattern.contains('/') { pattern.to_string() } else { format!("**/{pattern}") };
+        let glob = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(de::Error::custom)?;
+        builder.add(glob);
+    }
+    builder.build().map_err(de::Error::custom)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde::Deserialize;
+
+    use super::ESLintOverrides;
+
+    #[test]
+    fn test_parse_overrides() {
+        let overrides = ESLintOverrides::deserialize(&serde_json::json!([
+            {
+                "files": ["scripts/**"],
+                "rules": { "no-console": "off" }
+            },
+            {
+                "files": "*.test.ts",
+                "excludedFiles": ["fixtures/**"],
+                "rules": { "jest/no-disabled-tests": "error" }
+            }
+        ]))
+        .unwrap()
+        .with_base_dir(Path::new("packages/foo"));
+
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides[0].rules[0].rule_name, "no-console");
+        assert_eq!(overrides[1].rules[0].plugin_name, "jest");
+
+        assert_eq!(overrides.matched_indices(Path::new("packages/foo/scripts/a/b.js")), vec![0]);
+        assert_eq!(overrides.matched_indices(Path::new("packages/foo/src/a.test.ts")), vec![1]);
+        assert_eq!(overrides.matched_indices(Path::new("packages/foo/a.test.ts")), vec![1]);
+        assert!(overrides.matched_indices(Path::new("packages/foo/fixtures/a.test.ts")).is_empty());
+        assert!(overrides.matched_indices(Path::new("packages/foo/src/scripts/a.js")).is_empty());
+        assert!(overrides.matched_indices(Path::new("packages/foo/src/a.ts")).is_empty());
+    }
+
+    #[test]
+    fn test_extend_overrides() {
+        let mut overrides = ESLintOverrides::deserialize(&serde_json::json!([
+            { "files": "scripts/**", "rules": { "no-console": "off" } }
+        ]))
+        .unwrap()
+        .with_base_dir(Path::new("root"));
+        let nested = ESLintOverrides::deserialize(&serde_json::json!([
+            { "files": "scripts/**", "rules": { "no-debugger": "off" } }
+        ]))
+        .unwrap()
+        .with_base_dir(Path::new("root/nested"));
+        overrides.extend(nested);
+
+        assert_eq!(overrides.matched_indices(Path::new("root/scripts/a.js")), vec![0]);
+        assert_eq!(overrides.matched_indices(Path::new("root/nested/scripts/a.js")), vec![1]);
+    }
+
+    #[test]
+    fn test_parse_overrides_without_files() {
+        let overrides = ESLintOverrides::deserialize(&serde_json::json!([
+            { "rules": { "no-console": "off" } }
+        ]));
+        assert!(overrides.is_err());
+    }
+}
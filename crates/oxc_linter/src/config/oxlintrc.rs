@@ -0,0 +1,382 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{ArrayValidation, InstanceType, Schema, SchemaObject, SubschemaValidation},
+    schema_for, JsonSchema,
+};
+use serde::{de, Deserialize, Deserializer};
+
+use super::{rules::parse_rule_key, ESLintEnv, ESLintGlobals, ESLintSettings};
+use crate::{
+    rule::RuleWithSeverity,
+    rules::{RuleEnum, RULES},
+    AllowWarnDeny, RuleCategory,
+};
+
+// Example:
+//
+// ```json
+// {
+//   "$schema": "./node_modules/oxlint/configuration_schema.json",
+//   "plugins": { "import": true },
+//   "categories": { "correctness": "error", "suspicious": "warn" },
+//   "rules": { "eqeqeq": "warn", "import/no-cycle": ["error", { "maxDepth": 3 }] }
+// }
+// ```
+
+/// Oxlint Configuration File
+///
+/// The native configuration format, read from `.oxlintrc.json` or passed with `-c`.
+/// It follows the shape of ESLint's `.eslintrc.json`, but only accepts the features oxlint implements,
+/// and adds `categories` and `plugins`.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct Oxlintrc {
+    #[serde(rename = "$schema", default)]
+    pub schema: Option<String>,
+    /// Configuration files to extend, relative to this file,
+    /// or built-in presets such as `"oxc:recommended"` and `"plugin:react/recommended"`
+    #[serde(default)]
+    pub extends: Vec<String>,
+    /// Do not look up configuration files in parent directories
+    #[serde(default)]
+    pub root: bool,
+    /// Plugins to enable in addition to the ones enabled on the command line
+    #[serde(default)]
+    pub plugins: OxlintPlugins,
+    /// Severity of all the rules in a category, applied before `rules`
+    #[serde(default)]
+    pub categories: OxlintCategories,
+    #[serde(default)]
+    pub rules: OxlintRules,
+    #[serde(default)]
+    pub settings: ESLintSettings,
+    #[serde(default)]
+    pub env: ESLintEnv,
+    #[serde(default)]
+    pub globals: ESLintGlobals,
+    /// Files to ignore, in `.gitignore` syntax and relative to this file
+    #[serde(default)]
+    pub ignore_patterns: Vec<String>,
+    #[serde(default)]
+    pub overrides: Vec<OxlintOverride>,
+}
+
+/// Rules for the files matching `files`
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct OxlintOverride {
+    /// Glob patterns relative to this file
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub excluded_files: Vec<String>,
+    #[serde(default)]
+    pub rules: OxlintRules,
+}
+
+/// Plugins toggled on in the configuration file.
+///
+/// Plugins can only be enabled, the command line flags take effect when a plugin is not set here.
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct OxlintPlugins {
+    /// eslint-plugin-import, also builds the module graph for cross-module rules
+    pub import: bool,
+    /// eslint-plugin-jest
+    pub jest: bool,
+    /// eslint-plugin-jsx-a11y
+    pub jsx_a11y: bool,
+    /// @next/eslint-plugin-next
+    pub nextjs: bool,
+    /// eslint-plugin-react-perf
+    pub react_perf: bool,
+}
+
+/// The `categories` field, severity by rule category
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OxlintCategories(FxHashMap<RuleCategory, AllowWarnDeny>);
+
+impl OxlintCategories {
+    pub fn override_rules(
+        &self,
+        rules_for_override: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+    ) {
+        for (category, severity) in &self.0 {
+            if severity.is_warn_deny() {
+                for rule in all_rules.iter().filter(|rule| rule.category() == *category) {
+                    rules_for_override
+                        .replace(RuleWithSeverity::new(rule.clone(), Some(*severity)));
+                }
+            } else {
+                rules_for_override.retain(|rule| rule.category() != *category);
+            }
+        }
+    }
+}
+
+impl JsonSchema for OxlintCategories {
+    fn schema_name() -> String {
+        "OxlintCategories".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            ..SchemaObject::default()
+        };
+        let object = schema.object();
+        object.property_names = Some(Box::new(gen.subschema_for::<RuleCategory>()));
+        object.additional_properties = Some(Box::new(gen.subschema_for::<AllowWarnDeny>()));
+        schema.into()
+    }
+}
+
+/// The `rules` field, only rules implemented by oxlint are accepted.
+///
+/// Rule names are `<plugin>/<rule>` with the plugin names of `plugins`, e.g. `jsx-a11y/alt-text`,
+/// or `<rule>` for ESLint rules. ESLint plugin names such as `@typescript-eslint` are accepted as well.
+#[derive(Debug, Clone, Default)]
+pub struct OxlintRules(FxHashMap<String, OxlintRuleConf>);
+
+/// `"off" | "warn" | "error"` or `0 | 1 | 2`, or `[severity, ...options]`
+#[derive(Debug, Clone)]
+pub struct OxlintRuleConf {
+    pub severity: AllowWarnDeny,
+    pub options: Vec<serde_json::Value>,
+}
+
+impl OxlintRules {
+    pub fn get(&self, name: &str) -> Option<&OxlintRuleConf> {
+        self.0.get(name)
+    }
+}
+
+impl<'de> Deserialize<'de> for OxlintRules {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let rules = FxHashMap::<String, OxlintRuleConf>::deserialize(deserializer)?;
+        for name in rules.keys() {
+            let (plugin_name, rule_name) = parse_rule_key(name);
+            if !RULES
+                .iter()
+                .any(|rule| rule.plugin_name() == plugin_name && rule.name() == rule_name)
+            {
+                return Err(de::Error::custom(format!("unknown rule {name:?}")));
+            }
+        }
+        Ok(Self(rules))
+    }
+}
+
+impl<'de> Deserialize<'de> for OxlintRuleConf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (severity, options) = match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(mut values) if !values.is_empty() => {
+                let severity = values.remove(0);
+                (severity, values)
+            }
+            value @ (serde_json::Value::String(_) | serde_json::Value::Number(_)) => {
+                (value, vec![])
+            }
+            value => {
+                return Err(de::Error::custom(format!(
+                    "expected a severity or `[severity, ...options]` for rule, but got {value}"
+                )))
+            }
+        };
+        let severity = AllowWarnDeny::deserialize(severity).map_err(de::Error::custom)?;
+        Ok(Self { severity, options })
+    }
+}
+
+impl JsonSchema for OxlintRules {
+    fn schema_name() -> String {
+        "OxlintRules".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        let rule_conf = gen.subschema_for::<OxlintRuleConf>();
+        let severity = gen.subschema_for::<AllowWarnDeny>();
+        // Options are inlined, rules have option types of the same name
+        let mut options_gen =
+            SchemaSettings::draft07().with(|s| s.inline_subschemas = true).into_generator();
+        let mut schema = SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            ..SchemaObject::default()
+        };
+        let object = schema.object();
+        for rule in RULES.iter() {
+            let options = rule.options_schema(&mut options_gen);
+            let mut property = if options.is_empty() {
+                // `$ref` with sibling keywords is not allowed, wrap it for the description
+                SchemaObject {
+                    subschemas: Some(Box::new(SubschemaValidation {
+                        all_of: Some(vec![rule_conf.clone()]),
+                        ..SubschemaValidation::default()
+                    })),
+                    ..SchemaObject::default()
+                }
+            } else {
+                rule_conf_schema(severity.clone(), options)
+            };
+            property.metadata().description = rule.documentation().and_then(rule_summary);
+            object.properties.insert(rule_key(rule), property.into());
+        }
+        // Rule names with ESLint plugin names
+        object.additional_properties = Some(Box::new(rule_conf));
+        schema.into()
+    }
+}
+
+/// `<plugin>/<rule>` with the plugin name of [OxlintPlugins], or `<rule>` for ESLint rules
+fn rule_key(rule: &RuleEnum) -> String {
+    match rule.plugin_name() {
+        "eslint" => rule.name().to_string(),
+        plugin_name => format!("{}/{}", plugin_name.replace('_', "-"), rule.name()),
+    }
+}
+
+/// `severity` or `[severity, ...options]`
+fn rule_conf_schema(severity: Schema, options: Vec<Schema>) -> SchemaObject {
+    let items = std::iter::once(severity.clone()).chain(options).collect::<Vec<_>>();
+    let with_options = SchemaObject {
+        instance_type: Some(InstanceType::Array.into()),
+        array: Some(Box::new(ArrayValidation {
+            items: Some(items.into()),
+            min_items: Some(1),
+            ..ArrayValidation::default()
+        })),
+        ..SchemaObject::default()
+    };
+    SchemaObject {
+        subschemas: Some(Box::new(SubschemaValidation {
+            any_of: Some(vec![severity, with_options.into()]),
+            ..SubschemaValidation::default()
+        })),
+        ..SchemaObject::default()
+    }
+}
+
+impl JsonSchema for OxlintRuleConf {
+    fn schema_name() -> String {
+        "OxlintRuleConf".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        rule_conf_schema(gen.subschema_for::<AllowWarnDeny>(), vec![]).into()
+    }
+}
+
+/// The first paragraph of "What it does" in the rule documentation
+fn rule_summary(documentation: &str) -> Option<String> {
+    let (_, what_it_does) = documentation.split_once("### What it does")?;
+    let summary = what_it_does
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!summary.is_empty()).then_some(summary)
+}
+
+impl Oxlintrc {
+    /// JSON Schema of the configuration file, for editor completion and validation
+    ///
+    /// # Panics
+    pub fn json_schema() -> String {
+        let schema = schema_for!(Oxlintrc);
+        let mut json = serde_json::to_string_pretty(&schema).unwrap();
+        json.push('\n');
+        json
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::Oxlintrc;
+    use crate::{AllowWarnDeny, RuleCategory};
+
+    #[test]
+    fn test_parse_oxlintrc() {
+        let config = Oxlintrc::deserialize(&serde_json::json!({
+            "$schema": "./node_modules/oxlint/configuration_schema.json",
+            "plugins": { "import": true, "jsx-a11y": true },
+            "categories": { "correctness": "error", "pedantic": "warn", "nursery": "off" },
+            "rules": {
+                "eqeqeq": ["warn", "always"],
+                "no-debugger": 0,
+                "no-console": [2],
+                "typescript/no-explicit-any": "error",
+                "@typescript-eslint/no-namespace": "off"
+            },
+            "overrides": [{ "files": ["*.test.js"], "rules": { "no-console": "off" } }]
+        }))
+        .unwrap();
+        assert!(config.plugins.import && config.plugins.jsx_a11y && !config.plugins.jest);
+        assert_eq!(config.categories.0.get(&RuleCategory::Pedantic), Some(&AllowWarnDeny::Warn));
+        let eqeqeq = config.rules.get("eqeqeq").unwrap();
+        assert_eq!(eqeqeq.severity, AllowWarnDeny::Warn);
+        assert_eq!(eqeqeq.options, vec![serde_json::json!("always")]);
+        assert_eq!(config.rules.get("no-debugger").unwrap().severity, AllowWarnDeny::Allow);
+        assert_eq!(config.rules.get("no-console").unwrap().severity, AllowWarnDeny::Deny);
+        assert_eq!(config.overrides.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_oxlintrc_invalid() {
+        let invalid = [
+            serde_json::json!({ "rules": { "no-such-rule": "error" } }),
+            serde_json::json!({ "rules": { "eqeqeq": 3 } }),
+            serde_json::json!({ "rules": { "eqeqeq": [] } }),
+            serde_json::json!({ "categories": { "unknown": "error" } }),
+            serde_json::json!({ "plugins": { "unknown": true } }),
+            serde_json::json!({ "parser": "@typescript-eslint/parser" }),
+        ];
+        for config in invalid {
+            assert!(Oxlintrc::deserialize(&config).is_err(), "{config}");
+        }
+    }
+
+    #[test]
+    fn test_json_schema() {
+        let path =
+            std::env::current_dir().unwrap().join("../../npm/oxlint/configuration_schema.json");
+        let schema = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            schema == Oxlintrc::json_schema(),
+            "{} is outdated, run `just linter-schema-json` to update it",
+            path.display()
+        );
+    }
+
+    #[test]
+    fn test_json_schema_rules() {
+        let schema: serde_json::Value = serde_json::from_str(&Oxlintrc::json_schema()).unwrap();
+        let rules = schema["definitions"]["OxlintRules"]["properties"].as_object().unwrap();
+        // Plugin names are spelled as in `plugins`
+        assert!(rules.contains_key("jsx-a11y/alt-text"));
+        assert!(rules.contains_key("react-perf/jsx-no-new-object-as-prop"));
+        assert!(rules.keys().all(|key| !key.contains('_')));
+
+        // `[severity, ...options]` with the options of the rule
+        let items = &rules["eqeqeq"]["anyOf"][1]["items"];
+        assert_eq!(items[1]["enum"], serde_json::json!(["always", "smart"]));
+        assert_eq!(
+            items[2]["properties"]["null"]["enum"],
+            serde_json::json!(["always", "never", "ignore"])
+        );
+        let options = &rules["no-console"]["anyOf"][1]["items"][1];
+        assert_eq!(options["properties"]["allow"]["type"], "array");
+        // Rules without options only take a severity
+        assert!(rules["no-debugger"]["allOf"].is_array());
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{de, Deserialize, Deserializer};
+
+/// The `parserOptions` field from ESLint config
+/// <https://eslint.org/docs/latest/use/configure/language-options#specifying-parser-options>
+///
+/// Only `ecmaVersion` is read, the parser always accepts the latest syntax.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ESLintParserOptions {
+    #[serde(default, deserialize_with = "deserialize_ecma_version")]
+    pub ecma_version: Option<u32>,
+}
+
+/// The latest `ecmaVersion` with an environment in `javascript_globals`
+const LATEST_ECMA_VERSION: u32 = 2024;
+
+impl ESLintParserOptions {
+    /// The environment providing the globals of `ecmaVersion`, e.g. `es2021` for `12` or `2021`.
+    /// ES3 and ES5 globals are in the `builtin` environment.
+    pub fn ecma_version_env(&self) -> Option<String> {
+        let version = self.ecma_version?;
+        // Versions 6 to 15 are aliases of 2015 to 2024
+        let year = if version < 2015 { version + 2009 } else { version };
+        (2015..=LATEST_ECMA_VERSION).contains(&year).then(|| format!("es{year}"))
+    }
+}
+
+/// TS type is `3 | 5 | 6 | ... | 2015 | ... | "latest"`
+fn deserialize_ecma_version<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(s) if s == "latest" => Ok(Some(LATEST_ECMA_VERSION)),
+        serde_json::Value::Number(n) => n
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .map(Some)
+            .ok_or_else(|| de::Error::custom(format!("invalid ecmaVersion {n}"))),
+        value => Err(de::Error::custom(format!("invalid ecmaVersion {value}"))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::ESLintParserOptions;
+
+    fn ecma_version_env(value: &serde_json::Value) -> Option<String> {
+        ESLintParserOptions::deserialize(&serde_json::json!({ "ecmaVersion": value }))
+            .unwrap()
+            .ecma_version_env()
+    }
+
+    #[test]
+    fn test_parse_ecma_version() {
+        assert_eq!(ecma_version_env(&serde_json::json!(5)), None);
+        assert_eq!(ecma_version_env(&serde_json::json!(6)), Some("es2015".to_string()));
+        assert_eq!(ecma_version_env(&serde_json::json!(12)), Some("es2021".to_string()));
+        assert_eq!(ecma_version_env(&serde_json::json!(2022)), Some("es2022".to_string()));
+        assert_eq!(ecma_version_env(&serde_json::json!("latest")), Some("es2024".to_string()));
+        assert!(ESLintParserOptions::deserialize(&serde_json::json!({ "ecmaVersion": "next" }))
+            .is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use super::errors::FailedToParseRuleValueError;
-use crate::AllowWarnDeny;
+use crate::{rule::RuleWithSeverity, rules::RuleEnum, AllowWarnDeny};
 use oxc_diagnostics::Error;
+use rustc_hash::FxHashSet;
 use serde::de::{self, Deserializer, Visitor};
 use serde::Deserialize;
 use std::fmt;
@@ -60,7 +61,7 @@ impl<'de> Deserialize<'de> for ESLintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(crate) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return ("eslint".to_string(), name.to_string());
     };
@@ -116,6 +117,80 @@ fn parse_rule_value(
     }
 }
 
+impl ESLintRules {
+    #[allow(clippy::option_if_let_else)]
+    pub fn override_rules(
+        &self,
+        rules_for_override: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+    ) {
+        use itertools::Itertools;
+        let mut rules_to_replace = vec![];
+        let mut rules_to_remove = vec![];
+
+        // Rules can have the same name but different plugin names
+        let lookup = self.iter().into_group_map_by(|r| r.rule_name.as_str());
+
+        for (name, rule_configs) in &lookup {
+            match rule_configs.len() {
+                0 => unreachable!(),
+                1 => {
+                    let rule_config = &rule_configs[0];
+                    let rule_name = &rule_config.rule_name;
+                    let plugin_name = &rule_config.plugin_name;
+                    match rule_config.severity {
+                        AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
+                            if let Some(rule) = all_rules
+                                .iter()
+                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+                            {
+                                rules_to_replace.push(RuleWithSeverity::new(
+                                    rule.read_json(rule_config.config.clone()),
+                                    Some(rule_config.severity),
+                                ));
+                            }
+                        }
+                        AllowWarnDeny::Allow => {
+                            if let Some(rule) = rules_for_override
+                                .iter()
+                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+                            {
+                                rules_to_remove.push(rule.clone());
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    // For overlapping rule names, use the "error" one
+                    // "no-loss-of-precision": "off",
+                    // "@typescript-eslint/no-loss-of-precision": "error"
+                    if let Some(rule_config) =
+                        rule_configs.iter().find(|r| r.severity.is_warn_deny())
+                    {
+                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                            rules_to_replace.push(RuleWithSeverity::new(
+                                rule.read_json(rule_config.config.clone()),
+                                Some(rule_config.severity),
+                            ));
+                        }
+                    } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
+                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                            rules_to_remove.push(rule.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        for rule in rules_to_remove {
+            rules_for_override.remove(&rule);
+        }
+        for rule in rules_to_replace {
+            rules_for_override.replace(rule);
+        }
+    }
+}
+
 impl Deref for ESLintRules {
     type Target = Vec<ESLintRule>;
 
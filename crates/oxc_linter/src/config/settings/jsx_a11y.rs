@@ -1,8 +1,9 @@
 use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 /// https://github.com/jsx-eslint/eslint-plugin-jsx-a11y#configurations
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, JsonSchema)]
 pub struct ESLintSettingsJSXA11y {
     #[serde(rename = "polymorphicPropName")]
     pub polymorphic_prop_name: Option<String>,
@@ -1,4 +1,5 @@
 use self::{jsx_a11y::ESLintSettingsJSXA11y, next::ESLintSettingsNext, react::ESLintSettingsReact};
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 mod jsx_a11y;
@@ -11,7 +12,7 @@ mod react;
 /// TS type is `Object`
 /// https://github.com/eslint/eslint/blob/ce838adc3b673e52a151f36da0eedf5876977514/lib/shared/types.js#L53
 /// But each plugin extends this with their own properties.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, JsonSchema)]
 pub struct ESLintSettings {
     #[serde(default)]
     #[serde(rename = "jsx-a11y")]
@@ -1,7 +1,8 @@
+use schemars::JsonSchema;
 use serde::Deserialize;
 
 /// https://github.com/jsx-eslint/eslint-plugin-react#configuration-legacy-eslintrc-
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Deserialize, Default, JsonSchema)]
 pub struct ESLintSettingsReact {
     #[serde(default)]
     #[serde(rename = "formComponents")]
@@ -24,7 +25,7 @@ impl ESLintSettingsReact {
 
 // Deserialize helper types
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
 #[serde(untagged)]
 enum CustomComponent {
     NameOnly(String),
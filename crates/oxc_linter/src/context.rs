@@ -1,15 +1,16 @@
 use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
 use oxc_codegen::{Codegen, CodegenOptions};
-use oxc_diagnostics::Error;
+use oxc_diagnostics::{Error, LintDiagnostic, Severity};
 use oxc_semantic::{AstNodes, JSDocFinder, ScopeTree, Semantic, SymbolTable};
 use oxc_span::SourceType;
 
 use crate::{
+    config::eslint_rule_id,
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message},
+    fixer::{Fix, FixKind, Message, Suggestion},
     javascript_globals::GLOBALS,
-    ESLintEnv, ESLintSettings,
+    AllowWarnDeny, ESLintEnv, ESLintGlobals, ESLintSettings, GlobalValue,
 };
 
 pub struct LintContext<'a> {
@@ -19,16 +20,26 @@ pub struct LintContext<'a> {
 
     disable_directives: DisableDirectives<'a>,
 
-    /// Whether or not to apply code fixes during linting.
-    fix: bool,
+    /// Kinds of code fixes to compute during linting.
+    fix: FixKind,
+
+    /// Compute the fixes of `--fix` to attach them to the diagnostics, see [LintDiagnostic::with_fix]
+    report_fixes: bool,
+
+    current_plugin_name: &'static str,
 
     current_rule_name: &'static str,
 
+    /// Severity configured for the current rule, overrides the severity of its diagnostics
+    current_rule_severity: Option<AllowWarnDeny>,
+
     file_path: Box<Path>,
 
     settings: Arc<ESLintSettings>,
 
     env: Arc<ESLintEnv>,
+
+    globals: Arc<ESLintGlobals>,
 }
 
 impl<'a> LintContext<'a> {
@@ -39,20 +50,30 @@ impl<'a> LintContext<'a> {
             semantic: Rc::clone(semantic),
             diagnostics: RefCell::new(vec![]),
             disable_directives,
-            fix: false,
+            fix: FixKind::empty(),
+            report_fixes: false,
+            current_plugin_name: "",
             current_rule_name: "",
+            current_rule_severity: None,
             file_path,
             settings: Arc::new(ESLintSettings::default()),
             env: Arc::new(ESLintEnv::default()),
+            globals: Arc::new(ESLintGlobals::default()),
         }
     }
 
     #[must_use]
-    pub fn with_fix(mut self, fix: bool) -> Self {
+    pub fn with_fix(mut self, fix: FixKind) -> Self {
         self.fix = fix;
         self
     }
 
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_settings(mut self, settings: &Arc<ESLintSettings>) -> Self {
         self.settings = Arc::clone(settings);
@@ -65,6 +86,12 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_globals(mut self, globals: &Arc<ESLintGlobals>) -> Self {
+        self.globals = Arc::clone(globals);
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -93,7 +120,16 @@ impl<'a> LintContext<'a> {
         &self.env
     }
 
+    /// The value of `var` in the configured `globals`
+    pub fn global(&self, var: &str) -> Option<GlobalValue> {
+        self.globals.get(var)
+    }
+
+    /// Whether `var` is a global variable, from the configured `globals` or environments.
     pub fn env_contains_var(&self, var: &str) -> bool {
+        if let Some(value) = self.global(var) {
+            return value.is_enabled();
+        }
         for env in self.env.iter() {
             let env = GLOBALS.get(env).unwrap_or(&GLOBALS["builtin"]);
             if env.get(var).is_some() {
@@ -105,8 +141,15 @@ impl<'a> LintContext<'a> {
     }
 
     #[inline]
-    pub fn with_rule_name(&mut self, name: &'static str) {
+    pub fn with_rule(
+        &mut self,
+        plugin_name: &'static str,
+        name: &'static str,
+        severity: Option<AllowWarnDeny>,
+    ) {
+        self.current_plugin_name = plugin_name;
         self.current_rule_name = name;
+        self.current_rule_severity = severity;
     }
 
     /* Diagnostics */
@@ -115,14 +158,28 @@ impl<'a> LintContext<'a> {
         self.diagnostics.into_inner()
     }
 
-    fn add_diagnostic(&self, message: Message<'a>) {
-        if !self.disable_directives.contains(self.current_rule_name, message.start()) {
-            self.diagnostics.borrow_mut().push(message);
+    /// `fixable`: whether `--fix` fixes the diagnostic, whether the fix is computed in this run or not
+    fn add_diagnostic(&self, mut message: Message<'a>, fixable: bool) {
+        if self.disable_directives.contains(self.current_rule_name, message.start()) {
+            return;
         }
+        let mut diagnostic = LintDiagnostic::new(message.error)
+            .with_rule_id(eslint_rule_id(self.current_plugin_name, self.current_rule_name))
+            .with_fixable(fixable);
+        if let Some(fix) = message.fix.as_ref().filter(|_| fixable && self.report_fixes) {
+            diagnostic = self.with_reported_fix(diagnostic, fix);
+        }
+        match self.current_rule_severity {
+            Some(AllowWarnDeny::Warn) => diagnostic = diagnostic.with_severity(Severity::Warning),
+            Some(AllowWarnDeny::Deny) => diagnostic = diagnostic.with_severity(Severity::Error),
+            Some(AllowWarnDeny::Allow) | None => {}
+        }
+        message.error = diagnostic.into();
+        self.diagnostics.borrow_mut().push(message);
     }
 
     pub fn diagnostic<T: Into<Error>>(&self, diagnostic: T) {
-        self.add_diagnostic(Message::new(diagnostic.into(), None));
+        self.add_diagnostic(Message::new(diagnostic.into(), None), false);
     }
 
     pub fn diagnostic_with_fix<T, F>(&self, diagnostic: T, fix: F)
@@ -130,10 +187,63 @@ impl<'a> LintContext<'a> {
         T: Into<Error>,
         F: FnOnce() -> Fix<'a>,
     {
-        if self.fix {
-            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::Fix, fix);
+    }
+
+    /// Report a diagnostic with a fix which may change the behavior of the code,
+    /// only applied with `--fix-dangerously`.
+    pub fn diagnostic_with_dangerous_fix<T, F>(&self, diagnostic: T, fix: F)
+    where
+        T: Into<Error>,
+        F: FnOnce() -> Fix<'a>,
+    {
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::Fix | FixKind::Dangerous, fix);
+    }
+
+    fn diagnostic_with_fix_of_kind<T, F>(&self, diagnostic: T, kind: FixKind, fix: F)
+    where
+        T: Into<Error>,
+        F: FnOnce() -> Fix<'a>,
+    {
+        let report_fix = self.report_fixes && kind == FixKind::Fix;
+        let fix = (self.fix.contains(kind) || report_fix).then(fix);
+        self.add_diagnostic(Message::new(diagnostic.into(), fix), kind == FixKind::Fix);
+    }
+
+    /// Report the disable directives which did not suppress any diagnostic,
+    /// must be called after all the rules have run.
+    pub(crate) fn report_unused_disable_directives(&self) {
+        for (diagnostic, fix) in self.disable_directives.unused_directives() {
+            // Not suppressible by other directives
+            let mut error = LintDiagnostic::new(diagnostic.into()).with_fixable(true);
+            if self.report_fixes {
+                error = self.with_reported_fix(error, &fix);
+            }
+            let fix = self.fix.contains(FixKind::Fix).then_some(fix);
+            self.diagnostics.borrow_mut().push(Message::new(error.into(), fix));
+        }
+    }
+
+    fn with_reported_fix(&self, diagnostic: LintDiagnostic, fix: &Fix<'a>) -> LintDiagnostic {
+        let span = fix.span();
+        diagnostic.with_fix(
+            span.start as usize..span.end as usize,
+            fix.replacement(self.semantic.source_text()),
+        )
+    }
+
+    /// Report a diagnostic with alternative fixes for the user to choose from,
+    /// which are never applied by `--fix`.
+    pub fn diagnostic_with_suggestions<T, F>(&self, diagnostic: T, suggestions: F)
+    where
+        T: Into<Error>,
+        F: FnOnce() -> Vec<Suggestion<'a>>,
+    {
+        let message = Message::new(diagnostic.into(), None);
+        if self.fix.contains(FixKind::Suggestion) {
+            self.add_diagnostic(message.with_suggestions(suggestions()), false);
         } else {
-            self.diagnostic(diagnostic);
+            self.add_diagnostic(message, false);
         }
     }
 
@@ -1,12 +1,26 @@
+use std::cell::RefCell;
+
 use oxc_ast::TriviasMap;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::fixer::Fix;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unused eslint-disable directive (no problems were reported{0})")]
+#[diagnostic(severity(warning), help("Remove the directive"))]
+pub struct UnusedDisableDirectiveDiagnostic(String, #[label] pub Span);
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// A rule disabled by the comment starting at `comment_start`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum DisabledRule<'a> {
-    All,
-    Single(&'a str),
+    All { comment_start: u32 },
+    Single { rule_name: &'a str, comment_start: u32 },
 }
 
 /// A comment which disables one or more specific rules
@@ -18,8 +32,11 @@ pub struct DisableRuleComment<'a> {
 }
 
 pub struct DisableDirectives<'a> {
+    source_text: &'a str,
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
+    /// Disabled rules which suppressed at least one diagnostic
+    used: RefCell<FxHashSet<DisabledRule<'a>>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -27,14 +44,24 @@ pub struct DisableDirectives<'a> {
 }
 
 impl<'a> DisableDirectives<'a> {
+    /// Whether `rule_name` is disabled at `start`, the matching directives are marked as used.
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
+        let mut used = self.used.borrow_mut();
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let matched = match interval.val {
+                DisabledRule::All { .. } => true,
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
                 // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+                DisabledRule::Single { rule_name: name, .. } => name.contains(rule_name),
+            };
+            if matched {
+                used.insert(interval.val);
+                contains = true;
+            }
+        }
+        contains
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -44,6 +71,61 @@ impl<'a> DisableDirectives<'a> {
     pub fn disable_rule_comments(&self) -> &Vec<DisableRuleComment<'a>> {
         &self.disable_rule_comments
     }
+
+    /// Diagnostics for the directives which did not suppress any diagnostic,
+    /// with fixes removing the directive or the unused rule names.
+    ///
+    /// Only meaningful after all the rules have run.
+    pub fn unused_directives(&self) -> Vec<(UnusedDisableDirectiveDiagnostic, Fix<'a>)> {
+        let used = self.used.borrow();
+        let mut unused = vec![];
+
+        for span in &self.disable_all_comments {
+            if !used.contains(&DisabledRule::All { comment_start: span.start }) {
+                let diagnostic = UnusedDisableDirectiveDiagnostic(String::new(), *span);
+                unused.push((diagnostic, Fix::delete(self.comment_span(*span))));
+            }
+        }
+
+        for DisableRuleComment { span, rules } in &self.disable_rule_comments {
+            let (unused_rules, used_rules): (Vec<&str>, Vec<&str>) =
+                rules.iter().partition(|rule_name| {
+                    !used.contains(&DisabledRule::Single { rule_name, comment_start: span.start })
+                });
+            if unused_rules.is_empty() {
+                continue;
+            }
+            let names = unused_rules.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>();
+            let diagnostic =
+                UnusedDisableDirectiveDiagnostic(format!(" from {}", names.join(", ")), *span);
+            let fix = if used_rules.is_empty() {
+                Fix::delete(self.comment_span(*span))
+            } else {
+                // Rewrite the list of rule names, keeping the description after `--`
+                let first = self.offset_of(rules[0]);
+                let last = self.offset_of(rules[rules.len() - 1]) + rules[rules.len() - 1].len();
+                #[allow(clippy::cast_possible_truncation)] // for `as u32`
+                let rules_span = Span::new(first as u32, last as u32);
+                Fix::new(used_rules.join(", "), rules_span)
+            };
+            unused.push((diagnostic, fix));
+        }
+
+        unused.sort_by_key(|(diagnostic, _)| diagnostic.1);
+        unused
+    }
+
+    /// Span of the comment including `//` or `/* */`, `span` is the span of its content
+    fn comment_span(&self, span: Span) -> Span {
+        let start = span.start - 2;
+        let is_multi_line = self.source_text[start as usize..].starts_with("/*");
+        Span::new(start, if is_multi_line { span.end + 2 } else { span.end })
+    }
+
+    /// Byte offset of `text`, which is a slice of the source text
+    fn offset_of(&self, text: &str) -> usize {
+        text.as_ptr() as usize - self.source_text.as_ptr() as usize
+    }
 }
 
 pub struct DisableDirectivesBuilder<'a, 'b> {
@@ -51,10 +133,10 @@ pub struct DisableDirectivesBuilder<'a, 'b> {
     trivias: &'b TriviasMap,
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    /// Start and comment start of `eslint-disable`
+    disable_all_start: Option<(u32, u32)>,
+    /// Start and comment start of `eslint-disable rule_name`
+    disable_start_map: FxHashMap<&'a str, (u32, u32)>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -77,7 +159,9 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
     pub fn build(mut self) -> DisableDirectives<'a> {
         self.build_impl();
         DisableDirectives {
+            source_text: self.source_text,
             intervals: self.intervals,
+            used: RefCell::default(),
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
         }
@@ -101,8 +185,12 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             if let Some(text) = text.strip_prefix("eslint-disable") {
                 // `eslint-disable`
                 if text.trim().is_empty() {
-                    if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
+                    // A repeated `eslint-disable` takes over from the previous one,
+                    // so that each directive is only used by the diagnostics it covers.
+                    if let Some((start, comment_start)) =
+                        self.disable_all_start.replace((span.end, span.start))
+                    {
+                        self.add_interval(start, span.start, DisabledRule::All { comment_start });
                     }
                     self.disable_all_comments.push(span);
                     continue;
@@ -116,13 +204,19 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        self.add_interval(
+                            span.end,
+                            stop,
+                            DisabledRule::All { comment_start: span.start },
+                        );
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                            let rule =
+                                DisabledRule::Single { rule_name, comment_start: span.start };
+                            self.add_interval(span.end, stop, rule);
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -141,13 +235,19 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        self.add_interval(
+                            start,
+                            stop,
+                            DisabledRule::All { comment_start: span.start },
+                        );
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                            let rule =
+                                DisabledRule::Single { rule_name, comment_start: span.start };
+                            self.add_interval(start, stop, rule);
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -158,7 +258,12 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
                 // `eslint-disable rule-name1, rule-name2`
                 let mut rules = vec![];
                 Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
+                    if let Some((start, comment_start)) =
+                        self.disable_start_map.insert(rule_name, (span.end, span.start))
+                    {
+                        let rule = DisabledRule::Single { rule_name, comment_start };
+                        self.add_interval(start, span.start, rule);
+                    }
                     rules.push(rule_name);
                 });
                 self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -169,14 +274,17 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
             if let Some(text) = text.strip_prefix("eslint-enable") {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    if let Some((start, comment_start)) = self.disable_all_start.take() {
+                        self.add_interval(start, span.start, DisabledRule::All { comment_start });
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        if let Some((start, comment_start)) =
+                            self.disable_start_map.remove(rule_name)
+                        {
+                            let rule = DisabledRule::Single { rule_name, comment_start };
+                            self.add_interval(start, span.start, rule);
                         }
                     });
                 }
@@ -185,14 +293,15 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        if let Some((start, comment_start)) = self.disable_all_start {
+            self.add_interval(start, source_len, DisabledRule::All { comment_start });
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (rule_name, (start, comment_start)) in disable_start_map {
+            let rule = DisabledRule::Single { rule_name, comment_start };
+            self.add_interval(start, source_len, rule);
         }
     }
 
@@ -203,6 +312,91 @@ impl<'a, 'b> DisableDirectivesBuilder<'a, 'b> {
     }
 }
 
+#[test]
+fn test_unused_directives() {
+    use oxc_allocator::Allocator;
+    use oxc_ast::TriviasMap;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "
+        // eslint-disable-next-line no-debugger
+        debugger;
+        // eslint-disable-next-line no-console, no-debugger -- description
+        debugger;
+        /* eslint-disable-next-line */
+        foo();
+        /* eslint-disable no-alert */
+    ";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let trivias = TriviasMap::from(ret.trivias);
+    let directives = DisableDirectivesBuilder::new(source_text, &trivias).build();
+    for (start, _) in source_text.match_indices("debugger;") {
+        assert!(directives.contains("no-debugger", u32::try_from(start).unwrap()));
+    }
+
+    let unused = directives.unused_directives();
+    let messages = unused.iter().map(|(diagnostic, _)| diagnostic.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "Unused eslint-disable directive (no problems were reported from 'no-console')",
+            "Unused eslint-disable directive (no problems were reported)",
+            "Unused eslint-disable directive (no problems were reported from 'no-alert')",
+        ]
+    );
+
+    let fixed = crate::fixer::Fixer::new(
+        source_text,
+        unused
+            .into_iter()
+            .map(|(diagnostic, fix)| crate::Message::new(diagnostic.into(), Some(fix)))
+            .collect(),
+    )
+    .fix();
+    assert_eq!(
+        fixed.fixed_code,
+        source_text
+            .replace("no-console, ", "")
+            .replace("/* eslint-disable-next-line */", "")
+            .replace("/* eslint-disable no-alert */", "")
+    );
+}
+
+#[test]
+fn test_unused_repeated_directives() {
+    use oxc_allocator::Allocator;
+    use oxc_ast::TriviasMap;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "
+        /* eslint-disable */
+        debugger;
+        /* eslint-disable */
+        debugger;
+        /* eslint-disable no-debugger */
+        /* eslint-disable no-debugger */
+        debugger;
+    ";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let trivias = TriviasMap::from(ret.trivias);
+    let directives = DisableDirectivesBuilder::new(source_text, &trivias).build();
+    for (start, _) in source_text.match_indices("debugger;") {
+        assert!(directives.contains("no-debugger", u32::try_from(start).unwrap()));
+    }
+
+    // Each `debugger` is suppressed by the closest directive before it,
+    // only the first `eslint-disable no-debugger` suppresses nothing
+    let unused = directives.unused_directives();
+    let spans = unused.iter().map(|(diagnostic, _)| diagnostic.1).collect::<Vec<_>>();
+    let first_rule_comment = source_text.find(" eslint-disable no-debugger ").unwrap();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].start, u32::try_from(first_rule_comment).unwrap());
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
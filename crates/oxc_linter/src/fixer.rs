@@ -1,15 +1,52 @@
 use std::borrow::Cow;
 
-use oxc_diagnostics::Error;
+use bitflags::bitflags;
+use oxc_allocator::Allocator;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error as ThisError},
+    Error,
+};
 use oxc_span::Span;
 
-#[derive(Debug, Default)]
-pub struct Fix<'a> {
+/// Maximum number of passes of `--fix`, the same as ESLint
+pub const MAX_FIX_PASSES: usize = 10;
+
+#[derive(Debug, ThisError, Diagnostic)]
+#[error("Stopped fixing after {0} passes because the fixes oscillate between the same outputs")]
+#[diagnostic(
+    severity(warning),
+    help("Some fixes undo each other, fix the remaining problems manually")
+)]
+pub struct FixOscillationDiagnostic(
+    pub usize,
+    #[label("This fix is applied again, it conflicts with another fix")] pub Span,
+);
+
+bitflags! {
+    /// Kinds of fixes a rule can produce, also used to select which kinds are computed and applied.
+    ///
+    /// A fix is applied when all of its kinds are enabled,
+    /// e.g. a dangerous fix (`Fix | Dangerous`) needs both `--fix` and `--fix-dangerously`.
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+    pub struct FixKind: u8 {
+        /// Fixes which do not change the behavior of the code, applied by `--fix`
+        const Fix = 1 << 0;
+        /// Alternative fixes which are offered to the user, applied by `--fix-suggestions`
+        const Suggestion = 1 << 1;
+        /// Fixes which may change the behavior of the code, applied by `--fix-dangerously`
+        const Dangerous = 1 << 2;
+    }
+}
+
+/// Replacement of the text in `span` with `content`
+#[derive(Debug, Default, Clone)]
+pub struct Edit<'a> {
     pub content: Cow<'a, str>,
     pub span: Span,
 }
 
-impl<'a> Fix<'a> {
+impl<'a> Edit<'a> {
     pub const fn delete(span: Span) -> Self {
         Self { content: Cow::Borrowed(""), span }
     }
@@ -19,11 +56,90 @@ impl<'a> Fix<'a> {
     }
 }
 
+/// A fix made of one or more edits, which are applied all together or not at all.
+///
+/// The edits of a fix must not overlap each other, otherwise the fix is not applied.
+#[derive(Debug, Default)]
+pub struct Fix<'a> {
+    edits: Vec<Edit<'a>>,
+}
+
+impl<'a> Fix<'a> {
+    pub fn delete(span: Span) -> Self {
+        Self::from(Edit::delete(span))
+    }
+
+    pub fn new<T: Into<Cow<'a, str>>>(content: T, span: Span) -> Self {
+        Self::from(Edit::new(content, span))
+    }
+
+    /// e.g. add an import and rewrite a call in one fix
+    pub fn from_edits(mut edits: Vec<Edit<'a>>) -> Self {
+        edits.sort_by_key(|edit| edit.span);
+        Self { edits }
+    }
+
+    /// Edits sorted by their spans
+    pub fn edits(&self) -> &[Edit<'a>] {
+        &self.edits
+    }
+
+    /// The span covering all the edits
+    pub fn span(&self) -> Span {
+        match (self.edits.first(), self.edits.last()) {
+            (Some(first), Some(last)) => Span::new(first.span.start, last.span.end),
+            _ => Span::default(),
+        }
+    }
+
+    /// The text replacing [Self::span] when the fix is applied,
+    /// the text between the edits is kept
+    pub fn replacement(&self, source_text: &str) -> String {
+        let mut text = String::new();
+        let mut end = self.span().start as usize;
+        for edit in &self.edits {
+            text.push_str(source_text.get(end..edit.span.start as usize).unwrap_or_default());
+            text.push_str(&edit.content);
+            end = edit.span.end as usize;
+        }
+        text
+    }
+
+    /// Whether the edits are valid ranges and do not overlap each other
+    fn is_valid(&self) -> bool {
+        !self.edits.is_empty()
+            && self.edits.iter().all(|edit| edit.span.start <= edit.span.end)
+            && self.edits.windows(2).all(|pair| pair[0].span.end <= pair[1].span.start)
+    }
+}
+
+impl<'a> From<Edit<'a>> for Fix<'a> {
+    fn from(edit: Edit<'a>) -> Self {
+        Self { edits: vec![edit] }
+    }
+}
+
+/// One of the alternative fixes of a diagnostic, which are never applied by `--fix`
+#[derive(Debug)]
+pub struct Suggestion<'a> {
+    /// Description of the change, e.g. "Remove the `await`"
+    pub message: Cow<'a, str>,
+    pub fix: Fix<'a>,
+}
+
+impl<'a> Suggestion<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(message: T, fix: Fix<'a>) -> Self {
+        Self { message: message.into(), fix }
+    }
+}
+
 #[derive(Debug)]
 pub struct FixResult<'a> {
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message<'a>>,
+    /// Spans of the applied edits in the source text and the length of their content, sorted
+    pub applied_edits: Vec<(Span, usize)>,
 }
 
 #[derive(Debug)]
@@ -32,6 +148,7 @@ pub struct Message<'a> {
     start: u32,
     end: u32,
     pub fix: Option<Fix<'a>>,
+    pub suggestions: Vec<Suggestion<'a>>,
     fixed: bool,
 }
 
@@ -45,7 +162,19 @@ impl<'a> Message<'a> {
             .iter()
             .max_by_key(|span| span.offset() + span.len())
             .map_or(0, |span| (span.offset() + span.len()) as u32);
-        Self { error, start, end, fix, fixed: false }
+        Self { error, start, end, fix, suggestions: vec![], fixed: false }
+    }
+
+    #[must_use]
+    pub fn with_suggestions(mut self, suggestions: Vec<Suggestion<'a>>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// The fix applied by [Fixer], the first suggestion if there is no fix.
+    /// Suggestions are only present when they are enabled with [FixKind::Suggestion].
+    pub fn fix_to_apply(&self) -> Option<&Fix<'a>> {
+        self.fix.as_ref().or_else(|| self.suggestions.first().map(|suggestion| &suggestion.fix))
     }
 
     pub fn start(&self) -> u32 {
@@ -69,49 +198,118 @@ impl<'a> Fixer<'a> {
         Self { source_text, messages }
     }
 
-    /// # Panics
+    /// Apply the fixes which do not conflict with each other.
+    /// A fix conflicts with a previously applied fix when any of their edits overlap or touch.
     pub fn fix(mut self) -> FixResult<'a> {
         let source_text = self.source_text;
-        if self.messages.iter().all(|m| m.fix.is_none()) {
+        if self.messages.iter().all(|m| m.fix_to_apply().is_none()) {
             return FixResult {
                 fixed: false,
                 fixed_code: Cow::Borrowed(source_text),
                 messages: self.messages,
+                applied_edits: vec![],
             };
         }
 
-        self.messages.sort_by_key(|m| m.fix.as_ref().unwrap_or(&Fix::default()).span);
-        let mut fixed = false;
-        let mut output = String::with_capacity(source_text.len());
-        let mut last_pos: i64 = -1;
-        self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
-            let start = span.start;
-            let end = span.end;
-            if start > end {
-                return;
+        self.messages.sort_by_key(|m| m.fix_to_apply().map_or(Span::default(), Fix::span));
+        // Spans of the applied edits, sorted and disjoint
+        let mut applied: Vec<Span> = vec![];
+        for m in &mut self.messages {
+            let Some(fix) = m.fix_to_apply() else { continue };
+            if !fix.is_valid() || fix.edits().iter().any(|edit| conflicts(&applied, edit.span)) {
+                continue;
             }
-            if i64::from(start) <= last_pos {
-                return;
+            for edit in fix.edits() {
+                let index = applied.partition_point(|span| span.start < edit.span.start);
+                applied.insert(index, edit.span);
             }
-
             m.fixed = true;
-            fixed = true;
-            let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
-            output.push_str(&source_text[offset..start as usize]);
-            output.push_str(content);
-            last_pos = i64::from(end);
-        });
+        }
 
-        let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
-        output.push_str(&source_text[offset..]);
+        let mut edits = self
+            .messages
+            .iter()
+            .filter(|m| m.fixed)
+            .filter_map(Message::fix_to_apply)
+            .flat_map(Fix::edits)
+            .collect::<Vec<_>>();
+        edits.sort_by_key(|edit| edit.span);
 
+        let mut output = String::with_capacity(source_text.len());
+        let mut last_pos = 0;
+        for edit in &edits {
+            output.push_str(&source_text[last_pos..edit.span.start as usize]);
+            output.push_str(&edit.content);
+            last_pos = edit.span.end as usize;
+        }
+        output.push_str(&source_text[last_pos..]);
+
+        let fixed = !edits.is_empty();
+        let applied_edits = edits.iter().map(|edit| (edit.span, edit.content.len())).collect();
         let mut messages = self.messages.into_iter().filter(|m| !m.fixed).collect::<Vec<_>>();
         messages.sort_by_key(|m| (m.start, m.end));
-        return FixResult { fixed, fixed_code: Cow::Owned(output), messages };
+        return FixResult { fixed, fixed_code: Cow::Owned(output), messages, applied_edits };
     }
 }
 
+/// The code fixed by [fix_in_passes] and the problems left in it
+#[derive(Debug)]
+pub struct FixedCode {
+    pub code: String,
+    /// With a [FixOscillationDiagnostic] when the fixes oscillate
+    pub errors: Vec<Error>,
+}
+
+/// Lint `source_text` with `lint` and apply the fixes in passes until no more fixes apply
+/// or [MAX_FIX_PASSES] is reached, like `--fix`.
+///
+/// Fixes are applied to the code produced by the previous pass, so fixes which overlap
+/// are applied in later passes. Fixes producing an output seen before are reported as oscillating.
+/// `on_fix` is called with the code before and after each pass and the edits it applied.
+pub fn fix_in_passes<L, F>(source_text: &str, mut lint: L, mut on_fix: F) -> FixedCode
+where
+    L: for<'a> FnMut(&'a Allocator, &'a str) -> Vec<Message<'a>>,
+    F: FnMut(&str, &str, &[(Span, usize)]),
+{
+    let mut code = source_text.to_string();
+    let mut outputs = vec![];
+    let mut oscillating = false;
+    let errors = loop {
+        let allocator = Allocator::default();
+        let messages = lint(&allocator, &code);
+        if oscillating {
+            // The fixes left are the ones which would be applied again, the last one is reported
+            let span = messages
+                .iter()
+                .filter_map(Message::fix_to_apply)
+                .map(Fix::span)
+                .max_by_key(|span| span.start)
+                .unwrap_or_default();
+            let mut errors = messages.into_iter().map(|m| m.error).collect::<Vec<_>>();
+            errors.push(FixOscillationDiagnostic(outputs.len(), span).into());
+            break errors;
+        }
+        if outputs.len() == MAX_FIX_PASSES {
+            break messages.into_iter().map(|m| m.error).collect();
+        }
+        let fix_result = Fixer::new(&code, messages).fix();
+        if !fix_result.fixed || fix_result.fixed_code == code {
+            break fix_result.messages.into_iter().map(|m| m.error).collect();
+        }
+        on_fix(&code, &fix_result.fixed_code, &fix_result.applied_edits);
+        let fixed_code = fix_result.fixed_code.into_owned();
+        oscillating = outputs.contains(&fixed_code);
+        outputs.push(std::mem::replace(&mut code, fixed_code));
+    };
+    FixedCode { code, errors }
+}
+
+/// Whether `span` overlaps or touches any of the sorted and disjoint `applied` spans
+fn conflicts(applied: &[Span], span: Span) -> bool {
+    let index = applied.partition_point(|applied| applied.end < span.start);
+    applied.get(index).is_some_and(|applied| applied.start <= span.end)
+}
+
 #[cfg(test)]
 mod test {
     use std::borrow::Cow;
@@ -123,58 +321,59 @@ mod test {
     };
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{fix_in_passes, Edit, Fix, FixResult, Fixer, Message, MAX_FIX_PASSES};
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("End")]
     struct InsertAtEnd;
-    const INSERT_AT_END: Fix = Fix { span: Span::new(19, 19), content: Cow::Borrowed("// end") };
+    const INSERT_AT_END: Edit = Edit { span: Span::new(19, 19), content: Cow::Borrowed("// end") };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Start")]
     struct InsertAtStart;
-    const INSERT_AT_START: Fix = Fix { span: Span::new(0, 0), content: Cow::Borrowed("// start") };
+    const INSERT_AT_START: Edit =
+        Edit { span: Span::new(0, 0), content: Cow::Borrowed("// start") };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("Multiply")]
     struct InsertAtMiddle;
-    const INSERT_AT_MIDDLE: Fix = Fix { span: Span::new(13, 13), content: Cow::Borrowed("5 *") };
+    const INSERT_AT_MIDDLE: Edit = Edit { span: Span::new(13, 13), content: Cow::Borrowed("5 *") };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("foo")]
     struct ReplaceId;
-    const REPLACE_ID: Fix = Fix { span: Span::new(4, 10), content: Cow::Borrowed("foo") };
+    const REPLACE_ID: Edit = Edit { span: Span::new(4, 10), content: Cow::Borrowed("foo") };
     #[derive(Debug, Error, Diagnostic)]
     #[error("let")]
     struct ReplaceVar;
-    const REPLACE_VAR: Fix = Fix { span: Span::new(0, 3), content: Cow::Borrowed("let") };
+    const REPLACE_VAR: Edit = Edit { span: Span::new(0, 3), content: Cow::Borrowed("let") };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("5")]
     struct ReplaceNum;
-    const REPLACE_NUM: Fix = Fix { span: Span::new(13, 14), content: Cow::Borrowed("5") };
+    const REPLACE_NUM: Edit = Edit { span: Span::new(13, 14), content: Cow::Borrowed("5") };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("removestart")]
     struct RemoveStart;
-    const REMOVE_START: Fix = Fix::delete(Span::new(0, 4));
+    const REMOVE_START: Edit = Edit::delete(Span::new(0, 4));
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("removemiddle")]
     struct RemoveMiddle(#[label] pub Span);
-    const REMOVE_MIDDLE: Fix = Fix::delete(Span::new(5, 10));
+    const REMOVE_MIDDLE: Edit = Edit::delete(Span::new(5, 10));
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("removeend")]
     struct RemoveEnd;
-    const REMOVE_END: Fix = Fix::delete(Span::new(14, 18));
+    const REMOVE_END: Edit = Edit::delete(Span::new(14, 18));
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("reversed range")]
     struct ReverseRange;
-    const REVERSE_RANGE: Fix = Fix { span: Span::new(3, 0), content: Cow::Borrowed(" ") };
+    const REVERSE_RANGE: Edit = Edit { span: Span::new(3, 0), content: Cow::Borrowed(" ") };
 
     #[derive(Debug, Error, Diagnostic)]
     #[error("nofix")]
@@ -192,8 +391,8 @@ mod test {
         Fixer::new(TEST_CODE, messages).fix()
     }
 
-    fn create_message<T: Into<Error>>(error: T, fix: Option<Fix>) -> Message {
-        Message::new(error.into(), fix)
+    fn create_message<T: Into<Error>>(error: T, edit: Option<Edit>) -> Message {
+        Message::new(error.into(), edit.map(Fix::from))
     }
 
     #[test]
@@ -395,4 +594,96 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn apply_all_edits_of_a_fix() {
+        let fix = Fix::from_edits(vec![INSERT_AT_END, REPLACE_VAR, REPLACE_ID]);
+        let result = get_fix_result(vec![Message::new(ReplaceVar.into(), Some(fix))]);
+        assert_eq!(result.fixed_code, "let foo = 6 * 7;// end");
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.fixed);
+    }
+
+    #[test]
+    fn apply_no_edit_of_a_fix_when_one_edit_conflicts() {
+        // `REPLACE_ID` touches `REMOVE_START`, `REMOVE_END` alone would be fine
+        let fix = Fix::from_edits(vec![REPLACE_ID, REMOVE_END]);
+        let result = get_fix_result(vec![
+            create_message(RemoveStart, Some(REMOVE_START)),
+            Message::new(ReplaceId.into(), Some(fix)),
+            create_message(ReplaceNum, Some(REPLACE_NUM)),
+        ]);
+        assert_eq!(result.fixed_code, "answer = 5 * 7;");
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].error.to_string(), "foo");
+        assert!(result.fixed);
+    }
+
+    #[test]
+    fn ignore_fix_with_overlapping_edits() {
+        let fix = Fix::from_edits(vec![REMOVE_MIDDLE, REPLACE_ID]);
+        let result = get_fix_result(vec![Message::new(ReplaceId.into(), Some(fix))]);
+        assert_eq!(result.fixed_code, TEST_CODE);
+        assert_eq!(result.messages.len(), 1);
+        assert!(!result.fixed);
+    }
+
+    #[test]
+    fn fix_in_passes_until_nothing_is_fixed() {
+        let mut passes = vec![];
+        // Each pass replaces the first `a`, the fixes of one pass would overlap
+        let fixed = fix_in_passes(
+            "aaa",
+            |_, code| {
+                code.find('a')
+                    .map(|start| {
+                        #[allow(clippy::cast_possible_truncation)]
+                        let span = Span::new(start as u32, start as u32 + 1);
+                        create_message(ReplaceVar, Some(Edit::new("b", span)))
+                    })
+                    .into_iter()
+                    .collect()
+            },
+            |code, fixed_code, _| passes.push((code.to_string(), fixed_code.to_string())),
+        );
+        assert_eq!(fixed.code, "bbb");
+        assert!(fixed.errors.is_empty());
+        assert_eq!(
+            passes,
+            [("aaa", "baa"), ("baa", "bba"), ("bba", "bbb")].map(|(a, b)| (a.into(), b.into()))
+        );
+    }
+
+    #[test]
+    fn fix_in_passes_reports_oscillation() {
+        // `a` is fixed to `b` and `b` to `a`
+        let mut passes = 0;
+        let fixed = fix_in_passes(
+            "a",
+            |_, code| {
+                let content = if code == "a" { "b" } else { "a" };
+                vec![create_message(ReplaceVar, Some(Edit::new(content, Span::new(0, 1))))]
+            },
+            |_, _, _| passes += 1,
+        );
+        assert_eq!(passes, 2);
+        assert_eq!(fixed.code, "a");
+        assert_eq!(fixed.errors.len(), 2);
+        assert_eq!(fixed.errors[0].to_string(), "let");
+        assert!(fixed.errors[1].to_string().starts_with("Stopped fixing after 2 passes"));
+    }
+
+    #[test]
+    fn fix_in_passes_stops_after_max_passes() {
+        let mut passes = 0;
+        // Always fixable, never the same output
+        let fixed = fix_in_passes(
+            "",
+            |_, _| vec![create_message(InsertAtStart, Some(Edit::new("a", Span::new(0, 0))))],
+            |_, _, _| passes += 1,
+        );
+        assert_eq!(passes, MAX_FIX_PASSES);
+        assert_eq!(fixed.code, "a".repeat(MAX_FIX_PASSES));
+        assert_eq!(fixed.errors.len(), 1);
+    }
 }
@@ -5,6 +5,8 @@
 mod tester;
 
 mod ast_util;
+mod baseline;
+mod cache;
 mod config;
 mod context;
 mod disable_directives;
@@ -16,24 +18,41 @@ pub mod partial_loader;
 pub mod rule;
 mod rules;
 mod service;
+mod timing;
 mod utils;
 
-use rustc_hash::FxHashMap;
-use std::{io::Write, rc::Rc, sync::Arc};
+use dashmap::DashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::{Arc, OnceLock},
+};
 
 use oxc_diagnostics::Report;
+use schemars::gen::SchemaSettings;
+use serde::Serialize;
+use serde_json::json;
 
-use crate::{
-    config::{ESLintEnv, ESLintSettings},
-    fixer::Fix,
-    fixer::{Fixer, Message},
-    rule::RuleCategory,
-    rules::{RuleEnum, RULES},
-};
 pub use crate::{
+    baseline::Baseline,
+    cache::LintCache,
+    config::{ConfigIgnorePatterns, Oxlintrc, CONFIG_FILE_NAMES},
     context::LintContext,
+    fixer::{fix_in_passes, Edit, Fix, FixKind, FixedCode, Message},
     options::{AllowWarnDeny, LintOptions},
     service::{LintService, LintServiceOptions},
+    timing::{FileTiming, RuleTiming, Timings},
+};
+use crate::{
+    config::{
+        eslint_rule_key, parse_rule_key, ESLintConfig, ESLintEnv, ESLintGlobals,
+        ESLintIgnorePatterns, ESLintOverrides, ESLintSettings, GlobalValue,
+    },
+    rule::{RuleCategory, RuleWithSeverity},
+    rules::{RuleEnum, RULES},
+    timing::{RuleMethod, RuleTimer},
 };
 use oxc_semantic::AstNode;
 
@@ -48,12 +67,27 @@ fn size_asserts() {
     assert_eq_size!(RuleEnum, [u8; 16]);
 }
 
+type NamedRules = Vec<(/* rule name */ &'static str, RuleWithSeverity)>;
+
 #[derive(Debug)]
 pub struct Linter {
-    rules: Vec<(/* rule name */ &'static str, RuleEnum)>,
+    rules: NamedRules,
     options: LintOptions,
     settings: Arc<ESLintSettings>,
     env: Arc<ESLintEnv>,
+    globals: Arc<ESLintGlobals>,
+    overrides: ESLintOverrides,
+    /// Rules derived for each combination of matched `overrides` blocks, keyed by their indices
+    override_rules: DashMap<Vec<usize>, Arc<NamedRules>>,
+    ignore_patterns: ESLintIgnorePatterns,
+    /// The configuration files the linter is built from, see [Self::config_hash]
+    config_sources: Vec<serde_json::Value>,
+    /// See [Self::config_files]
+    config_files: Vec<PathBuf>,
+    /// See [Self::config_hash]
+    config_hash: OnceLock<u64>,
+    /// Shared with the linters derived by [Self::with_config]
+    timings: Arc<Timings>,
 }
 
 impl Default for Linter {
@@ -67,26 +101,62 @@ impl Linter {
     ///
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Report> {
-        let (rules, settings, env) = options.derive_rules_and_settings_and_env()?;
+        let config =
+            options.config_path.as_ref().map(|path| ESLintConfig::from_file(path)).transpose()?;
+        Ok(Self::from_config(options, config.unwrap_or_default()))
+    }
+
+    /// A linter with the same options, but with the rules and properties from `config`
+    /// instead of the configuration file from `config_path`.
+    pub(crate) fn with_config(&self, config: ESLintConfig) -> Self {
+        let linter = Self::from_config(self.options.clone(), config);
+        Self { timings: Arc::clone(&self.timings), ..linter }
+    }
+
+    fn from_config(options: LintOptions, config: ESLintConfig) -> Self {
+        let options = options.with_config_plugins(config.plugins);
+        let rules = options.derive_rules(&config);
+        let ESLintConfig {
+            settings, env, globals, overrides, ignore_patterns, sources, files, ..
+        } = config;
         let rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
-        Ok(Self { rules, options, settings: Arc::new(settings), env: Arc::new(env) })
+        Self {
+            rules,
+            options,
+            settings: Arc::new(settings),
+            env: Arc::new(env),
+            globals: Arc::new(globals),
+            overrides,
+            override_rules: DashMap::default(),
+            ignore_patterns,
+            config_sources: sources,
+            config_files: files,
+            config_hash: OnceLock::new(),
+            timings: Arc::default(),
+        }
     }
 
     #[must_use]
     pub fn with_rules(mut self, rules: Vec<RuleEnum>) -> Self {
-        self.rules = rules.into_iter().map(|rule| (rule.name(), rule)).collect();
+        self.rules = rules
+            .into_iter()
+            .map(|rule| (rule.name(), RuleWithSeverity::new(rule, None)))
+            .collect();
+        self.config_hash = OnceLock::new();
         self
     }
 
     #[must_use]
     pub fn with_settings(mut self, settings: ESLintSettings) -> Self {
         self.settings = Arc::new(settings);
+        self.config_hash = OnceLock::new();
         self
     }
 
     #[must_use]
     pub fn with_envs(mut self, env: ESLintEnv) -> Self {
         self.env = Arc::new(env);
+        self.config_hash = OnceLock::new();
         self
     }
 
@@ -94,43 +164,130 @@ impl Linter {
         &self.options
     }
 
+    /// Whether `path` is matched by `ignorePatterns` of the configuration file
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.ignore_patterns.is_ignored(path)
+    }
+
+    /// Canonical paths of the configuration files the linter is built from,
+    /// including the ones they extend
+    pub fn config_files(&self) -> &[PathBuf] {
+        &self.config_files
+    }
+
+    /// Hash of everything which affects the diagnostics of a file besides its content:
+    /// the configuration files, the options and the enabled rules with their severities.
+    /// It is stable across runs, see [LintCache].
+    pub(crate) fn config_hash(&self) -> u64 {
+        *self.config_hash.get_or_init(|| {
+            let Self { rules, options, config_sources, .. } = self;
+            let rules = rules
+                .iter()
+                .map(|(name, rule)| json!([rule.plugin_name(), name, rule.severity]))
+                .collect::<Vec<_>>();
+            let mut env = options.env.iter().collect::<Vec<_>>();
+            env.sort_unstable();
+            let options = json!({
+                "filter": options.filter,
+                "fix": options.fix.bits(),
+                "reportFixes": options.report_fixes,
+                "reportUnusedDirectives": options.report_unused_directives,
+                "plugins": [
+                    options.import_plugin,
+                    options.jest_plugin,
+                    options.jsx_a11y_plugin,
+                    options.nextjs_plugin,
+                    options.react_perf_plugin,
+                ],
+                "env": env,
+            });
+            let config = json!({ "configs": config_sources, "options": options, "rules": rules });
+            cache::stable_hash(&serde_json::to_vec(&config).unwrap())
+        })
+    }
+
+    /// Time spent in each rule and in parsing and building semantic for each file,
+    /// only measured when `LintOptions::timing` is enabled
+    pub fn timings(&self) -> &Timings {
+        &self.timings
+    }
+
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
     }
 
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.options.fix = yes;
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.options.fix = kind;
+        self.config_hash = OnceLock::new();
         self
     }
 
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
-        let mut ctx =
-            ctx.with_fix(self.options.fix).with_settings(&self.settings).with_env(&self.env);
+        let override_rules = self.rules_for_path(ctx.file_path());
+        let rules = override_rules.as_deref().unwrap_or(&self.rules);
+        let mut ctx = ctx
+            .with_fix(self.options.fix)
+            .with_report_fixes(self.options.report_fixes)
+            .with_settings(&self.settings)
+            .with_env(&self.env)
+            .with_globals(&self.globals);
+        let mut timer = RuleTimer::new(self.options.timing, rules.len());
 
-        for (rule_name, rule) in &self.rules {
-            ctx.with_rule_name(rule_name);
-            rule.run_once(&ctx);
+        for (index, (rule_name, rule)) in rules.iter().enumerate() {
+            ctx.with_rule(rule.plugin_name(), rule_name, rule.severity);
+            timer.measure(index, RuleMethod::RunOnce, || rule.run_once(&ctx));
         }
 
         for symbol in semantic.symbols().iter() {
-            for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
-                rule.run_on_symbol(symbol, &ctx);
+            for (index, (rule_name, rule)) in rules.iter().enumerate() {
+                ctx.with_rule(rule.plugin_name(), rule_name, rule.severity);
+                timer.measure(index, RuleMethod::RunOnSymbol, || rule.run_on_symbol(symbol, &ctx));
             }
         }
 
         for node in semantic.nodes().iter() {
-            for (rule_name, rule) in &self.rules {
-                ctx.with_rule_name(rule_name);
-                rule.run(node, &ctx);
+            for (index, (rule_name, rule)) in rules.iter().enumerate() {
+                ctx.with_rule(rule.plugin_name(), rule_name, rule.severity);
+                timer.measure(index, RuleMethod::Run, || rule.run(node, &ctx));
             }
         }
 
+        self.timings.add_rules(rules.iter().map(|(rule_name, _)| *rule_name), timer);
+
+        if self.options.report_unused_directives {
+            ctx.report_unused_disable_directives();
+        }
+
         ctx.into_message()
     }
 
+    /// Rules for a file matched by `overrides` in the configuration file,
+    /// `None` if no override applies and the base rules should be used.
+    fn rules_for_path(&self, path: &Path) -> Option<Arc<NamedRules>> {
+        let indices = self.overrides.matched_indices(path);
+        if indices.is_empty() {
+            return None;
+        }
+        if let Some(rules) = self.override_rules.get(&indices) {
+            return Some(Arc::clone(&rules));
+        }
+        let rules = self.override_rules.entry(indices.clone()).or_insert_with(|| {
+            let all_rules = self.options.get_filtered_rules();
+            let mut rules =
+                self.rules.iter().map(|(_, rule)| rule.clone()).collect::<FxHashSet<_>>();
+            for index in indices {
+                self.overrides[index].rules.override_rules(&mut rules, &all_rules);
+            }
+            let mut rules = rules.into_iter().collect::<Vec<_>>();
+            // for stable diagnostics output ordering
+            rules.sort_unstable_by_key(|rule| rule.name());
+            Arc::new(rules.into_iter().map(|rule| (rule.name(), rule)).collect())
+        });
+        Some(Arc::clone(&rules))
+    }
+
     pub fn print_rules<W: Write>(writer: &mut W) {
         let rules_by_category = RULES.iter().fold(
             FxHashMap::default(),
@@ -150,11 +307,149 @@ impl Linter {
         }
         writeln!(writer, "Total: {}", RULES.len()).unwrap();
     }
+
+    /// Print the metadata and documentation of all rules as a JSON array, sorted by plugin and rule name
+    ///
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn print_rules_json<W: Write>(writer: &mut W) {
+        let mut rules = RULES.iter().collect::<Vec<_>>();
+        rules.sort_unstable_by_key(|rule| (rule.plugin_name(), rule.name()));
+        let rules = rules.into_iter().map(RuleInfo::new).collect::<Vec<_>>();
+        serde_json::to_writer_pretty(&mut *writer, &rules).unwrap();
+        writeln!(writer).unwrap();
+    }
+
+    /// Print the documentation and the options schema of the rules named `name`,
+    /// which is `plugin/rule` as in configuration files, or the rule name of any plugin.
+    /// Returns `false` if there is no such rule.
+    ///
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    pub fn print_rule_documentation<W: Write>(writer: &mut W, name: &str) -> bool {
+        let (plugin_name, rule_name) = if name.contains('/') {
+            let (plugin_name, rule_name) = parse_rule_key(name);
+            (Some(plugin_name), rule_name)
+        } else {
+            (None, name.to_string())
+        };
+        let rules = RULES
+            .iter()
+            .filter(|rule| rule.name() == rule_name)
+            .filter(|rule| plugin_name.as_ref().map_or(true, |name| rule.plugin_name() == name))
+            .collect::<Vec<_>>();
+
+        for (i, rule) in rules.iter().enumerate() {
+            if i > 0 {
+                writeln!(writer).unwrap();
+            }
+            let fix = rule.fix();
+            let fix = if fix.contains(FixKind::Dangerous) {
+                "fixable with --fix-dangerously"
+            } else if fix.contains(FixKind::Fix) {
+                "fixable with --fix"
+            } else if fix.contains(FixKind::Suggestion) {
+                "suggestions with --fix-suggestions"
+            } else {
+                "not fixable"
+            };
+            writeln!(writer, "{}", eslint_rule_key(rule)).unwrap();
+            writeln!(writer, "Category: {}, {fix}", rule.category()).unwrap();
+            writeln!(writer).unwrap();
+            for line in Self::render_documentation(rule.documentation().unwrap_or_default()) {
+                writeln!(writer, "{line}").unwrap();
+            }
+            // The same schemas as the rules of the configuration file schema, with the defaults
+            let mut gen =
+                SchemaSettings::draft07().with(|s| s.inline_subschemas = true).into_generator();
+            let options = rule.options_schema(&mut gen);
+            writeln!(writer).unwrap();
+            if options.is_empty() {
+                writeln!(writer, "This rule has no options.").unwrap();
+            } else {
+                writeln!(writer, "Options, following the severity in `[severity, ...options]`:")
+                    .unwrap();
+                for schema in options {
+                    writeln!(writer, "{}", serde_json::to_string_pretty(&schema).unwrap()).unwrap();
+                }
+            }
+        }
+        !rules.is_empty()
+    }
+
+    /// The markdown documentation of a rule for the terminal,
+    /// without the heading markers and code fences, and with the code indented.
+    fn render_documentation(documentation: &str) -> Vec<String> {
+        let mut in_code_block = false;
+        documentation
+            .lines()
+            .filter_map(|line| {
+                if line.trim_start().starts_with("```") {
+                    in_code_block = !in_code_block;
+                    return None;
+                }
+                Some(if in_code_block {
+                    format!("    {line}")
+                } else {
+                    line.trim_start_matches('#').trim_start().to_string()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Metadata of a rule in the output of [Linter::print_rules_json]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleInfo<'a> {
+    name: &'a str,
+    plugin: &'a str,
+    /// The rule name in ESLint configuration files, e.g. `@typescript-eslint/no-explicit-any`
+    config_key: String,
+    category: RuleCategory,
+    /// Applied by `--fix`
+    fixable: bool,
+    /// Applied by `--fix-dangerously` only
+    dangerous_fix: bool,
+    /// Applied by `--fix-suggestions`
+    has_suggestions: bool,
+    /// Markdown
+    docs: &'a str,
+}
+
+impl<'a> RuleInfo<'a> {
+    fn new(rule: &'a RuleEnum) -> Self {
+        let fix = rule.fix();
+        Self {
+            name: rule.name(),
+            plugin: rule.plugin_name(),
+            config_key: eslint_rule_key(rule),
+            category: rule.category(),
+            fixable: fix.contains(FixKind::Fix) && !fix.contains(FixKind::Dangerous),
+            dangerous_fix: fix.contains(FixKind::Dangerous),
+            has_suggestions: fix.contains(FixKind::Suggestion),
+            docs: rule.documentation().unwrap_or_default(),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::Linter;
+    use super::{AllowWarnDeny, LintOptions, Linter};
+
+    #[test]
+    fn config_hash() {
+        let options =
+            || LintOptions::default().with_config_path(Some("fixtures/eslint_config.json".into()));
+        let linter = Linter::from_options(options()).unwrap();
+        assert_eq!(linter.config_hash(), Linter::from_options(options()).unwrap().config_hash());
+        let filter = vec![(AllowWarnDeny::Deny, "correctness".to_string())];
+        let denied = Linter::from_options(options().with_filter(filter)).unwrap();
+        assert_ne!(linter.config_hash(), denied.config_hash());
+        assert_ne!(linter.config_hash(), Linter::default().config_hash());
+    }
 
     #[test]
     fn print_rules() {
@@ -162,4 +457,40 @@ mod test {
         Linter::print_rules(&mut writer);
         assert!(!writer.is_empty());
     }
+
+    #[test]
+    fn print_rules_json() {
+        let mut writer = Vec::new();
+        Linter::print_rules_json(&mut writer);
+        let rules: Vec<serde_json::Value> = serde_json::from_slice(&writer).unwrap();
+        let no_debugger = rules.iter().find(|rule| rule["name"] == "no-debugger").unwrap();
+        assert_eq!(no_debugger["plugin"], "eslint");
+        assert_eq!(no_debugger["configKey"], "no-debugger");
+        assert_eq!(no_debugger["category"], "correctness");
+        assert_eq!(no_debugger["fixable"], true);
+        let no_prototype_builtins =
+            rules.iter().find(|rule| rule["name"] == "no-prototype-builtins").unwrap();
+        assert_eq!(no_prototype_builtins["fixable"], false);
+        assert_eq!(no_prototype_builtins["hasSuggestions"], true);
+    }
+
+    #[test]
+    fn print_rule_documentation() {
+        let mut writer = Vec::new();
+        assert!(Linter::print_rule_documentation(
+            &mut writer,
+            "@typescript-eslint/no-explicit-any"
+        ));
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.starts_with("@typescript-eslint/no-explicit-any\n"));
+        assert!(output.contains("Options, following the severity in `[severity, ...options]`:"));
+        assert!(output.contains("\"fixToUnknown\": {"));
+        assert!(!output.contains("```"));
+
+        let mut writer = Vec::new();
+        assert!(Linter::print_rule_documentation(&mut writer, "no-debugger"));
+        assert!(String::from_utf8(writer).unwrap().contains("This rule has no options."));
+
+        assert!(!Linter::print_rule_documentation(&mut Vec::new(), "jest/no-debugger"));
+    }
 }
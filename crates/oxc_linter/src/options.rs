@@ -6,23 +6,36 @@ use crate::{
             FailedToParseAllowWarnDenyFromJsonValueError,
             FailedToParseAllowWarnDenyFromNumberError, FailedToParseAllowWarnDenyFromStringError,
         },
-        ESLintConfig,
+        ESLintConfig, OxlintPlugins,
     },
+    rule::RuleWithSeverity,
     rules::RULES,
-    ESLintEnv, ESLintSettings, RuleCategory, RuleEnum,
+    ESLintEnv, FixKind, RuleCategory, RuleEnum,
 };
 use oxc_diagnostics::Error;
 use rustc_hash::FxHashSet;
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{Schema, SchemaObject},
+    JsonSchema,
+};
+use serde::{de, Deserialize, Deserializer, Serialize};
 use serde_json::{Number, Value};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LintOptions {
-    /// Allow / Deny rules in order. [("allow" / "deny", rule name)]
-    /// Defaults to [("deny", "correctness")]
+    /// Allow / Warn / Deny rules in order. [("allow" / "warn" / "deny", rule name)]
+    /// Defaults to [("warn", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
-    pub fix: bool,
+    /// Kinds of fixes to compute and apply, nothing is fixed when empty
+    pub fix: FixKind,
+    /// Compute the fixes of `--fix` to report them, e.g. as `fix` of the JSON output,
+    /// without applying them
+    pub report_fixes: bool,
     pub timing: bool,
+    /// Report `eslint-disable` directives which did not suppress any diagnostic
+    pub report_unused_directives: bool,
     pub import_plugin: bool,
     pub jest_plugin: bool,
     pub jsx_a11y_plugin: bool,
@@ -34,10 +47,12 @@ pub struct LintOptions {
 impl Default for LintOptions {
     fn default() -> Self {
         Self {
-            filter: vec![(AllowWarnDeny::Deny, String::from("correctness"))],
+            filter: vec![(AllowWarnDeny::Warn, String::from("correctness"))],
             config_path: None,
-            fix: false,
+            fix: FixKind::empty(),
+            report_fixes: false,
             timing: false,
+            report_unused_directives: false,
             import_plugin: false,
             jest_plugin: false,
             jsx_a11y_plugin: false,
@@ -64,8 +79,14 @@ impl LintOptions {
     }
 
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.fix = yes;
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.fix = kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
         self
     }
 
@@ -75,6 +96,12 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_import_plugin(mut self, yes: bool) -> Self {
         self.import_plugin = yes;
@@ -112,11 +139,17 @@ impl LintOptions {
     }
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// Severity of a rule or category
+// Deserialized from the oxlint configuration as `"off" | "warn" | "error"` or `0 | 1 | 2`,
+// ESLint severities are converted with `TryFrom`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
 pub enum AllowWarnDeny {
+    #[serde(rename = "off")]
     Allow, // Off
-    Warn,  // Warn
-    Deny,  // Error
+    #[serde(rename = "warn")]
+    Warn, // Warn
+    #[serde(rename = "error")]
+    Deny, // Error
 }
 
 impl AllowWarnDeny {
@@ -129,6 +162,48 @@ impl AllowWarnDeny {
     }
 }
 
+impl<'de> Deserialize<'de> for AllowWarnDeny {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::String(s) => match s.as_str() {
+                "off" => Ok(Self::Allow),
+                "warn" => Ok(Self::Warn),
+                "error" => Ok(Self::Deny),
+                _ => Err(de::Error::unknown_variant(&s, &["off", "warn", "error"])),
+            },
+            Value::Number(n) => Self::try_from(&n).map_err(de::Error::custom),
+            value => Err(de::Error::custom(FailedToParseAllowWarnDenyFromJsonValueError(
+                value.to_string(),
+            ))),
+        }
+    }
+}
+
+impl JsonSchema for AllowWarnDeny {
+    fn schema_name() -> String {
+        "AllowWarnDeny".to_string()
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        let mut schema = SchemaObject {
+            enum_values: Some(vec![
+                "off".into(),
+                "warn".into(),
+                "error".into(),
+                0.into(),
+                1.into(),
+                2.into(),
+            ]),
+            ..SchemaObject::default()
+        };
+        schema.metadata().description = Some("Severity of a rule or category".to_string());
+        schema.into()
+    }
+}
+
 impl TryFrom<&str> for AllowWarnDeny {
     type Error = Error;
 
@@ -173,71 +248,71 @@ const NEXTJS_PLUGIN_NAME: &str = "nextjs";
 const REACT_PERF_PLUGIN_NAME: &str = "react_perf";
 
 impl LintOptions {
-    /// # Errors
-    ///
-    /// * Returns `Err` if there are any errors parsing the configuration file.
-    pub fn derive_rules_and_settings_and_env(
-        &self,
-    ) -> Result<(Vec<RuleEnum>, ESLintSettings, ESLintEnv), Error> {
-        let config =
-            self.config_path.as_ref().map(|path| ESLintConfig::from_file(path)).transpose()?;
-
-        let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
+    /// Enable the plugins turned on by `plugins` of the configuration file
+    #[must_use]
+    pub(crate) fn with_config_plugins(mut self, plugins: OxlintPlugins) -> Self {
+        self.import_plugin |= plugins.import;
+        self.jest_plugin |= plugins.jest;
+        self.jsx_a11y_plugin |= plugins.jsx_a11y;
+        self.nextjs_plugin |= plugins.nextjs;
+        self.react_perf_plugin |= plugins.react_perf;
+        self
+    }
+
+    /// Rules enabled by `filter`, then by `categories` and `rules` of the configuration file.
+    /// Rule names in `filter` take precedence over the configuration file.
+    pub(crate) fn derive_rules(&self, config: &ESLintConfig) -> Vec<RuleWithSeverity> {
+        let mut rules: FxHashSet<RuleWithSeverity> = FxHashSet::default();
         let all_rules = self.get_filtered_rules();
 
         for (allow_warn_deny, name_or_category) in &self.filter {
-            let maybe_category = RuleCategory::from(name_or_category.as_str());
-            match allow_warn_deny {
-                AllowWarnDeny::Deny | AllowWarnDeny::Warn => {
-                    match maybe_category {
-                        Some(category) => rules.extend(
-                            all_rules.iter().filter(|rule| rule.category() == category).cloned(),
-                        ),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.extend(all_rules.iter().cloned());
-                            } else {
-                                rules.extend(
-                                    all_rules
-                                        .iter()
-                                        .filter(|rule| rule.name() == name_or_category)
-                                        .cloned(),
-                                );
-                            }
-                        }
-                    };
-                }
-                AllowWarnDeny::Allow => {
-                    match maybe_category {
-                        Some(category) => rules.retain(|rule| rule.category() != category),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.clear();
-                            } else {
-                                rules.retain(|rule| rule.name() != name_or_category);
-                            }
-                        }
-                    };
-                }
-            }
+            Self::apply_filter(&mut rules, &all_rules, *allow_warn_deny, name_or_category);
         }
 
-        if let Some(config) = &config {
-            config.override_rules(&mut rules, &all_rules);
+        config.override_rules(&mut rules, &all_rules);
+
+        for (allow_warn_deny, name_or_category) in &self.filter {
+            if name_or_category != "all" && RuleCategory::from(name_or_category.as_str()).is_none()
+            {
+                Self::apply_filter(&mut rules, &all_rules, *allow_warn_deny, name_or_category);
+            }
         }
 
         let mut rules = rules.into_iter().collect::<Vec<_>>();
 
-        let (settings, env) = config.map(ESLintConfig::properties).unwrap_or_default();
-
         // for stable diagnostics output ordering
-        rules.sort_unstable_by_key(RuleEnum::name);
+        rules.sort_unstable_by_key(|rule| rule.name());
+
+        rules
+    }
 
-        Ok((rules, settings, env))
+    fn apply_filter(
+        rules: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+        allow_warn_deny: AllowWarnDeny,
+        name_or_category: &str,
+    ) {
+        let maybe_category = RuleCategory::from(name_or_category);
+        let matches = |rule: &RuleEnum| match maybe_category {
+            Some(category) => rule.category() == category,
+            None => name_or_category == "all" || rule.name() == name_or_category,
+        };
+        match allow_warn_deny {
+            AllowWarnDeny::Deny | AllowWarnDeny::Warn => {
+                for rule in all_rules.iter().filter(|rule| matches(rule)) {
+                    // Only the severity of an enabled rule changes, it keeps its configured options
+                    let rule = rules
+                        .take(&RuleWithSeverity::new(rule.clone(), None))
+                        .map_or_else(|| rule.clone(), |enabled| enabled.rule);
+                    rules.insert(RuleWithSeverity::new(rule, Some(allow_warn_deny)));
+                }
+            }
+            AllowWarnDeny::Allow => rules.retain(|rule| !matches(rule)),
+        }
     }
 
     // get final filtered rules by reading `self.jest_plugin` and `self.jsx_a11y_plugin`
-    fn get_filtered_rules(&self) -> Vec<RuleEnum> {
+    pub(crate) fn get_filtered_rules(&self) -> Vec<RuleEnum> {
         let mut rules = RULES.clone();
 
         let mut may_exclude_plugin_rules = |yes: bool, name: &str| {
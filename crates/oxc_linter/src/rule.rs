@@ -1,8 +1,14 @@
-use std::fmt;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
 
 use oxc_semantic::SymbolId;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::{context::LintContext, AstNode};
+use crate::{context::LintContext, AllowWarnDeny, AstNode, FixKind, RuleEnum};
 
 pub trait Rule: Sized + Default + fmt::Debug {
     /// Initialize from eslint json configuration
@@ -10,6 +16,14 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// JSON Schema of the options read by `from_configuration`,
+    /// in the order they follow the severity in `[severity, ...options]`.
+    ///
+    /// Describe the same types `from_configuration` reads with [rule_option] so they do not drift.
+    fn options_schema(_gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![]
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -20,18 +34,28 @@ pub trait Rule: Sized + Default + fmt::Debug {
     fn run_once(&self, _ctx: &LintContext) {}
 }
 
+/// The option at `index` of the options given to [Rule::from_configuration],
+/// or the default when it is missing or does not match `T`
+pub fn rule_option<T: DeserializeOwned + Default>(value: &serde_json::Value, index: usize) -> T {
+    value.get(index).and_then(|option| T::deserialize(option).ok()).unwrap_or_default()
+}
+
 pub trait RuleMeta {
     const NAME: &'static str;
 
     const CATEGORY: RuleCategory;
 
+    /// Kinds of fixes the rule can produce, declared after the category in `declare_oxc_lint!`
+    const FIX: FixKind = FixKind::empty();
+
     fn documentation() -> Option<&'static str> {
         None
     }
 }
 
 /// Rule categories defined by rust-clippy
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
 pub enum RuleCategory {
     /// Code that is outright wrong or useless
     Correctness,
@@ -82,6 +106,44 @@ impl fmt::Display for RuleCategory {
     }
 }
 
+/// An enabled rule and the severity it is configured with
+#[derive(Debug, Clone)]
+pub struct RuleWithSeverity {
+    pub rule: RuleEnum,
+    /// Overrides the severity of the diagnostics,
+    /// `None` keeps the severity declared by the rule, e.g. for rules given to `Linter::with_rules`
+    pub severity: Option<AllowWarnDeny>,
+}
+
+impl RuleWithSeverity {
+    pub fn new(rule: RuleEnum, severity: Option<AllowWarnDeny>) -> Self {
+        Self { rule, severity }
+    }
+}
+
+impl Deref for RuleWithSeverity {
+    type Target = RuleEnum;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rule
+    }
+}
+
+// A rule is only enabled once, regardless of its severity
+impl Hash for RuleWithSeverity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rule.hash(state);
+    }
+}
+
+impl PartialEq for RuleWithSeverity {
+    fn eq(&self, other: &Self) -> bool {
+        self.rule == other.rule
+    }
+}
+
+impl Eq for RuleWithSeverity {}
+
 #[cfg(test)]
 mod test {
     use crate::RULES;
@@ -11,13 +11,15 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, GetSpan, Span};
 use phf::phf_set;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 use serde_json::Value;
 
 use self::return_checker::{check_function_body, StatementReturnStatus};
 use crate::{
     ast_util::{get_enclosing_function, is_nth_argument, outermost_paren},
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     AstNode,
 };
 
@@ -38,12 +40,14 @@ enum ArrayCallbackReturnDiagnostic {
     ExpectNoReturn(CompactString, #[label] Span),
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct ArrayCallbackReturn {
     /// When set to true, rule will also report forEach callbacks that return a value.
     check_for_each: bool,
     /// When set to true, allows callbacks of methods that require a return value to
     /// implicitly return undefined with a return statement containing no expression.
+    #[serde(rename = "allowImplicit")]
     allow_implicit_return: bool,
 }
 
@@ -70,15 +74,11 @@ declare_oxc_lint!(
 
 impl Rule for ArrayCallbackReturn {
     fn from_configuration(value: Value) -> Self {
-        let (check_for_each, allow_implicit_return) =
-            value.get(0).map_or((false, false), |config| {
-                (
-                    config.get("checkForEach").and_then(Value::as_bool).unwrap_or_default(),
-                    config.get("allowImplicit").and_then(Value::as_bool).unwrap_or_default(),
-                )
-            });
+        rule_option(&value, 0)
+    }
 
-        Self { check_for_each, allow_implicit_return }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -9,8 +9,15 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
-
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(eqeqeq): Expected {1} and instead saw {0}")]
@@ -37,25 +44,18 @@ declare_oxc_lint!(
     /// a == b
     /// ```
     Eqeqeq,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for Eqeqeq {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj1 = value.get(0);
-        let obj2 = value.get(1);
-
-        Self {
-            compare_type: obj1
-                .and_then(serde_json::Value::as_str)
-                .map(CompareType::from)
-                .unwrap_or_default(),
-            null_type: obj2
-                .and_then(|v| v.get("null"))
-                .and_then(serde_json::Value::as_str)
-                .map(NullType::from)
-                .unwrap_or_default(),
-        }
+        let NullOption { null: null_type } = rule_option(&value, 1);
+        Self { compare_type: rule_option(&value, 0), null_type }
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<CompareType>(), gen.subschema_for::<NullOption>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -117,23 +117,23 @@ impl Rule for Eqeqeq {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum CompareType {
     #[default]
     Always,
     Smart,
 }
 
-impl CompareType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "smart" => Self::Smart,
-            _ => Self::Always,
-        }
-    }
+/// The second option, how `null` is compared with `"always"`
+#[derive(Default, JsonSchema, Deserialize)]
+#[serde(default)]
+struct NullOption {
+    null: NullType,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum NullType {
     #[default]
     Always,
@@ -141,16 +141,6 @@ enum NullType {
     Ignore,
 }
 
-impl NullType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "always" => Self::Always,
-            "never" => Self::Never,
-            _ => Self::Ignore,
-        }
-    }
-}
-
 fn to_strict_eq_operator_str(operator: BinaryOperator) -> (&'static str, &'static str) {
     match operator {
         BinaryOperator::Equality => ("===", " === "),
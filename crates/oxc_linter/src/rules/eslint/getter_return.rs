@@ -14,15 +14,22 @@ use oxc_semantic::{
     pg::neighbors_filtered_by_edge_weight, AssignmentValue, BasicBlockElement, EdgeType, Register,
 };
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(getter-return): Expected to always return a value in getter.")]
 #[diagnostic(severity(warning), help("Return a value from all code paths in getter."))]
 struct GetterReturnDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct GetterReturn {
     pub allow_implicit: bool,
 }
@@ -67,13 +74,11 @@ impl Rule for GetterReturn {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_implicit = value
-            .get(0)
-            .and_then(|config| config.get("allowImplicit"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { allow_implicit }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 }
 
@@ -6,8 +6,14 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::BinaryOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-bitwise): Unexpected use of {0:?}")]
@@ -20,7 +26,8 @@ struct NoBitwiseDiagnostic(&'static str, #[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct NoBitwise(Box<NoBitwiseConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoBitwiseConfig {
     allow: Vec<String>,
     int32_hint: bool,
@@ -55,24 +62,11 @@ declare_oxc_lint!(
 
 impl Rule for NoBitwise {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self(Box::new(NoBitwiseConfig {
-            allow: obj
-                .and_then(|v| v.get("allow"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                })
-                .unwrap_or_default(),
-            int32_hint: obj
-                .and_then(|v| v.get("int32Hint"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoBitwiseConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -8,8 +8,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-cond-assign): Expected a conditional expression and instead saw an assignment")]
@@ -21,7 +27,8 @@ pub struct NoCondAssign {
     config: NoCondAssignConfig,
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum NoCondAssignConfig {
     #[default]
     ExceptParens,
@@ -44,14 +51,11 @@ declare_oxc_lint!(
 
 impl Rule for NoCondAssign {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0).and_then(serde_json::Value::as_str).map_or_else(
-            NoCondAssignConfig::default,
-            |value| match value {
-                "always" => NoCondAssignConfig::Always,
-                _ => NoCondAssignConfig::ExceptParens,
-            },
-        );
-        Self { config }
+        Self { config: rule_option(&value, 0) }
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoCondAssignConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
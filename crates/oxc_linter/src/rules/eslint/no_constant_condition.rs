@@ -5,16 +5,25 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{ast_util::IsConstant, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::IsConstant,
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-constant-condition): Unexpected constant condition")]
 #[diagnostic(severity(warning), help("Constant expression as a test condition is not allowed"))]
 struct NoConstantConditionDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoConstantCondition {
+    #[serde(rename = "checkLoops")]
     _check_loops: bool,
 }
 
@@ -40,14 +49,11 @@ declare_oxc_lint!(
 
 impl Rule for NoConstantCondition {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
+        rule_option(&value, 0)
+    }
 
-        Self {
-            _check_loops: obj
-                .and_then(|v| v.get("checkLoops"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
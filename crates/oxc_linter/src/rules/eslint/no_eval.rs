@@ -8,15 +8,21 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-eval): eval can be harmful.")]
 #[diagnostic(severity(warning))]
 struct NoEvalDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoEval {
     /// Whether to allow references to the `eval` function as long as they are
     /// not called. For example, the following code is valid if this property is
@@ -54,11 +60,11 @@ declare_oxc_lint!(
 
 impl Rule for NoEval {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_indirect = value.get(0).map_or(false, |config| {
-            config.get("allowIndirect").and_then(serde_json::Value::as_bool).unwrap_or(false)
-        });
+        rule_option(&value, 0)
+    }
 
-        Self { allow_indirect }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -7,8 +7,14 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{LogicalOperator, UnaryOperator};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-extra-boolean-cast): Redundant double negation")]
@@ -26,7 +32,8 @@ struct NoExtraDoubleNegationCastDiagnostic(#[label] pub Span);
 )]
 struct NoExtraBooleanCastDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoExtraBooleanCast {
     pub enforce_for_logical_operands: bool,
 }
@@ -56,13 +63,11 @@ declare_oxc_lint!(
 
 impl Rule for NoExtraBooleanCast {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            enforce_for_logical_operands: value
-                .get(0)
-                .and_then(|x| x.get("enforceForLogicalOperands"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -4,8 +4,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    GlobalValue,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-global-assign): Read-only global '{0}' should not be modified.")]
@@ -18,8 +24,11 @@ struct NoGlobalAssignDiagnostic(
 #[derive(Debug, Default, Clone)]
 pub struct NoGlobalAssign(Box<NoGlobalAssignConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoGlobalAssignConfig {
+    #[serde(rename = "exceptions")]
+    #[schemars(with = "Vec<String>")]
     excludes: Vec<CompactString>,
 }
 
@@ -48,19 +57,11 @@ declare_oxc_lint!(
 
 impl Rule for NoGlobalAssign {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(NoGlobalAssignConfig {
-            excludes: obj
-                .and_then(|v| v.get("exceptions"))
-                .and_then(serde_json::Value::as_array)
-                .unwrap_or(&vec![])
-                .iter()
-                .map(serde_json::Value::as_str)
-                .filter(std::option::Option::is_some)
-                .map(|x| CompactString::from(x.unwrap()))
-                .collect::<Vec<CompactString>>(),
-        }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoGlobalAssignConfig>()]
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -71,7 +72,11 @@ impl Rule for NoGlobalAssign {
                 if reference.is_write() && symbol_table.is_global_reference(reference_id) {
                     let name = reference.name();
 
-                    if !self.excludes.contains(name) && ctx.env_contains_var(name) {
+                    // Globals configured as writable can be assigned
+                    if !self.excludes.contains(name)
+                        && ctx.global(name) != Some(GlobalValue::Writeable)
+                        && ctx.env_contains_var(name)
+                    {
                         ctx.diagnostic(NoGlobalAssignDiagnostic(name.clone(), reference.span()));
                     }
                 }
@@ -5,8 +5,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
@@ -20,7 +26,8 @@ pub struct NoInnerDeclarations {
     config: NoInnerDeclarationsConfig,
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, JsonSchema, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum NoInnerDeclarationsConfig {
     /// Disallows function declarations in nested blocks
     #[default]
@@ -53,14 +60,11 @@ declare_oxc_lint!(
 
 impl Rule for NoInnerDeclarations {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0).and_then(serde_json::Value::as_str).map_or_else(
-            NoInnerDeclarationsConfig::default,
-            |value| match value {
-                "functions" => NoInnerDeclarationsConfig::Functions,
-                _ => NoInnerDeclarationsConfig::Both,
-            },
-        );
-        Self { config }
+        Self { config: rule_option(&value, 0) }
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoInnerDeclarationsConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -6,8 +6,13 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{Deserialize, Deserializer};
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-mixed-operators): Unexpected mix of {0} with {1}")]
@@ -25,9 +30,11 @@ struct NoMixedOperatorsDiagnostic(
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
 pub struct NoMixedOperators(Box<NoMixedOperatorsConfig>);
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoMixedOperatorsConfig {
     /// Disallow Mixed operators within one group.
+    #[serde(deserialize_with = "deserialize_groups")]
     groups: Vec<Vec<&'static str>>,
     /// Allow operators of the same precedence to be mixed.
     allow_same_precedence: bool,
@@ -92,44 +99,15 @@ impl Rule for NoMixedOperators {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self::try_from_configuration(&value).unwrap_or_default()
+        Self(Box::new(rule_option(&value, 0)))
     }
-}
-
-impl NoMixedOperators {
-    pub fn try_from_configuration(value: &serde_json::Value) -> Option<Self> {
-        let config = value.get(0)?;
-
-        let mut groups = vec![];
-        if let Some(groups_config) = config.get("groups") {
-            if let Some(groups_config) = groups_config.as_array() {
-                'outer: for group_config in groups_config {
-                    // Parse current group configuration. On failure fall through to next group.
-                    if let Some(group_config) = group_config.as_array() {
-                        let mut group = vec![];
-                        for val in group_config {
-                            let Some(val) = val.as_str() else { continue 'outer };
-                            let Some((operator, _)) = operator_and_precedence(val) else {
-                                continue 'outer;
-                            };
-                            group.push(operator);
-                        }
-                        groups.push(group);
-                    }
-                }
-            }
-        }
 
-        if groups.is_empty() {
-            groups = default_groups();
-        }
-
-        let allow_same_precedence =
-            config.get("allowSamePrecedence").map_or(true, |val| val.as_bool().unwrap_or_default());
-
-        Some(Self(Box::new(NoMixedOperatorsConfig { groups, allow_same_precedence })))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoMixedOperatorsConfig>()]
     }
+}
 
+impl NoMixedOperators {
     fn is_mixed_with_parent(node: AstKind, parent: AstKind) -> bool {
         match (node, parent) {
             (AstKind::BinaryExpression(node), AstKind::BinaryExpression(parent)) => {
@@ -244,6 +222,22 @@ fn default_groups() -> Vec<Vec<&'static str>> {
 }
 
 #[inline]
+/// Groups with unknown operators are skipped, the default groups are used when none are left
+fn deserialize_groups<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Vec<&'static str>>, D::Error> {
+    let groups = Vec::<Vec<String>>::deserialize(deserializer)?
+        .iter()
+        .filter_map(|group| {
+            group
+                .iter()
+                .map(|operator| operator_and_precedence(operator).map(|(operator, _)| operator))
+                .collect::<Option<Vec<_>>>()
+        })
+        .collect::<Vec<_>>();
+    Ok(if groups.is_empty() { default_groups() } else { groups })
+}
+
 fn operator_and_precedence(operator: &str) -> Option<(&'static str, u8)> {
     OPERATORS.iter().position(|op| *op == operator).map(|idx| (OPERATORS[idx], PRECEDENCES[idx]))
 }
@@ -356,8 +350,8 @@ mod internal_tests {
             ],
             "allowSamePrecedence": true
         }]);
-        let rule = NoMixedOperators::try_from_configuration(&config);
-        assert_eq!(Some(NoMixedOperators::default()), rule);
+        let rule = NoMixedOperators::from_configuration(config);
+        assert_eq!(NoMixedOperators::default(), rule);
     }
 
     #[test]
@@ -365,12 +359,12 @@ mod internal_tests {
         let config = json!([
           { "allowSamePrecedence": false }
         ]);
-        let rule = NoMixedOperators::try_from_configuration(&config);
+        let rule = NoMixedOperators::from_configuration(config);
         // missing groups should fall back to default
         let expected = NoMixedOperators(Box::new(NoMixedOperatorsConfig {
             groups: default_groups(),
             allow_same_precedence: false,
         }));
-        assert_eq!(Some(expected), rule);
+        assert_eq!(expected, rule);
     }
 }
@@ -1,4 +1,7 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{CallExpression, Expression, MemberExpression},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -6,7 +9,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::{Edit, Fix, Suggestion},
+    rule::Rule,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
@@ -44,7 +52,8 @@ declare_oxc_lint!(
     /// var barIsEnumerable = foo.propertyIsEnumerable("bar");
     /// ```
     NoPrototypeBuiltins,
-    pedantic
+    pedantic,
+    suggestion
 );
 
 const DISALLOWED_PROPS: &[&str; 3] = &["hasOwnProperty", "isPrototypeOf", "propertyIsEnumerable"];
@@ -54,15 +63,65 @@ impl Rule for NoPrototypeBuiltins {
         let AstKind::CallExpression(expr) = node.kind() else { return };
         let Some(member_expr) = expr.callee.get_member_expr() else { return };
         let Some(prop_name) = member_expr.static_property_name() else { return };
-        if DISALLOWED_PROPS.contains(&prop_name) {
-            ctx.diagnostic(NoPrototypeBuiltinsDiagnostic(
-                prop_name.to_string(),
-                member_expr.span(),
-            ));
+        if !DISALLOWED_PROPS.contains(&prop_name) {
+            return;
+        }
+        let diagnostic = NoPrototypeBuiltinsDiagnostic(prop_name.to_string(), member_expr.span());
+        if !can_call_object_prototype(expr, node, ctx) {
+            ctx.diagnostic(diagnostic);
+            return;
         }
+        ctx.diagnostic_with_suggestions(diagnostic, || {
+            vec![Suggestion::new(
+                format!("Call Object.prototype.{prop_name} explicitly"),
+                call_object_prototype(expr, member_expr, prop_name, ctx),
+            )]
+        });
     }
 }
 
+/// Whether `foo.hasOwnProperty(bar)` can be rewritten to `Object.prototype.hasOwnProperty.call(foo, bar)`.
+/// Optional chains would throw instead of short-circuiting, and a shadowed `Object` is not the global one.
+fn can_call_object_prototype(expr: &CallExpression, node: &AstNode, ctx: &LintContext) -> bool {
+    let Expression::MemberExpression(member_expr) = &expr.callee else { return false };
+    let scopes = ctx.scopes();
+    !expr.optional
+        && !member_expr.optional()
+        && expr.type_parameters.is_none()
+        // Nothing but whitespace between the callee and its parenthesis
+        && ctx.source_text()[member_expr.span().end as usize..].trim_start().starts_with('(')
+        && !matches!(member_expr.object(), Expression::Super(_))
+        && !scopes.ancestors(node.scope_id()).any(|id| scopes.get_binding(id, "Object").is_some())
+}
+
+/// `foo.hasOwnProperty(bar)` to `Object.prototype.hasOwnProperty.call(foo, bar)`,
+/// keeping the arguments and the comments between them
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn call_object_prototype<'a>(
+    expr: &CallExpression<'a>,
+    member_expr: &MemberExpression<'a>,
+    prop_name: &str,
+    ctx: &LintContext<'a>,
+) -> Fix<'a> {
+    let object = member_expr.object();
+    let object_text = object.span().source_text(ctx.source_text());
+    let object_text = if matches!(object, Expression::SequenceExpression(_)) {
+        format!("({object_text})")
+    } else {
+        object_text.to_string()
+    };
+    let this_arg = if expr.arguments.is_empty() { object_text } else { format!("{object_text}, ") };
+    // Right after the opening parenthesis, so that comments stay in front of their argument
+    let callee_end = expr.callee.span().end;
+    let source = &ctx.source_text()[callee_end as usize..];
+    let open_paren = callee_end + (source.len() - source.trim_start().len()) as u32 + 1;
+    let this_arg = Edit::new(this_arg, Span::new(open_paren, open_paren));
+    Fix::from_edits(vec![
+        Edit::new(format!("Object.prototype.{prop_name}.call"), expr.callee.span()),
+        this_arg,
+    ])
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -112,5 +171,23 @@ fn test() {
         "(foo?.[`hasOwnProperty`])('bar')",
     ];
 
-    Tester::new(NoPrototypeBuiltins::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("foo.hasOwnProperty('bar')", "Object.prototype.hasOwnProperty.call(foo, 'bar')", None),
+        ("foo.bar.isPrototypeOf(baz)", "Object.prototype.isPrototypeOf.call(foo.bar, baz)", None),
+        ("foo['propertyIsEnumerable']()", "Object.prototype.propertyIsEnumerable.call(foo)", None),
+        ("foo.hasOwnProperty (bar)", "Object.prototype.hasOwnProperty.call (foo, bar)", None),
+        ("(a, b).hasOwnProperty(c)", "Object.prototype.hasOwnProperty.call((a, b), c)", None),
+        (
+            "foo.hasOwnProperty(/* bar */ bar)",
+            "Object.prototype.hasOwnProperty.call(foo, /* bar */ bar)",
+            None,
+        ),
+        // No suggestion
+        ("foo?.hasOwnProperty('bar')", "foo?.hasOwnProperty('bar')", None),
+        ("foo.hasOwnProperty?.('bar')", "foo.hasOwnProperty?.('bar')", None),
+        ("foo.hasOwnProperty /* ( */ (bar)", "foo.hasOwnProperty /* ( */ (bar)", None),
+        ("let Object; foo.hasOwnProperty('bar')", "let Object; foo.hasOwnProperty('bar')", None),
+    ];
+
+    Tester::new(NoPrototypeBuiltins::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
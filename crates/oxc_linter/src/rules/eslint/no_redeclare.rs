@@ -9,8 +9,13 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::VariableInfo;
 use oxc_span::{CompactString, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-redeclare): '{0}' is already defined.")]
@@ -38,8 +43,10 @@ struct NoRedeclareBySyntaxDiagnostic(
     #[label("It cannot be redeclared here.")] pub Span,
 );
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(default)]
 pub struct NoRedeclare {
+    #[serde(rename = "builtinGlobals")]
     built_in_globals: bool,
 }
 
@@ -63,13 +70,11 @@ declare_oxc_lint!(
 
 impl Rule for NoRedeclare {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let built_in_globals = value
-            .get(0)
-            .and_then(|config| config.get("builtinGlobals"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { built_in_globals }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoReturnAwait,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for NoReturnAwait {
@@ -6,17 +6,25 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, Span};
 use oxc_syntax::operator::UnaryOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-undef): Disallow the use of undeclared variables")]
 #[diagnostic(severity(warning), help("'{0}' is not defined."))]
 struct NoUndefDiagnostic(CompactString, #[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(default)]
 pub struct NoUndef {
     #[allow(dead_code)]
+    #[serde(rename = "typeof")]
     type_of: bool,
 }
 
@@ -40,12 +48,11 @@ declare_oxc_lint!(
 
 impl Rule for NoUndef {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let type_of = value
-            .get(0)
-            .and_then(|config| config.get("typeof"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        Self { type_of }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
     fn run_once(&self, ctx: &LintContext) {
         let symbol_table = ctx.symbols();
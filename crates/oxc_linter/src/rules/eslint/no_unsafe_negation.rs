@@ -9,8 +9,15 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("Unexpected logical not in the left hand side of '{0}' operator")]
@@ -22,7 +29,8 @@ use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 )]
 struct NoUnsafeNegationDiagnostic(&'static str, #[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoUnsafeNegation {
     /// true: disallow negation of the left-hand side of ordering relational operators
     /// false: allow negation of the left-hand side of ordering relational operators (<, >, <=, >=)
@@ -46,17 +54,17 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnsafeNegation,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUnsafeNegation {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let enforce_for_ordering_relations = value
-            .get(0)
-            .and_then(|config| config.get("enforceForOrderingRelations"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        Self { enforce_for_ordering_relations }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -82,24 +90,14 @@ impl NoUnsafeNegation {
     /// Precondition:
     /// expr.left is `UnaryExpression` whose operator is '!'
     fn report_with_fix(expr: &BinaryExpression, ctx: &LintContext<'_>) {
-        use oxc_codegen::{Context, Gen};
         // Diagnostic points at the unexpected negation
         let diagnostic = NoUnsafeNegationDiagnostic(expr.operator.as_str(), expr.left.span());
 
         let fix_producer = || {
-            // modify `!a instance of B` to `!(a instanceof B)`
-            let modified_code = {
-                let mut codegen = ctx.codegen();
-                codegen.print(b'!');
-                let Expression::UnaryExpression(left) = &expr.left else { unreachable!() };
-                codegen.print(b'(');
-                codegen.print_expression(&left.argument);
-                expr.operator.gen(&mut codegen, Context::default());
-                codegen.print_expression(&expr.right);
-                codegen.print(b')');
-                codegen.into_code()
-            };
-            Fix::new(modified_code, expr.span)
+            // modify `!a instance of B` to `!(a instanceof B)`, keeping the source text of the operands
+            let Expression::UnaryExpression(left) = &expr.left else { unreachable!() };
+            let negated = Span::new(left.argument.span().start, expr.span.end);
+            Fix::new(format!("!({})", negated.source_text(ctx.source_text())), expr.span)
         };
 
         ctx.diagnostic_with_fix(diagnostic, fix_producer);
@@ -145,5 +143,15 @@ fn test() {
         ("! a <= b", Some(serde_json::json!([{ "enforceForOrderingRelations": true }]))),
     ];
 
-    Tester::new(NoUnsafeNegation::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("!a in b", "!(a in b)", None),
+        ("!a instanceof b", "!(a instanceof b)", None),
+        (
+            "if (! a < b) {}",
+            "if (!(a < b)) {}",
+            Some(serde_json::json!([{ "enforceForOrderingRelations": true }])),
+        ),
+    ];
+
+    Tester::new(NoUnsafeNegation::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
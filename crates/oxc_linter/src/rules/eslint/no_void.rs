@@ -1,4 +1,8 @@
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 use oxc_ast::AstKind;
 use oxc_diagnostics::{
@@ -8,13 +12,16 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::UnaryOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-void): Disallow `void` operators")]
 #[diagnostic(severity(warning), help("Expected 'undefined' and instead saw 'void'."))]
 struct NoVoidDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoVoid {
     pub allow_as_statement: bool,
 }
@@ -42,13 +49,11 @@ declare_oxc_lint!(
 
 impl Rule for NoVoid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_as_statement = value
-            .get(0)
-            .and_then(|config| config.get("allowAsStatement"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { allow_as_statement }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
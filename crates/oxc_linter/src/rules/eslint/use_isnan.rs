@@ -8,8 +8,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum UseIsnanDiagnostic {
@@ -35,7 +41,8 @@ enum UseIsnanDiagnostic {
     IndexOfNaN(&'static str, #[label] Span),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct UseIsnan {
     /// Whether to disallow NaN in switch cases and discriminants
     enforce_for_switch_case: bool,
@@ -119,21 +126,11 @@ impl Rule for UseIsnan {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let (enforce_for_switch_case, enforce_for_index_of) =
-            value.get(0).map_or((true, false), |config| {
-                (
-                    config
-                        .get("enforceForSwitchCase")
-                        .and_then(serde_json::Value::as_bool)
-                        .unwrap_or(true),
-                    config
-                        .get("enforceForIndexOf")
-                        .and_then(serde_json::Value::as_bool)
-                        .unwrap_or_default(),
-                )
-            });
+        rule_option(&value, 0)
+    }
 
-        Self { enforce_for_switch_case, enforce_for_index_of }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 }
 
@@ -7,8 +7,15 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 use phf::{phf_set, Set};
-
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum ValidTypeofDiagnostic {
@@ -20,7 +27,8 @@ enum ValidTypeofDiagnostic {
     InvalidValue(#[help] Option<&'static str>, #[label] Span),
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct ValidTypeof {
     /// true requires typeof expressions to only be compared to string literals or other typeof expressions, and disallows comparisons to any other value.
     require_string_literals: bool,
@@ -46,6 +54,7 @@ declare_oxc_lint!(
     /// ```
     ValidTypeof,
     correctness,
+    fix
 );
 
 impl Rule for ValidTypeof {
@@ -117,14 +126,11 @@ impl Rule for ValidTypeof {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let require_string_literals = value.get(0).map_or(false, |config| {
-            config
-                .get("requireStringLiterals")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false)
-        });
-
-        Self { require_string_literals }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 }
 
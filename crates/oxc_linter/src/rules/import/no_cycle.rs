@@ -13,8 +13,13 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, Span};
 use oxc_syntax::module_record::ModuleRecord;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-import(no-cycle): Dependency cycle detected")]
@@ -22,7 +27,8 @@ use crate::{context::LintContext, rule::Rule};
 struct NoCycleDiagnostic(#[label] Span, String);
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-cycle.md>
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoCycle {
     /// maximum dependency depth to traverse
     max_depth: u32,
@@ -75,22 +81,11 @@ declare_oxc_lint!(
 
 impl Rule for NoCycle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        Self {
-            max_depth: obj
-                .and_then(|v| v.get("maxDepth"))
-                .and_then(serde_json::Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(u32::MAX, |n| n as u32),
-            ignore_external: obj
-                .and_then(|v| v.get("ignoreExternal"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            allow_unsafe_dynamic_cyclic_dependency: obj
-                .and_then(|v| v.get("allowUnsafeDynamicCyclicDependency"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
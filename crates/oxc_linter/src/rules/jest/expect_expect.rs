@@ -9,11 +9,13 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{Deserialize, Deserializer};
 
 use crate::{
     ast_util::get_declaration_of_variable,
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{
         collect_possible_jest_call_node, get_node_name, is_type_of_jest_fn_call, JestFnKind,
         JestGeneralFnKind, PossibleJestNode,
@@ -28,8 +30,10 @@ struct ExpectExpectDiagnostic(#[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct ExpectExpect(Box<ExpectExpectConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct ExpectExpectConfig {
+    #[serde(deserialize_with = "deserialize_assert_function_names")]
     assert_function_names: Vec<String>,
     additional_test_block_functions: Vec<String>,
 }
@@ -74,28 +78,11 @@ declare_oxc_lint!(
 
 impl Rule for ExpectExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let default_assert_function_names = vec![String::from("expect")];
-        let config = value.get(0);
-
-        let assert_function_names = config
-            .and_then(|config| config.get("assertFunctionNames"))
-            .and_then(serde_json::Value::as_array)
-            .map_or(default_assert_function_names, |v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(convert_pattern).collect()
-            });
-
-        let additional_test_block_functions = config
-            .and_then(|config| config.get("additionalTestBlockFunctions"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(ExpectExpectConfig {
-            assert_function_names,
-            additional_test_block_functions,
-        }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<ExpectExpectConfig>()]
     }
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
@@ -215,6 +202,13 @@ fn matches_assert_function_name(name: &str, patterns: &[String]) -> bool {
     patterns.iter().any(|pattern| Regex::new(pattern).unwrap().is_match(name))
 }
 
+fn deserialize_assert_function_names<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    let patterns = Vec::<String>::deserialize(deserializer)?;
+    Ok(patterns.iter().map(|pattern| convert_pattern(pattern)).collect())
+}
+
 fn convert_pattern(pattern: &str) -> String {
     // Pre-process pattern, e.g.
     // request.*.expect -> request.[a-z\\d]*.expect
@@ -2,7 +2,7 @@ use std::{collections::HashMap, hash::BuildHasherDefault};
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{collect_possible_jest_call_node, PossibleJestNode},
 };
 use oxc_ast::{ast::Expression, AstKind};
@@ -13,6 +13,8 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use rustc_hash::{FxHashMap, FxHasher};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 #[derive(Debug, Error, Diagnostic)]
 #[error(
@@ -24,7 +26,8 @@ use rustc_hash::{FxHashMap, FxHasher};
 )]
 pub struct ExceededMaxAssertion(pub usize, pub usize, #[label] pub Span);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct MaxExpects {
     pub max: usize,
 }
@@ -73,14 +76,11 @@ declare_oxc_lint!(
 
 impl Rule for MaxExpects {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max = value
-            .get(0)
-            .and_then(|config| config.get("max"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(5, |v| usize::try_from(v).unwrap_or(5));
+        rule_option(&value, 0)
+    }
 
-        Self { max }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -6,16 +6,23 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::{phf_map, Map};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 use std::borrow::Cow;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule};
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-jest(no-deprecated-functions): Disallow use of deprecated functions")]
 #[diagnostic(severity(warning), help("{0:?} has been deprecated in favor of {1:?}"))]
 pub struct DeprecatedFunction(pub String, pub String, #[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(default)]
 pub struct JestConfig {
     version: String,
 }
@@ -23,7 +30,8 @@ pub struct JestConfig {
 #[derive(Debug, Default, Clone)]
 pub struct NoDeprecatedFunctions(Box<NoDeprecatedFunctionsConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoDeprecatedFunctionsConfig {
     jest: JestConfig,
 }
@@ -76,6 +84,7 @@ declare_oxc_lint!(
     /// ```
     NoDeprecatedFunctions,
     style,
+    fix
 );
 
 const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_map! {
@@ -89,22 +98,11 @@ const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_m
 
 impl Rule for NoDeprecatedFunctions {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let version = value
-            .get(0)
-            .and_then(|v| v.get("jest"))
-            .and_then(|v| v.get("version"))
-            .and_then(|v| serde_json::Value::as_str(v))
-            // Todo: Fixed Me
-            // Currently set the default version to the (maybe) latest, to help to find more problems in
-            // the codebase. In the future, the version should come from the cli option or the config files,
-            // such as `package.json` or `eslint.config.js`.
-            .unwrap_or("29");
-
-        let major: Vec<&str> = version.split('.').collect();
-
-        Self(Box::new(NoDeprecatedFunctionsConfig {
-            jest: JestConfig { version: major[0].to_string() },
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoDeprecatedFunctionsConfig>()]
     }
 
     fn run<'a>(&self, node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
@@ -121,8 +119,12 @@ impl Rule for NoDeprecatedFunctions {
         }
 
         let node_name = chain.join(".");
-        // Todo: read from configuration
-        let jest_version_num: usize = self.jest.version.parse().unwrap_or(29);
+        // Todo: Fixed Me
+        // Currently set the default version to the (maybe) latest, to help to find more problems in
+        // the codebase. In the future, the version should come from the cli option or the config files,
+        // such as `package.json` or `eslint.config.js`.
+        let jest_version_num: usize =
+            self.jest.version.split('.').next().and_then(|major| major.parse().ok()).unwrap_or(29);
 
         if let Some((base_version, replacement)) = DEPRECATED_FUNCTIONS_MAP.get(&node_name) {
             if jest_version_num >= *base_version {
@@ -5,10 +5,12 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{
         collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
         PossibleJestNode,
@@ -23,7 +25,8 @@ pub struct UnexpectedHookDiagonsitc(#[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct NoHooks(Box<NoHooksConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoHooksConfig {
     allow: Vec<String>,
 }
@@ -86,16 +89,11 @@ declare_oxc_lint!(
 
 impl Rule for NoHooks {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow = value
-            .get(0)
-            .and_then(|config| config.get("allow"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(NoHooksConfig { allow }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoHooksConfig>()]
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -1,6 +1,6 @@
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{
         collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
         PossibleJestNode,
@@ -14,8 +14,8 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::HashMap, hash::BuildHasherDefault};
+use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema};
 
 #[derive(Debug, Error, Diagnostic)]
 enum NoRestrictedJestMethodsDiagnostic {
@@ -71,17 +71,19 @@ declare_oxc_lint!(
 
 impl Rule for NoRestrictedJestMethods {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let restricted_jest_methods = &value
-            .get(0)
-            .and_then(serde_json::Value::as_object)
-            .and_then(Self::compile_restricted_jest_methods)
-            .unwrap_or_default();
-
+        let restricted_jest_methods: FxHashMap<String, Option<String>> = rule_option(&value, 0);
         Self(Box::new(NoRestrictedJestMethodsConfig {
-            restricted_jest_methods: restricted_jest_methods.clone(),
+            restricted_jest_methods: restricted_jest_methods
+                .into_iter()
+                .map(|(name, message)| (name, message.unwrap_or_default()))
+                .collect(),
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<FxHashMap<String, Option<String>>>()]
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             self.run(possible_jest_node, ctx);
@@ -148,20 +150,6 @@ impl NoRestrictedJestMethods {
             );
         }
     }
-
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn compile_restricted_jest_methods(
-        matchers: &serde_json::Map<String, serde_json::Value>,
-    ) -> Option<HashMap<String, String, BuildHasherDefault<FxHasher>>> {
-        Some(
-            matchers
-                .iter()
-                .map(|(key, value)| {
-                    (String::from(key), String::from(value.as_str().unwrap_or_default()))
-                })
-                .collect(),
-        )
-    }
 }
 
 #[test]
@@ -1,6 +1,6 @@
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{
         collect_possible_jest_call_node, is_type_of_jest_fn_call, parse_expect_jest_fn_call,
         JestFnKind, KnownMemberExpressionProperty, PossibleJestNode,
@@ -15,8 +15,9 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
-use rustc_hash::{FxHashMap, FxHasher};
-use std::{collections::HashMap, hash::BuildHasherDefault, path::Path};
+use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema};
+use std::path::Path;
 
 #[derive(Debug, Error, Diagnostic)]
 enum NoRestrictedMatchersDiagnostic {
@@ -77,17 +78,19 @@ const MODIFIER_NAME: phf::Set<&'static str> = phf_set!["not", "rejects", "resolv
 
 impl Rule for NoRestrictedMatchers {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let restricted_matchers = &value
-            .get(0)
-            .and_then(serde_json::Value::as_object)
-            .and_then(Self::compile_restricted_matchers)
-            .unwrap_or_default();
-
+        let restricted_matchers: FxHashMap<String, Option<String>> = rule_option(&value, 0);
         Self(Box::new(NoRestrictedMatchersConfig {
-            restricted_matchers: restricted_matchers.clone(),
+            restricted_matchers: restricted_matchers
+                .into_iter()
+                .map(|(name, message)| (name, message.unwrap_or_default()))
+                .collect(),
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<FxHashMap<String, Option<String>>>()]
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             self.run(possible_jest_node, ctx);
@@ -156,20 +159,6 @@ impl NoRestrictedMatchers {
 
         chain_call == restriction
     }
-
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn compile_restricted_matchers(
-        matchers: &serde_json::Map<String, serde_json::Value>,
-    ) -> Option<HashMap<String, String, BuildHasherDefault<FxHasher>>> {
-        Some(
-            matchers
-                .iter()
-                .map(|(key, value)| {
-                    (String::from(key), String::from(value.as_str().unwrap_or_default()))
-                })
-                .collect(),
-        )
-    }
 }
 
 #[test]
@@ -8,10 +8,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNodeId;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{
         collect_possible_jest_call_node, get_node_name, parse_expect_jest_fn_call,
         parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind, KnownMemberExpressionParentKind,
@@ -29,7 +31,8 @@ struct NoStandaloneExpectDiagnostic(#[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct NoStandaloneExpect(Box<NoStandaloneExpectConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoStandaloneExpectConfig {
     additional_test_block_functions: Vec<String>,
 }
@@ -64,16 +67,11 @@ declare_oxc_lint!(
 
 impl Rule for NoStandaloneExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let additional_test_block_functions = value
-            .get(0)
-            .and_then(|v| v.get("additionalTestBlockFunctions"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(NoStandaloneExpectConfig { additional_test_block_functions }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoStandaloneExpectConfig>()]
     }
     fn run_once(&self, ctx: &LintContext<'_>) {
         let possible_jest_nodes = collect_possible_jest_call_node(ctx);
@@ -8,10 +8,12 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{
         collect_possible_jest_call_node, parse_expect_jest_fn_call, ExpectError, PossibleJestNode,
     },
@@ -26,7 +28,8 @@ struct ValidExpectDiagnostic(CompactString, &'static str, #[label] Span);
 #[derive(Debug, Default, Clone)]
 pub struct ValidExpect(Box<ValidExpectConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct ValidExpectConfig {
     async_matchers: Vec<String>,
     min_args: usize,
@@ -73,33 +76,11 @@ declare_oxc_lint!(
 
 impl Rule for ValidExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let default_async_matchers = vec![String::from("toResolve"), String::from("toReject")];
-        let config = value.get(0);
-
-        let async_matchers = config
-            .and_then(|config| config.get("asyncMatchers"))
-            .and_then(serde_json::Value::as_array)
-            .map_or(default_async_matchers, |v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(String::from).collect()
-            });
-        let min_args = config
-            .and_then(|config| config.get("minArgs"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(1, |v| usize::try_from(v).unwrap_or(1));
-
-        let max_args = config
-            .and_then(|config| config.get("maxArgs"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(1, |v| usize::try_from(v).unwrap_or(1));
-
-        let always_await = config
-            .and_then(|config| config.get("alwaysAwait"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self(Box::new(ValidExpectConfig { async_matchers, min_args, max_args, always_await }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<ValidExpectConfig>()]
     }
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
@@ -11,10 +11,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, GetSpan, Span};
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{Deserialize, Deserializer};
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{
         collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
         PossibleJestNode,
@@ -29,15 +31,30 @@ struct ValidTitleDiagnostic(CompactString, &'static str, #[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct ValidTitle(Box<ValidTitleConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct ValidTitleConfig {
     ignore_type_of_describe_name: bool,
     disallowed_words: Vec<String>,
+    #[serde(rename = "ignoreSpaces")]
     ignore_space: bool,
+    #[serde(rename = "mustNotMatch", deserialize_with = "deserialize_matcher_patterns")]
+    #[schemars(with = "MatcherPatternsOption")]
     must_not_match_patterns: HashMap<MatchKind, CompiledMatcherAndMessage>,
+    #[serde(rename = "mustMatch", deserialize_with = "deserialize_matcher_patterns")]
+    #[schemars(with = "MatcherPatternsOption")]
     must_match_patterns: HashMap<MatchKind, CompiledMatcherAndMessage>,
 }
 
+/// A pattern for all the blocks, optionally followed by a message, or a pattern by block
+#[derive(JsonSchema, Deserialize)]
+#[serde(untagged)]
+enum MatcherPatternsOption {
+    Pattern(String),
+    PatternWithMessage(Vec<String>),
+    ByBlock { describe: Option<String>, it: Option<String>, test: Option<String> },
+}
+
 impl std::ops::Deref for ValidTitle {
     type Target = ValidTitleConfig;
 
@@ -74,38 +91,11 @@ declare_oxc_lint!(
 
 impl Rule for ValidTitle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0);
-        let get_as_bool = |name: &str| -> bool {
-            config
-                .and_then(|v| v.get(name))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default()
-        };
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        let ignore_type_of_describe_name = get_as_bool("ignoreTypeOfDescribeName");
-        let ignore_space = get_as_bool("ignoreSpaces");
-        let disallowed_words = config
-            .and_then(|v| v.get("disallowedWords"))
-            .and_then(|v| v.as_array())
-            .map(|v| {
-                v.iter().filter_map(|v| v.as_str().map(std::string::ToString::to_string)).collect()
-            })
-            .unwrap_or_default();
-        let must_not_match_patterns = config
-            .and_then(|v| v.get("mustNotMatch"))
-            .and_then(compile_matcher_patterns)
-            .unwrap_or_default();
-        let must_match_patterns = config
-            .and_then(|v| v.get("mustMatch"))
-            .and_then(compile_matcher_patterns)
-            .unwrap_or_default();
-        Self(Box::new(ValidTitleConfig {
-            ignore_type_of_describe_name,
-            disallowed_words,
-            ignore_space,
-            must_not_match_patterns,
-            must_match_patterns,
-        }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<ValidTitleConfig>()]
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -190,12 +180,6 @@ enum MatchKind {
     Test,
 }
 
-#[derive(Copy, Clone)]
-enum MatcherPattern<'a> {
-    String(&'a serde_json::Value),
-    Vec(&'a Vec<serde_json::Value>),
-}
-
 impl MatchKind {
     fn from(name: &str) -> Option<Self> {
         match name {
@@ -207,68 +191,43 @@ impl MatchKind {
     }
 }
 
-fn compile_matcher_patterns(
-    matcher_patterns: &serde_json::Value,
-) -> Option<HashMap<MatchKind, CompiledMatcherAndMessage>> {
-    matcher_patterns
-        .as_array()
-        .map_or_else(
-            || {
-                // for `{ "describe": "/pattern/" }`
-                let obj = matcher_patterns.as_object()?;
-                let mut map: HashMap<MatchKind, CompiledMatcherAndMessage> = HashMap::new();
-                for (key, value) in obj {
-                    let Some(v) = compile_matcher_pattern(MatcherPattern::String(value)) else {
-                        continue;
-                    };
-                    if let Some(kind) = MatchKind::from(key) {
-                        map.insert(kind, v);
-                    }
-                }
-
-                Some(map)
-            },
-            |value| {
-                // for `["/pattern/", "message"]`
-                let mut map: HashMap<MatchKind, CompiledMatcherAndMessage> = HashMap::new();
-                let v = &compile_matcher_pattern(MatcherPattern::Vec(value))?;
-                map.insert(MatchKind::Describe, v.clone());
-                map.insert(MatchKind::Test, v.clone());
-                map.insert(MatchKind::It, v.clone());
-                Some(map)
-            },
-        )
-        .map_or_else(
-            || {
-                // for `"/pattern/"`
-                let string = matcher_patterns.as_str()?;
-                let mut map: HashMap<MatchKind, CompiledMatcherAndMessage> = HashMap::new();
-                let v = &compile_matcher_pattern(MatcherPattern::String(
-                    &serde_json::Value::String(string.to_string()),
-                ))?;
-                map.insert(MatchKind::Describe, v.clone());
-                map.insert(MatchKind::Test, v.clone());
-                map.insert(MatchKind::It, v.clone());
-                Some(map)
-            },
-            Some,
-        )
-}
-
-fn compile_matcher_pattern(pattern: MatcherPattern) -> Option<CompiledMatcherAndMessage> {
-    match pattern {
-        MatcherPattern::String(pattern) => {
-            let reg_str = format!("(?u){}", pattern.as_str()?);
-            let reg = Regex::new(&reg_str).ok()?;
-            Some((reg, None))
-        }
-        MatcherPattern::Vec(pattern) => {
-            let reg_str = pattern.first().and_then(|v| v.as_str()).map(|v| format!("(?u){v}"))?;
-            let reg = Regex::new(&reg_str).ok()?;
-            let message = pattern.get(1).map(std::string::ToString::to_string);
-            Some((reg, message))
-        }
-    }
+/// Compiles the patterns by kind of block, invalid patterns are left out
+fn deserialize_matcher_patterns<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<MatchKind, CompiledMatcherAndMessage>, D::Error> {
+    let all_blocks = |pattern| {
+        vec![
+            (MatchKind::Describe, Some(pattern)),
+            (MatchKind::It, Some(pattern)),
+            (MatchKind::Test, Some(pattern)),
+        ]
+    };
+    let option = MatcherPatternsOption::deserialize(deserializer)?;
+    let (patterns, message) = match &option {
+        // `"/pattern/"`
+        MatcherPatternsOption::Pattern(pattern) => (all_blocks(pattern), None),
+        // `["/pattern/", "message"]`
+        MatcherPatternsOption::PatternWithMessage(pattern) => match pattern.first() {
+            Some(first) => (all_blocks(first), pattern.get(1)),
+            None => (vec![], None),
+        },
+        // `{ "describe": "/pattern/" }`
+        MatcherPatternsOption::ByBlock { describe, it, test } => (
+            vec![
+                (MatchKind::Describe, describe.as_ref()),
+                (MatchKind::It, it.as_ref()),
+                (MatchKind::Test, test.as_ref()),
+            ],
+            None,
+        ),
+    };
+    Ok(patterns
+        .into_iter()
+        .filter_map(|(kind, pattern)| {
+            let regex = Regex::new(&format!("(?u){}", pattern?)).ok()?;
+            Some((kind, (regex, message.cloned())))
+        })
+        .collect())
 }
 
 fn validate_title(
@@ -11,12 +11,18 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::utils::{
     get_element_type, get_prop_value, get_string_literal_prop_value, has_jsx_prop_lowercase,
     object_has_accessible_child,
 };
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum AltTextDiagnostic {
@@ -74,6 +80,31 @@ pub struct AltTextConfig {
     input_type_image: Option<Vec<String>>,
 }
 
+/// Elements to check, and custom components to check as each of them
+#[derive(Default, JsonSchema, Deserialize)]
+#[serde(default)]
+struct AltTextOptions {
+    /// All the elements are checked when left out
+    elements: Option<Vec<AltTextElement>>,
+    img: Vec<String>,
+    object: Vec<String>,
+    area: Vec<String>,
+    #[serde(rename = "input[type=\"image\"]")]
+    input_type_image: Vec<String>,
+}
+
+#[derive(PartialEq, Eq, JsonSchema, Deserialize)]
+enum AltTextElement {
+    #[serde(rename = "img")]
+    Img,
+    #[serde(rename = "object")]
+    Object,
+    #[serde(rename = "area")]
+    Area,
+    #[serde(rename = "input[type=\"image\"]")]
+    InputTypeImage,
+}
+
 impl std::ops::Deref for AltText {
     type Target = AltTextConfig;
 
@@ -129,39 +160,24 @@ declare_oxc_lint!(
 
 impl Rule for AltText {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut alt_text = AltTextConfig::default();
-        if let Some(config) = value.get(0) {
-            if let Some(elements) = config.get("elements").and_then(|v| v.as_array()) {
-                alt_text =
-                    AltTextConfig { img: None, object: None, area: None, input_type_image: None };
-                for el in elements {
-                    match el.as_str() {
-                        Some("img") => alt_text.img = Some(vec![]),
-                        Some("object") => alt_text.object = Some(vec![]),
-                        Some("area") => alt_text.area = Some(vec![]),
-                        Some("input[type=\"image\"]") => alt_text.input_type_image = Some(vec![]),
-                        _ => {}
-                    }
-                }
-            }
-
-            for (tags, field) in [
-                (&mut alt_text.img, "img"),
-                (&mut alt_text.object, "object"),
-                (&mut alt_text.area, "area"),
-                (&mut alt_text.input_type_image, "input[type=\"image\"]"),
-            ] {
-                if let (Some(tags), Some(elements)) =
-                    (tags, config.get(field).and_then(|v| v.as_array()))
-                {
-                    tags.extend(
-                        elements.iter().filter_map(|v| v.as_str().map(ToString::to_string)),
-                    );
-                }
-            }
-        }
+        let AltTextOptions { elements, img, object, area, input_type_image } =
+            rule_option(&value, 0);
+        let check = |element, components| {
+            elements
+                .as_ref()
+                .map_or(true, |elements| elements.contains(&element))
+                .then_some(components)
+        };
+        Self(Box::new(AltTextConfig {
+            img: check(AltTextElement::Img, img),
+            object: check(AltTextElement::Object, object),
+            area: check(AltTextElement::Area, area),
+            input_type_image: check(AltTextElement::InputTypeImage, input_type_image),
+        }))
+    }
 
-        Self(Box::new(alt_text))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<AltTextOptions>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -8,10 +8,12 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{get_element_type, has_jsx_prop_lowercase},
     AstNode,
 };
@@ -36,7 +38,8 @@ enum AnchorIsValidDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct AnchorIsValid(Box<AnchorIsValidConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 struct AnchorIsValidConfig {
     valid_hrefs: Vec<String>,
 }
@@ -119,11 +122,11 @@ declare_oxc_lint!(
 
 impl Rule for AnchorIsValid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let valid_hrefs =
-            value.get("validHrefs").and_then(|v| v.as_array()).map_or_else(Vec::new, |array| {
-                array.iter().filter_map(|v| v.as_str().map(String::from)).collect::<Vec<String>>()
-            });
-        Self(Box::new(AnchorIsValidConfig { valid_hrefs }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<AnchorIsValidConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -233,42 +236,42 @@ fn test() {
     let pass = vec![
         (r"<Anchor />", None, None),
         (r"<a {...props} />", None, None),
-        (r"<a href='foo' />", Some(serde_json::json!({ "validHrefs": ["foo"] })), None),
+        (r"<a href='foo' />", Some(serde_json::json!([{ "validHrefs": ["foo"] }])), None),
         (r"<a href={foo} />", None, None),
-        (r"<a href='/foo' />", Some(serde_json::json!({ "validHrefs": ["/foo"] })), None),
+        (r"<a href='/foo' />", Some(serde_json::json!([{ "validHrefs": ["/foo"] }])), None),
         (
             r"<a href='https://foo.bar.com' />",
-            Some(serde_json::json!({ "validHrefs": ["https://foo.bar.com"] })),
+            Some(serde_json::json!([{ "validHrefs": ["https://foo.bar.com"] }])),
             None,
         ),
         (r"<div href='foo' />", None, None),
         (
             r"<a href='javascript' />",
-            Some(serde_json::json!({ "validHrefs": ["javascript"] })),
+            Some(serde_json::json!([{ "validHrefs": ["javascript"] }])),
             None,
         ),
         (
             r"<a href='javascriptFoo' />",
-            Some(serde_json::json!({ "validHrefs": ["javascriptFoo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["javascriptFoo"] }])),
             None,
         ),
         (r"<a href={`#foo`}/>", None, None),
-        (r"<a href={'foo'}/>", Some(serde_json::json!({ "validHrefs": ["foo"] })), None),
+        (r"<a href={'foo'}/>", Some(serde_json::json!([{ "validHrefs": ["foo"] }])), None),
         (
             r"<a href={'javascript'}/>",
-            Some(serde_json::json!({ "validHrefs": ["javascript"] })),
+            Some(serde_json::json!([{ "validHrefs": ["javascript"] }])),
             None,
         ),
         (r"<a href={`#javascript`}/>", None, None),
-        (r"<a href='#foo' />", Some(serde_json::json!({ "validHrefs": ["#foo"] })), None),
+        (r"<a href='#foo' />", Some(serde_json::json!([{ "validHrefs": ["#foo"] }])), None),
         (
             r"<a href='#javascript' />",
-            Some(serde_json::json!({ "validHrefs": ["#javascript"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#javascript"] }])),
             None,
         ),
         (
             r"<a href='#javascriptFoo' />",
-            Some(serde_json::json!({ "validHrefs": ["#javascriptFoo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#javascriptFoo"] }])),
             None,
         ),
         (r"<UX.Layout>test</UX.Layout>", None, None),
@@ -293,7 +296,7 @@ fn test() {
         // (r#"<Link href='#foo' />"#, Some(serde_json::json!(components))),
         (
             r"<Link href='#foo' />",
-            Some(serde_json::json!({ "validHrefs": ["#foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#foo"] }])),
             Some(
                 serde_json::json!({ "jsx-a11y": { "components": { "Anchor": "a", "Link": "a" } } }),
             ),
@@ -336,30 +339,30 @@ fn test() {
         (r"<a {...props} onClick={() => void 0} />", None, None),
         (
             r"<a href='foo' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["foo"] }])),
             None,
         ),
         (r"<a href={foo} onClick={() => void 0} />", None, None),
         (
             r"<a href='/foo' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["/foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["/foo"] }])),
             None,
         ),
         (
             r"<a href='https://foo.bar.com' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["https://foo.bar.com"] })),
+            Some(serde_json::json!([{ "validHrefs": ["https://foo.bar.com"] }])),
             None,
         ),
         (r"<div href='foo' onClick={() => void 0} />", None, None),
         (r"<a href={`#foo`} onClick={() => void 0} />", None, None),
         (
             r"<a href={'foo'} onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["foo"] }])),
             None,
         ),
         (
             r"<a href='#foo' onClick={() => void 0} />",
-            Some(serde_json::json!({ "validHrefs": ["#foo"] })),
+            Some(serde_json::json!([{ "validHrefs": ["#foo"] }])),
             None,
         ),
         (r"<a href={this} onClick={() => void 0} />", None, None),
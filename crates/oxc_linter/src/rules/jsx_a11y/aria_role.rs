@@ -8,11 +8,13 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     globals::{HTML_TAG, VALID_ARIA_ROLES},
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{get_element_type, get_prop_value, has_jsx_prop},
     AstNode,
 };
@@ -28,8 +30,10 @@ struct AriaRoleDiagnostic(#[label] pub Span, String);
 #[derive(Debug, Default, Clone)]
 pub struct AriaRole(Box<AriaRoleConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct AriaRoleConfig {
+    #[serde(rename = "ignoreNonDOM")]
     ignore_non_dom: bool,
     allowed_invalid_roles: Vec<String>,
 }
@@ -96,26 +100,11 @@ declare_oxc_lint!(
 
 impl Rule for AriaRole {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut ignore_non_dom = false;
-        let mut allowed_invalid_roles: Vec<String> = vec![];
-
-        let _ = value.as_array().unwrap().iter().find(|v| {
-            if let serde_json::Value::Object(obj) = v {
-                if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
-                    ignore_non_dom = *val;
-                }
-
-                if let Some(serde_json::Value::Array(val)) = obj.get("allowedInvalidRoles") {
-                    allowed_invalid_roles =
-                        val.iter().map(|v| v.as_str().unwrap().to_string()).collect();
-                }
-
-                return true;
-            }
-            false
-        });
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(AriaRoleConfig { ignore_non_dom, allowed_invalid_roles }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<AriaRoleConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -169,13 +158,13 @@ fn test() {
     use crate::tester::Tester;
 
     fn ignore_non_dom_schema() -> serde_json::Value {
-        serde_json::json!([2,{
+        serde_json::json!([{
             "ignoreNonDOM": true
         }])
     }
 
     fn allowed_invalid_roles() -> serde_json::Value {
-        serde_json::json!([2,{
+        serde_json::json!([{
             "allowedInvalidRoles": ["invalid-role", "other-invalid-role"],
         }])
     }
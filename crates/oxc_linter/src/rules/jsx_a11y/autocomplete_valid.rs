@@ -1,6 +1,6 @@
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{get_element_type, has_jsx_prop_lowercase},
     AstNode,
 };
@@ -15,6 +15,8 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::{phf_map, phf_set};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 #[derive(Debug, Error, Diagnostic)]
 #[error(
     "eslint-plugin-jsx-a11y(autocomplete-valid): `{autocomplete}` is not a valid value for autocomplete."
@@ -47,7 +49,8 @@ declare_oxc_lint!(
     correctness
 );
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct AutocompleteValidConfig {
     input_components: Vec<String>,
 }
@@ -164,20 +167,16 @@ fn is_valid_autocomplete_value(value: &str) -> bool {
 
 impl Rule for AutocompleteValid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut input_components: Vec<String> = vec!["input".to_string()];
-        if let Some(config) = value.get(0) {
-            if let Some(serde_json::Value::Array(components)) = config.get("inputComponents") {
-                input_components = components
-                    .iter()
-                    .filter_map(|c| c.as_str().map(std::string::ToString::to_string))
-                    .collect();
-            }
-        }
-
+        let mut config: AutocompleteValidConfig = rule_option(&value, 0);
         // Add default input component
-        input_components.push("input".to_string());
+        if !config.input_components.iter().any(|component| component == "input") {
+            config.input_components.push("input".to_string());
+        }
+        Self(Box::new(config))
+    }
 
-        Self(Box::new(AutocompleteValidConfig { input_components }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<AutocompleteValidConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -13,11 +13,17 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::utils::{
     get_element_type, get_prop_value, has_jsx_prop_lowercase, is_hidden_from_screen_reader,
 };
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-jsx-a11y(img-redundant-alt): Redundant alt attribute.")]
@@ -27,9 +33,12 @@ struct ImgRedundantAltDiagnostic(#[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct ImgRedundantAlt(Box<ImgRedundantAltConfig>);
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Custom components and words to check, in addition to the defaults
+#[derive(Debug, Clone, PartialEq, Eq, JsonSchema, Deserialize)]
 pub struct ImgRedundantAltConfig {
+    #[serde(rename = "components", default)]
     types_to_validate: Vec<String>,
+    #[serde(rename = "words", default)]
     redundant_words: Vec<String>,
 }
 
@@ -90,22 +99,16 @@ const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
 
 impl Rule for ImgRedundantAlt {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut img_redundant_alt = ImgRedundantAltConfig::default();
-        if let Some(config) = value.get(0) {
-            if let Some(components) = config.get("components").and_then(|v| v.as_array()) {
-                img_redundant_alt
-                    .types_to_validate
-                    .extend(components.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
-            }
-
-            if let Some(words) = config.get("words").and_then(|v| v.as_array()) {
-                img_redundant_alt
-                    .redundant_words
-                    .extend(words.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
-            }
+        let mut config = ImgRedundantAltConfig::default();
+        if let Some(options) = rule_option::<Option<ImgRedundantAltConfig>>(&value, 0) {
+            config.types_to_validate.extend(options.types_to_validate);
+            config.redundant_words.extend(options.redundant_words);
         }
+        Self(Box::new(config))
+    }
 
-        Self(Box::new(img_redundant_alt))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<ImgRedundantAltConfig>()]
     }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
@@ -10,8 +10,15 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, utils::get_element_type, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    utils::get_element_type,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-jsx-a11y(media-has-caption): Missing <track> element with captions inside <audio> or <video> element")]
@@ -24,10 +31,15 @@ struct MediaHasCaptionDiagnostic(#[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct MediaHasCaption(Box<MediaHasCaptionConfig>);
 
-#[derive(Debug, Clone)]
+/// Custom components to check as each element, in addition to the element itself
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MediaHasCaptionConfig {
+    #[serde(default)]
     audio: Vec<String>,
+    #[serde(default)]
     video: Vec<String>,
+    #[serde(default)]
     track: Vec<String>,
 }
 
@@ -67,32 +79,17 @@ declare_oxc_lint!(
 impl Rule for MediaHasCaption {
     fn from_configuration(value: serde_json::Value) -> Self {
         let mut config = MediaHasCaptionConfig::default();
-
-        if let Some(arr) = value.as_array() {
-            for v in arr {
-                if let serde_json::Value::Object(rule_config) = v {
-                    if let Some(audio) = rule_config.get("audio").and_then(|v| v.as_array()) {
-                        config
-                            .audio
-                            .extend(audio.iter().filter_map(|v| v.as_str().map(String::from)));
-                    }
-                    if let Some(video) = rule_config.get("video").and_then(|v| v.as_array()) {
-                        config
-                            .video
-                            .extend(video.iter().filter_map(|v| v.as_str().map(String::from)));
-                    }
-                    if let Some(track) = rule_config.get("track").and_then(|v| v.as_array()) {
-                        config
-                            .track
-                            .extend(track.iter().filter_map(|v| v.as_str().map(String::from)));
-                    }
-                    break;
-                }
-            }
+        if let Some(options) = rule_option::<Option<MediaHasCaptionConfig>>(&value, 0) {
+            config.audio.extend(options.audio);
+            config.video.extend(options.video);
+            config.track.extend(options.track);
         }
-
         Self(Box::new(config))
     }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<MediaHasCaptionConfig>()]
+    }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
 
@@ -8,11 +8,13 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     globals::HTML_TAG,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{get_element_type, get_prop_value, has_jsx_prop},
     AstNode,
 };
@@ -31,7 +33,8 @@ enum MouseEventsHaveKeyEventsDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct MouseEventsHaveKeyEvents(Box<MouseEventsHaveKeyEventsConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct MouseEventsHaveKeyEventsConfig {
     hover_in_handlers: Vec<String>,
     hover_out_handlers: Vec<String>,
@@ -70,33 +73,11 @@ declare_oxc_lint!(
 
 impl Rule for MouseEventsHaveKeyEvents {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut config = MouseEventsHaveKeyEventsConfig::default();
-
-        if let Some(hover_in_handlers_config) = value
-            .get(0)
-            .and_then(|v| v.get("hoverInHandlers"))
-            .and_then(serde_json::Value::as_array)
-        {
-            config.hover_in_handlers = hover_in_handlers_config
-                .iter()
-                .filter_map(serde_json::Value::as_str)
-                .map(ToString::to_string)
-                .collect();
-        }
-
-        if let Some(hover_out_handlers_config) = value
-            .get(0)
-            .and_then(|v| v.get("hoverOutHandlers"))
-            .and_then(serde_json::Value::as_array)
-        {
-            config.hover_out_handlers = hover_out_handlers_config
-                .iter()
-                .filter_map(serde_json::Value::as_str)
-                .map(ToString::to_string)
-                .collect();
-        }
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(config))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<MouseEventsHaveKeyEventsConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -5,11 +5,13 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     globals::HTML_TAG,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{get_element_type, has_jsx_prop},
     AstNode,
 };
@@ -19,8 +21,10 @@ use crate::{
 #[diagnostic(severity(warning), help("Remove `autofocus` attribute"))]
 struct NoAutofocusDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoAutofocus {
+    #[serde(rename = "ignoreNonDOM")]
     ignore_non_dom: bool,
 }
 
@@ -63,30 +67,13 @@ declare_oxc_lint!(
     correctness
 );
 
-impl NoAutofocus {
-    pub fn set_option(&mut self, value: bool) {
-        self.ignore_non_dom = value;
-    }
-}
-
 impl Rule for NoAutofocus {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut no_focus = Self::default();
-
-        if let Some(arr) = value.as_array() {
-            if arr.iter().any(|v| {
-                if let serde_json::Value::Object(obj) = v {
-                    if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
-                        return *val;
-                    }
-                }
-                false
-            }) {
-                no_focus.set_option(true);
-            }
-        }
+        rule_option(&value, 0)
+    }
 
-        no_focus
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -116,7 +103,7 @@ impl Rule for NoAutofocus {
 fn test() {
     use crate::tester::Tester;
     fn config() -> serde_json::Value {
-        serde_json::json!([2,{
+        serde_json::json!([{
             "ignoreNonDOM": true
         }])
     }
@@ -12,8 +12,14 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNodeId;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum JsxNoUselessFragmentDiagnostic {
@@ -25,7 +31,8 @@ enum JsxNoUselessFragmentDiagnostic {
     ChildOfHtmlElement(#[label] Span),
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct JsxNoUselessFragment {
     /// Allow fragments with a single expression child.
     pub allow_expressions: bool,
@@ -56,13 +63,11 @@ declare_oxc_lint!(
 
 impl Rule for JsxNoUselessFragment {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
+        rule_option(&value, 0)
+    }
 
-        Self {
-            allow_expressions: value
-                .and_then(|val| val.get("allowExpressions").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -236,7 +241,7 @@ fn test() {
         ",
             Some(json!([{ "allowExpressions": true }])),
         ),
-        (r"{1 && <>{1}</>}", Some(json!(["warn", {"allowExpressions": true}]))),
+        (r"{1 && <>{1}</>}", Some(json!([{ "allowExpressions": true }]))),
     ];
 
     let fail = vec![
@@ -11,10 +11,12 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{get_parent_es5_component, get_parent_es6_component},
     AstNode,
 };
@@ -30,7 +32,8 @@ enum NoStringRefsDiagnostic {
     StringInRefDeprecated(#[label] Span),
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoStringRefs {
     no_template_literals: bool,
 }
@@ -105,11 +108,13 @@ fn is_literal_ref_attribute(attr: &JSXAttribute, no_template_literals: bool) ->
 
 impl Rule for NoStringRefs {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let no_template_literals =
-            value.get("noTemplateLiterals").and_then(serde_json::Value::as_bool).unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { no_template_literals }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(attr)) => {
@@ -229,7 +234,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -242,7 +247,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -252,7 +257,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -285,7 +290,7 @@ fn test() {
                 }
               }
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
     ];
 
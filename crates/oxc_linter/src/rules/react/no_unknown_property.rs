@@ -12,11 +12,17 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::{phf_map, phf_set, Map, Set};
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::Deserialize;
 use std::collections::hash_map::HashMap;
 use std::collections::hash_set::HashSet;
 
-use crate::{context::LintContext, rule::Rule, utils::get_jsx_attribute_name, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    utils::get_jsx_attribute_name,
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 enum NoUnknownPropertyDiagnostic {
@@ -37,7 +43,7 @@ enum NoUnknownPropertyDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoUnknownProperty(Box<NoUnknownPropertyConfig>);
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NoUnknownPropertyConfig {
     #[serde(default)]
@@ -438,11 +444,11 @@ fn has_uppercase(name: &str) -> bool {
 
 impl Rule for NoUnknownProperty {
     fn from_configuration(value: serde_json::Value) -> Self {
-        value
-            .as_array()
-            .and_then(|arr| arr.first())
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
-            .map_or_else(Self::default, |value| Self(Box::new(value)))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoUnknownPropertyConfig>()]
     }
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         static HTML_TAG_CONVENTION: Lazy<Regex> = Lazy::new(|| Regex::new("^[a-z][^-]*$").unwrap());
@@ -9,8 +9,14 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule};
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Default, Clone)]
 pub struct ArrayType(Box<ArrayTypeConfig>);
@@ -29,6 +35,7 @@ declare_oxc_lint!(
     /// ```
     ArrayType,
     style,
+    fix
 );
 
 #[derive(Debug, Diagnostic, Error)]
@@ -56,7 +63,8 @@ pub enum ArrayTypeDiagnostic {
     ArraySimple(String, String, String, #[label] Span),
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(default)]
 pub struct ArrayTypeConfig {
     // The array type expected for mutable cases.
     default: ArrayOption,
@@ -71,7 +79,8 @@ impl std::ops::Deref for ArrayType {
         &self.0
     }
 }
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ArrayOption {
     #[default]
     Array,
@@ -81,32 +90,11 @@ pub enum ArrayOption {
 
 impl Rule for ArrayType {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self(Box::new(ArrayTypeConfig {
-            default: value
-                .get(0)
-                .and_then(|v| v.get("default"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(
-                    || ArrayOption::Array,
-                    |s| match s {
-                        "array" => ArrayOption::Array,
-                        "generic" => ArrayOption::Generic,
-                        _ => ArrayOption::ArraySimple,
-                    },
-                ),
-            readonly: value
-                .get(0)
-                .and_then(|v| v.get("readonly"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(
-                    || None,
-                    |s| match s {
-                        "array" => Some(ArrayOption::Array),
-                        "generic" => Some(ArrayOption::Generic),
-                        _ => Some(ArrayOption::ArraySimple),
-                    },
-                ),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<ArrayTypeConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -5,8 +5,13 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 pub enum BanTsCommentDiagnostic {
@@ -28,12 +33,18 @@ pub enum BanTsCommentDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct BanTsComment(Box<BanTsCommentConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
 pub struct BanTsCommentConfig {
+    #[schemars(with = "DirectiveOption")]
     ts_expect_error: DirectiveConfig,
+    #[schemars(with = "DirectiveOption")]
     ts_ignore: DirectiveConfig,
+    #[schemars(with = "DirectiveOption")]
     ts_nocheck: DirectiveConfig,
+    #[schemars(with = "DirectiveOption")]
     ts_check: DirectiveConfig,
+    #[serde(rename = "minimumDescriptionLength")]
     minimum_description_length: u64,
 }
 
@@ -57,32 +68,41 @@ impl Default for BanTsCommentConfig {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "DirectiveOption")]
 pub enum DirectiveConfig {
     Boolean(bool),
     RequireDescription,
     DescriptionFormat(Option<Regex>),
 }
 
-impl DirectiveConfig {
-    fn from_json(value: &serde_json::Value) -> Option<Self> {
-        match value {
-            serde_json::Value::Bool(b) => Some(Self::Boolean(*b)),
-            serde_json::Value::String(s) => {
-                if s == "allow-with-description" {
-                    Some(Self::RequireDescription)
-                } else {
-                    None
-                }
-            }
-            serde_json::Value::Object(o) => {
-                let re = o
-                    .get("descriptionFormat")
-                    .and_then(serde_json::Value::as_str)
-                    .and_then(|pattern| Regex::new(pattern).ok());
-                Some(Self::DescriptionFormat(re))
+/// `true` to ban the directive, `"allow-with-description"`,
+/// or `{ "descriptionFormat": "<pattern>" }` to require a description matching the pattern
+#[derive(JsonSchema, Deserialize)]
+#[serde(untagged)]
+enum DirectiveOption {
+    Boolean(bool),
+    RequireDescription(RequireDescriptionOption),
+    #[serde(rename_all = "camelCase")]
+    DescriptionFormat {
+        description_format: String,
+    },
+}
+
+#[derive(JsonSchema, Deserialize)]
+enum RequireDescriptionOption {
+    #[serde(rename = "allow-with-description")]
+    AllowWithDescription,
+}
+
+impl From<DirectiveOption> for DirectiveConfig {
+    fn from(option: DirectiveOption) -> Self {
+        match option {
+            DirectiveOption::Boolean(b) => Self::Boolean(b),
+            DirectiveOption::RequireDescription(_) => Self::RequireDescription,
+            DirectiveOption::DescriptionFormat { description_format } => {
+                Self::DescriptionFormat(Regex::new(&description_format).ok())
             }
-            _ => None,
         }
     }
 }
@@ -108,33 +128,11 @@ declare_oxc_lint!(
 
 impl Rule for BanTsComment {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self(Box::new(BanTsCommentConfig {
-            ts_expect_error: value
-                .get(0)
-                .and_then(|x| x.get("ts-expect-error"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::RequireDescription),
-            ts_ignore: value
-                .get(0)
-                .and_then(|x| x.get("ts-ignore"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(true)),
-            ts_nocheck: value
-                .get(0)
-                .and_then(|x| x.get("ts-nocheck"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(true)),
-            ts_check: value
-                .get(0)
-                .and_then(|x| x.get("ts-check"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(false)),
-            minimum_description_length: value
-                .get(0)
-                .and_then(|x| x.get("minimumDescriptionLength"))
-                .and_then(serde_json::Value::as_u64)
-                .unwrap_or(3),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<BanTsCommentConfig>()]
     }
 
     fn run_once(&self, ctx: &LintContext) {
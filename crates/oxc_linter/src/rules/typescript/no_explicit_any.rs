@@ -6,16 +6,23 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-explicit-any): Unexpected any. Specify a different type.")]
 #[diagnostic(severity(warning), help("Use `unknown` instead, this will force you to explicitly, and safely, assert the type is correct."))]
 struct NoExplicitAnyDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoExplicitAny {
     /// Whether to enable auto-fixing in which the `any` type is converted to the `unknown` type.
     ///
@@ -85,7 +92,8 @@ declare_oxc_lint!(
     /// Whether to enable auto-fixing in which the `any` type is converted to the `unknown` type.
     /// `false` by default.
     NoExplicitAny,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoExplicitAny {
@@ -105,11 +113,11 @@ impl Rule for NoExplicitAny {
     }
 
     fn from_configuration(value: Value) -> Self {
-        let Some(cfg) = value.get(0) else { return Self::default() };
-        let fix_to_unknown = cfg.get("fixToUnknown").and_then(Value::as_bool).unwrap_or(false);
-        let ignore_rest_args = cfg.get("ignoreRestArgs").and_then(Value::as_bool).unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { fix_to_unknown, ignore_rest_args }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 }
 
@@ -128,16 +136,10 @@ mod tests {
     use crate::tester::Tester;
     use serde_json::json;
 
-    #[test]
-    fn test_simple() {
-        let pass = vec!["let x: number = 1"];
-        let fail = vec!["let x: any = 1"];
-        Tester::new(NoExplicitAny::NAME, pass, fail).test();
-    }
-
     #[test]
     fn test() {
         let pass = vec![
+            ("let x: number = 1", None),
             ("const number: number = 1;", None),
             ("function greet(): string {}", None),
             ("function greet(): Array<string> {}", None),
@@ -564,6 +566,7 @@ mod tests {
         ];
 
         let fail = vec![
+            ("let x: any = 1", None),
             ("const number: any = 1", None),
             ("function generic(): any {}", None),
             ("function generic(): Array<any>", None),
@@ -8,8 +8,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-namespace): ES2015 module syntax is preferred over namespaces.")]
@@ -19,7 +25,8 @@ use crate::{context::LintContext, rule::Rule, AstNode};
 )]
 struct NoNamespaceDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoNamespace {
     allow_declarations: bool,
     allow_definition_files: bool,
@@ -47,18 +54,11 @@ declare_oxc_lint!(
 
 impl Rule for NoNamespace {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            allow_declarations: value
-                .get(0)
-                .and_then(|x| x.get("allowDeclarations"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allow_definition_files: value
-                .get(0)
-                .and_then(|x| x.get("allowDefinitionFiles"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
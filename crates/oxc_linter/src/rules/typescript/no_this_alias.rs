@@ -8,8 +8,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(no-this-alias): Unexpected aliasing of 'this' to local variable.")]
@@ -34,9 +40,11 @@ struct NoThisDestructureDiagnostic(#[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct NoThisAlias(Box<NoThisAliasConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(default)]
 pub struct NoThisAliasConfig {
     allow_destructuring: bool,
+    #[schemars(with = "Vec<String>")]
     allow_names: Vec<CompactString>,
 }
 
@@ -74,25 +82,11 @@ declare_oxc_lint!(
 
 impl Rule for NoThisAlias {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        let allowed_names = value
-            .get(0)
-            .and_then(|v| v.get("allow_names"))
-            .and_then(serde_json::Value::as_array)
-            .unwrap_or(&vec![])
-            .iter()
-            .map(serde_json::Value::as_str)
-            .filter(std::option::Option::is_some)
-            .map(|x| CompactString::from(x.unwrap()))
-            .collect::<Vec<CompactString>>();
-
-        Self(Box::new(NoThisAliasConfig {
-            allow_destructuring: obj
-                .and_then(|v| v.get("allow_destructuring"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            allow_names: allowed_names,
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NoThisAliasConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
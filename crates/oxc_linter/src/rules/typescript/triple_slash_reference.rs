@@ -10,8 +10,13 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("typescript-eslint(triple-slash-reference): Do not use a triple slash reference for {0}, use `import` style instead.")]
@@ -21,25 +26,29 @@ struct TripleSlashReferenceDiagnostic(String, #[label] pub Span);
 #[derive(Debug, Default, Clone)]
 pub struct TripleSlashReference(Box<TripleSlashReferenceConfig>);
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, JsonSchema, Deserialize)]
+#[serde(default)]
 pub struct TripleSlashReferenceConfig {
     lib: LibOption,
     path: PathOption,
     types: TypesOption,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum LibOption {
     #[default]
     Always,
     Never,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum PathOption {
     Always,
     #[default]
     Never,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum TypesOption {
     Always,
     Never,
@@ -73,34 +82,11 @@ declare_oxc_lint!(
 
 impl Rule for TripleSlashReference {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let options: Option<&serde_json::Value> = value.get(0);
-        Self(Box::new(TripleSlashReferenceConfig {
-            lib: options
-                .and_then(|x| x.get("lib"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(LibOption::default, |value| match value {
-                    "always" => LibOption::Always,
-                    "never" => LibOption::Never,
-                    _ => LibOption::default(),
-                }),
-            path: options
-                .and_then(|x| x.get("path"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(PathOption::default, |value| match value {
-                    "always" => PathOption::Always,
-                    "never" => PathOption::Never,
-                    _ => PathOption::default(),
-                }),
-            types: options
-                .and_then(|x| x.get("types"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(TypesOption::default, |value| match value {
-                    "always" => TypesOption::Always,
-                    "never" => TypesOption::Never,
-                    "prefer-import" => TypesOption::PreferImport,
-                    _ => TypesOption::default(),
-                }),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<TripleSlashReferenceConfig>()]
     }
     fn run_once(&self, ctx: &LintContext) {
         let Some(root) = ctx.nodes().iter().next() else { return };
@@ -9,8 +9,14 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::SymbolId;
 use oxc_span::{Atom, CompactString, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(catch-error-name): The catch parameter {0:?} should be named {1:?}")]
@@ -20,9 +26,13 @@ struct CatchErrorNameDiagnostic(CompactString, CompactString, #[label] pub Span)
 #[derive(Debug, Default, Clone)]
 pub struct CatchErrorName(Box<CatchErrorNameConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(default)]
 pub struct CatchErrorNameConfig {
+    #[serde(rename = "ignored")]
+    #[schemars(with = "Vec<String>")]
     ignore: Vec<CompactString>,
+    #[schemars(with = "String")]
     name: CompactString,
 }
 
@@ -61,26 +71,11 @@ declare_oxc_lint!(
 
 impl Rule for CatchErrorName {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let ignored_names = value
-            .get(0)
-            .and_then(|v| v.get("ignored"))
-            .and_then(serde_json::Value::as_array)
-            .unwrap_or(&vec![])
-            .iter()
-            .map(serde_json::Value::as_str)
-            .filter(std::option::Option::is_some)
-            .map(|x| CompactString::from(x.unwrap()))
-            .collect::<Vec<CompactString>>();
-
-        let allowed_name = CompactString::from(
-            value
-                .get(0)
-                .and_then(|v| v.get("name"))
-                .and_then(serde_json::Value::as_str)
-                .unwrap_or("error"),
-        );
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(CatchErrorNameConfig { ignore: ignored_names, name: allowed_name }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<CatchErrorNameConfig>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -12,10 +12,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactString, Span};
 use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
-    rule::Rule,
+    rule::{rule_option, Rule},
     utils::{get_boolean_ancestor, is_boolean_node},
     AstNode, Fix,
 };
@@ -31,21 +33,15 @@ enum ExplicitLengthCheckDiagnostic {
     #[diagnostic(severity(warning))]
     Zero(#[label] Span, CompactString, CompactString, #[help] Option<String>),
 }
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 enum NonZero {
     #[default]
     GreaterThan,
     NotEqual,
 }
-impl NonZero {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "not-equal" => Self::NotEqual,
-            _ => Self::GreaterThan,
-        }
-    }
-}
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
 pub struct ExplicitLengthCheck {
     non_zero: NonZero,
 }
@@ -75,7 +71,8 @@ declare_oxc_lint!(
     /// const isEmpty = foo.length === 0;
     /// ```
     ExplicitLengthCheck,
-    pedantic
+    pedantic,
+    fix
 );
 fn is_literal(expr: &Expression, value: f64) -> bool {
     matches!(expr, Expression::NumericLiteral(lit) if (lit.value - value).abs() < f64::EPSILON)
@@ -289,14 +286,11 @@ impl Rule for ExplicitLengthCheck {
         }
     }
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            non_zero: value
-                .get(0)
-                .and_then(|v| v.get("non-zero"))
-                .and_then(serde_json::Value::as_str)
-                .map(NonZero::from)
-                .unwrap_or_default(),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 }
 
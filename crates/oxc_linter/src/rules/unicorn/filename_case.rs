@@ -5,17 +5,22 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use serde_json::Value;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    context::LintContext,
+    rule::{rule_option, Rule},
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(filename-case): Filename should not be in {1} case")]
 #[diagnostic(severity(warning))]
 struct FilenameCaseDiagnostic(#[label] pub Span, &'static str);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
 #[allow(clippy::struct_field_names)]
+#[serde(rename_all = "camelCase", default)]
 pub struct FilenameCase {
     kebab_case: bool,
     camel_case: bool,
@@ -24,6 +29,25 @@ pub struct FilenameCase {
     underscore_case: bool,
 }
 
+/// A single `case`, or the `cases` allowed
+#[derive(Default, JsonSchema, Deserialize)]
+#[serde(default)]
+struct FilenameCaseOptions {
+    case: Option<CaseOption>,
+    cases: Option<FilenameCase>,
+}
+
+#[derive(JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::enum_variant_names)]
+enum CaseOption {
+    KebabCase,
+    CamelCase,
+    SnakeCase,
+    PascalCase,
+    UnderscoreCase,
+}
+
 impl Default for FilenameCase {
     fn default() -> Self {
         Self {
@@ -51,48 +75,19 @@ declare_oxc_lint!(
 
 impl Rule for FilenameCase {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(case_type) = value.get(0) else {
-            return Self::default();
-        };
-
-        if let Some(Value::String(s)) = case_type.get("case") {
-            return match s.as_str() {
-                "kebabCase" => Self { kebab_case: true, ..Self::default() },
-                "camelCase" => Self { camel_case: true, ..Self::default() },
-                "snakeCase" => Self { snake_case: true, ..Self::default() },
-                "pascalCase" => Self { pascal_case: true, ..Self::default() },
-                "underscoreCase" => Self { underscore_case: true, ..Self::default() },
-                _ => Self::default(),
-            };
-        }
-
-        if let Some(Value::String(s)) = case_type.get("case") {
-            return match s.as_str() {
-                "kebabCase" => Self { kebab_case: true, ..Self::default() },
-                "camelCase" => Self { camel_case: true, ..Self::default() },
-                "snakeCase" => Self { snake_case: true, ..Self::default() },
-                "pascalCase" => Self { pascal_case: true, ..Self::default() },
-                "underscoreCase" => Self { underscore_case: true, ..Self::default() },
-                _ => Self::default(),
-            };
-        }
-
-        if let Some(Value::Object(map)) = case_type.get("cases") {
-            let mut filename_case = Self::default();
-            for (key, value) in map {
-                match (key.as_str(), value) {
-                    ("kebabCase", Value::Bool(b)) => filename_case.kebab_case = *b,
-                    ("camelCase", Value::Bool(b)) => filename_case.camel_case = *b,
-                    ("snakeCase", Value::Bool(b)) => filename_case.snake_case = *b,
-                    ("pascalCase", Value::Bool(b)) => filename_case.pascal_case = *b,
-                    ("underscoreCase", Value::Bool(b)) => filename_case.underscore_case = *b,
-                    _ => (),
-                }
-            }
-            return filename_case;
+        let FilenameCaseOptions { case, cases } = rule_option(&value, 0);
+        match case {
+            Some(CaseOption::KebabCase) => Self { kebab_case: true, ..Self::default() },
+            Some(CaseOption::CamelCase) => Self { camel_case: true, ..Self::default() },
+            Some(CaseOption::SnakeCase) => Self { snake_case: true, ..Self::default() },
+            Some(CaseOption::PascalCase) => Self { pascal_case: true, ..Self::default() },
+            Some(CaseOption::UnderscoreCase) => Self { underscore_case: true, ..Self::default() },
+            None => cases.unwrap_or_default(),
         }
+    }
 
-        Self::default()
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<FilenameCaseOptions>()]
     }
 
     fn run_once<'a>(&self, ctx: &LintContext<'_>) {
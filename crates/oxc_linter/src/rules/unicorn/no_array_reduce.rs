@@ -8,9 +8,14 @@ use oxc_diagnostics::{
 };
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
-    ast_util::is_method_call, context::LintContext, rule::Rule, utils::is_prototype_property,
+    ast_util::is_method_call,
+    context::LintContext,
+    rule::{rule_option, Rule},
+    utils::is_prototype_property,
     AstNode,
 };
 
@@ -19,7 +24,8 @@ use crate::{
 #[diagnostic(severity(warning), help("Refactor your code to use `for` loops instead."))]
 struct NoArrayReduceDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoArrayReduce {
     pub allow_simple_operations: bool,
 }
@@ -50,13 +56,11 @@ declare_oxc_lint!(
 
 impl Rule for NoArrayReduce {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_simple_operations = value
-            .as_object()
-            .and_then(|v| v.get("allowSimpleOperations"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(true);
+        rule_option(&value, 0)
+    }
 
-        Self { allow_simple_operations }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -371,27 +375,27 @@ fn test() {
         // Option: allowSimpleOperations
         (
             r"array.reduce((total, item) => total + item)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce((total, item) => { return total - item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce(function (total, item) { return total * item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce((total, item) => total + item, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce((total, item) => { return total - item }, 0 )",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduce(function (total, item) { return total * item }, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"
@@ -399,7 +403,7 @@ fn test() {
 					return (total / item) * 100;
 				}, 0);
 		",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (r#"array.reduceRight((str, item) => str += item, "")"#, None),
         (
@@ -442,27 +446,27 @@ fn test() {
         // Option: allowSimpleOperations
         (
             r"array.reduceRight((total, item) => total + item)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight((total, item) => { return total - item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight(function (total, item) { return total * item })",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight((total, item) => total + item, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight((total, item) => { return total - item }, 0 )",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"array.reduceRight(function (total, item) { return total * item }, 0)",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
         (
             r"
@@ -470,7 +474,7 @@ fn test() {
 					return (total / item) * 100;
 				}, 0);
 		",
-            Some(json!({ "allowSimpleOperations": false})),
+            Some(json!([{ "allowSimpleOperations": false }])),
         ),
     ];
     Tester::new(NoArrayReduce::NAME, pass, fail).test_and_snapshot();
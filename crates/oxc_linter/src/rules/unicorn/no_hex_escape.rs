@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// const foo = `\u001B${bar}`;
     /// ```
     NoHexEscape,
-    pedantic
+    pedantic,
+    fix
 );
 
 // \x -> \u00
@@ -11,8 +11,15 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
-
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode, Fix};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    ast_util::is_method_call,
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode, Fix,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(no-null): Disallow the use of the `null` literal")]
@@ -24,7 +31,8 @@ struct ReplaceNullDiagnostic(#[label] pub Span);
 #[diagnostic(severity(warning), help("Remove the `null` literal."))]
 struct RemoveNullDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoNull {
     check_strict_equality: Option<bool>,
 }
@@ -50,7 +58,8 @@ declare_oxc_lint!(
     /// let foo
     /// ```
     NoNull,
-    style
+    style,
+    fix
 );
 
 fn match_null_arg(call_expr: &CallExpression, index: usize, span: Span) -> bool {
@@ -162,12 +171,11 @@ fn match_call_expression_pass_case(null_literal: &NullLiteral, call_expr: &CallE
 
 impl Rule for NoNull {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            check_strict_equality: value
-                .get(0)
-                .and_then(|v| v.get("checkStrictEquality"))
-                .and_then(serde_json::Value::as_bool),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -6,15 +6,23 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
-
-use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    ast_util::get_declaration_of_variable,
+    context::LintContext,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(no-typeof-undefined): Compare with `undefined` directly instead of using `typeof`.")]
 #[diagnostic(severity(warning))]
 struct NoTypeofUndefinedDiagnostic(#[label] pub Span);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
 pub struct NoTypeofUndefined {
     check_global_variables: bool,
 }
@@ -73,10 +81,11 @@ impl Rule for NoTypeofUndefined {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let check_global_variables =
-            value.get("checkGlobalVariables").and_then(serde_json::Value::as_bool).unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { check_global_variables }
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<Self>()]
     }
 }
 
@@ -127,11 +136,11 @@ fn test() {
         (r#"typeof foo.bar === "undefined""#, None),
         (
             r#"let foo; typeof foo === "undefined""#,
-            Some(serde_json::json!({ "checkGlobalVariables": false })),
+            Some(serde_json::json!([{ "checkGlobalVariables": false }])),
         ),
         (
             r#"typeof foo === "undefined""#,
-            Some(serde_json::json!({ "checkGlobalVariables": true })),
+            Some(serde_json::json!([{ "checkGlobalVariables": true }])),
         ),
     ];
 
@@ -10,8 +10,15 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
-
-use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    fixer::Fix,
+    rule::{rule_option, Rule},
+    AstNode,
+};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-unicorn(numeric-separators-style): Invalid group length in numeric value.")]
@@ -84,7 +91,8 @@ declare_oxc_lint!(
     /// ];
     /// ```
     NumericSeparatorsStyle,
-    style
+    style,
+    fix
 );
 
 impl Rule for NumericSeparatorsStyle {
@@ -123,30 +131,19 @@ impl Rule for NumericSeparatorsStyle {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut cfg = NumericSeparatorsStyleConfig::default();
-
-        if let Some(config) = value.get(0) {
-            if let Some(config) = config.get("binary") {
-                cfg.binary.set_numeric_base_from_config(config);
-            }
-            if let Some(config) = config.get("hexadecimal") {
-                cfg.hexadecimal.set_numeric_base_from_config(config);
-            }
-            if let Some(config) = config.get("number") {
-                cfg.number.set_numeric_base_from_config(config);
-            }
-            if let Some(config) = config.get("octal") {
-                cfg.octal.set_numeric_base_from_config(config);
-            }
-
-            if let Some(val) =
-                config.get("onlyIfContainsSeparator").and_then(serde_json::Value::as_bool)
-            {
-                cfg.only_if_contains_separator = val;
-            }
-        }
+        let options: NumericSeparatorsStyleOptions = rule_option(&value, 0);
+        let config = NumericSeparatorsStyleConfig::default();
+        Self(Box::new(NumericSeparatorsStyleConfig {
+            only_if_contains_separator: options.only_if_contains_separator,
+            hexadecimal: config.hexadecimal.with_options(options.hexadecimal),
+            binary: config.binary.with_options(options.binary),
+            octal: config.octal.with_options(options.octal),
+            number: config.number.with_options(options.number),
+        }))
+    }
 
-        Self(Box::new(cfg))
+    fn options_schema(gen: &mut SchemaGenerator) -> Vec<Schema> {
+        vec![gen.subschema_for::<NumericSeparatorsStyleOptions>()]
     }
 }
 
@@ -267,17 +264,34 @@ struct NumericBaseConfig {
     group_length: usize,
     minimum_digits: usize,
 }
+
 impl NumericBaseConfig {
-    pub(self) fn set_numeric_base_from_config(&mut self, val: &serde_json::Value) {
-        if let Some(group_length) = val.get("groupLength").and_then(serde_json::Value::as_u64) {
-            self.group_length = usize::try_from(group_length).unwrap();
-        }
-        if let Some(minimum_digits) = val.get("minimumDigits").and_then(serde_json::Value::as_u64) {
-            self.minimum_digits = usize::try_from(minimum_digits).unwrap();
+    fn with_options(self, options: NumericBaseOptions) -> Self {
+        Self {
+            group_length: options.group_length.unwrap_or(self.group_length),
+            minimum_digits: options.minimum_digits.unwrap_or(self.minimum_digits),
         }
     }
 }
 
+/// The options of the rule, the bases and fields left out keep their defaults
+#[derive(Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct NumericSeparatorsStyleOptions {
+    only_if_contains_separator: bool,
+    hexadecimal: NumericBaseOptions,
+    binary: NumericBaseOptions,
+    octal: NumericBaseOptions,
+    number: NumericBaseOptions,
+}
+
+#[derive(Clone, Copy, Default, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct NumericBaseOptions {
+    group_length: Option<usize>,
+    minimum_digits: Option<usize>,
+}
+
 enum SeparatorDir {
     Left,
     Right,
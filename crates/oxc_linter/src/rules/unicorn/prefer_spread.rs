@@ -39,7 +39,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferSpread,
-    style
+    style,
+    fix
 );
 
 impl Rule for PreferSpread {
@@ -1,26 +1,32 @@
 use std::{
-    collections::HashMap,
+    cell::RefCell,
+    collections::{HashMap, HashSet},
     ffi::OsStr,
     fs,
     path::{Path, PathBuf},
     rc::Rc,
     sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
 };
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
 use oxc_parser::Parser;
 use oxc_resolver::Resolver;
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::{SourceType, Span, VALID_EXTENSIONS};
 
 use crate::{
+    baseline::Baseline,
+    cache::LintCache,
+    config::{project_root, ESLintConfig},
+    fix_in_passes,
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    FixedCode, LintContext, Linter, Message,
 };
 
 pub struct LintServiceOptions {
@@ -32,6 +38,28 @@ pub struct LintServiceOptions {
 
     /// TypeScript `tsconfig.json` path for reading path alias and project references
     pub tsconfig: Option<PathBuf>,
+
+    /// Lint each file with the rules from its nearest `.eslintrc.json`,
+    /// instead of the configuration the linter was created with
+    pub config_discovery: bool,
+
+    /// Skip files which are unchanged since they were linted with the cache,
+    /// and report their diagnostics from the cache instead
+    pub cache: Option<LintCache>,
+
+    /// Only report diagnostics starting on these 1-based lines of each file,
+    /// files which are not in the map are reported in full
+    pub changed_lines: Option<HashMap<Box<Path>, HashSet<usize>>>,
+
+    /// Suppress the problems recorded in the baseline, or record them in write mode
+    pub baseline: Option<Baseline>,
+
+    /// Source text of the paths to lint, read from stdin instead of the files.
+    /// Fixed code is kept for [LintService::fixed_stdin] instead of being written.
+    pub stdin: Option<String>,
+
+    /// Source text of files read from the Git index instead of the working tree, e.g. with `--staged`
+    pub staged: HashMap<Box<Path>, String>,
 }
 
 #[derive(Clone)]
@@ -59,14 +87,102 @@ impl LintService {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
 
+    /// The root of the git repository containing `cwd`, or `cwd`,
+    /// where config discovery stops looking for configuration files
+    pub fn project_root(&self) -> &Path {
+        &self.runtime.project_root
+    }
+
+    /// Canonical paths of the configuration files read so far,
+    /// including the ones discovered for the linted files
+    pub fn config_files(&self) -> Vec<PathBuf> {
+        let mut files = self.runtime.linter.config_files().to_vec();
+        if let Some(directory_linters) = &self.runtime.directory_linters {
+            for linter in directory_linters.iter().filter_map(|entry| entry.value().clone()) {
+                files.extend_from_slice(linter.config_files());
+            }
+        }
+        files
+    }
+
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
-        self.runtime
+        let paths = self.runtime.paths.iter().map(|path| path.key().clone()).collect::<Vec<_>>();
+        self.runtime.process_paths(&paths, tx_error);
+        self.runtime.save_cache(tx_error);
+        self.runtime.finish_baseline(tx_error);
+        tx_error.send(None).unwrap();
+    }
+
+    /// The fixed source text of `stdin`, `None` if no fixes were applied
+    ///
+    /// # Panics
+    pub fn fixed_stdin(&self) -> Option<String> {
+        self.runtime.fixed_stdin.lock().unwrap().clone()
+    }
+
+    /// Lint `paths` again, keeping the module records of the other files from previous runs.
+    /// Used by watch mode after [Self::invalidate].
+    ///
+    /// # Panics
+    pub fn run_paths(&self, paths: &[Box<Path>], tx_error: &DiagnosticSender) {
+        self.runtime.process_paths(paths, tx_error);
+        tx_error.send(None).unwrap();
+    }
+
+    /// Replace the paths to lint, e.g. when files are created or deleted in watch mode.
+    /// The module records of removed paths are forgotten.
+    pub fn set_paths(&self, paths: Vec<Box<Path>>) {
+        let paths = paths.into_iter().collect::<DashSet<_>>();
+        let removed = self
+            .runtime
             .paths
             .iter()
-            .par_bridge()
-            .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
-        tx_error.send(None).unwrap();
+            .filter(|path| !paths.contains(path.key()))
+            .map(|path| path.key().to_path_buf())
+            .collect::<Vec<_>>();
+        self.runtime.paths.clear();
+        for path in paths {
+            self.runtime.paths.insert(path);
+        }
+        self.invalidate(&removed);
+    }
+
+    /// Forget the module records of the `changed` files and of the modules importing them,
+    /// directly or transitively. Returns the paths to lint which are affected by the change.
+    ///
+    /// A changed file is matched by the path it is linted with or its resolved absolute path.
+    ///
+    /// # Panics
+    pub fn invalidate(&self, changed: &[PathBuf]) -> Vec<Box<Path>> {
+        let runtime = &self.runtime;
+        // Modules importing each module
+        let mut importers = FxHashMap::<PathBuf, Vec<Box<Path>>>::default();
+        for entry in &runtime.module_map {
+            if let ModuleState::Resolved(module_record) = entry.value() {
+                for loaded_module in &module_record.loaded_modules {
+                    importers
+                        .entry(loaded_module.resolved_absolute_path.clone())
+                        .or_default()
+                        .push(entry.key().clone());
+                }
+            }
+        }
+        let mut invalidated = FxHashSet::default();
+        let mut stack =
+            changed.iter().map(|path| path.clone().into_boxed_path()).collect::<Vec<_>>();
+        while let Some(path) = stack.pop() {
+            if let Some(importers) = importers.get(&*path) {
+                stack.extend(importers.iter().filter(|path| !invalidated.contains(*path)).cloned());
+            }
+            invalidated.insert(path);
+        }
+        let mut cache_state = runtime.cache_state.lock().unwrap();
+        for path in &invalidated {
+            runtime.module_map.remove(path);
+            cache_state.remove(path);
+        }
+        invalidated.into_iter().filter(|path| runtime.paths.contains(path)).collect()
     }
 
     /// For tests
@@ -81,7 +197,9 @@ impl LintService {
         self.runtime
             .paths
             .iter()
+            .map(|path| path.key().clone())
             .flat_map(|path| {
+                let path = &path;
                 let source_type = SourceType::from_path(path).unwrap();
                 self.runtime.init_cache_state(path);
                 self.runtime.process_source(
@@ -123,26 +241,51 @@ enum ModuleState {
     Ignored,
 }
 
+/// Linters derived from the nearest configuration file, keyed by directory.
+/// `None` for directories without a configuration file.
+type DirectoryLinters = DashMap<Box<Path>, Option<Arc<Linter>>>;
+
 pub struct Runtime {
     cwd: Box<Path>,
-    /// All paths to lint
-    paths: FxHashSet<Box<Path>>,
+    /// The root of the git repository containing `cwd`, or `cwd`,
+    /// where the lookup of configuration files stops
+    project_root: Box<Path>,
+    /// All paths to lint, replaced in watch mode when files are created or deleted
+    paths: DashSet<Box<Path>>,
     linter: Linter,
+    /// Only present when config discovery is enabled
+    directory_linters: Option<DirectoryLinters>,
     resolver: Option<Resolver>,
     module_map: ModuleMap,
     cache_state: CacheState,
+    /// Diagnostics from previous runs, not to be confused with `cache_state` for the module map
+    cache: Option<LintCache>,
+    changed_lines: Option<HashMap<Box<Path>, HashSet<usize>>>,
+    baseline: Option<Baseline>,
+    stdin: Option<String>,
+    fixed_stdin: Mutex<Option<String>>,
+    staged: HashMap<Box<Path>, String>,
 }
 
 impl Runtime {
     fn new(linter: Linter, options: LintServiceOptions) -> Self {
         let resolver = linter.options().import_plugin.then(|| Self::get_resolver(options.tsconfig));
+        let project_root = project_root(&options.cwd);
         Self {
             cwd: options.cwd,
+            project_root,
             paths: options.paths.iter().cloned().collect(),
             linter,
+            directory_linters: options.config_discovery.then(DirectoryLinters::default),
             resolver,
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            cache: options.cache,
+            changed_lines: options.changed_lines,
+            baseline: options.baseline,
+            stdin: options.stdin,
+            fixed_stdin: Mutex::default(),
+            staged: options.staged,
         }
     }
 
@@ -168,6 +311,7 @@ impl Runtime {
     }
 
     fn get_source_type_and_text(
+        &self,
         path: &Path,
         ext: &str,
     ) -> Option<Result<(SourceType, String), Error>> {
@@ -178,6 +322,12 @@ impl Runtime {
             return None;
         }
         let source_type = source_type.unwrap_or_default();
+        if let Some(source_text) = self.stdin.as_ref().filter(|_| self.paths.contains(path)) {
+            return Some(Ok((source_type, source_text.clone())));
+        }
+        if let Some(source_text) = self.staged.get(path) {
+            return Some(Ok((source_type, source_text.clone())));
+        }
         let file_result = fs::read_to_string(path)
             .map_err(|e| Error::new(FailedToOpenFileError(path.to_path_buf(), e)));
         Some(match file_result {
@@ -186,6 +336,10 @@ impl Runtime {
         })
     }
 
+    fn process_paths(&self, paths: &[Box<Path>], tx_error: &DiagnosticSender) {
+        paths.iter().par_bridge().for_each(|path| self.process_path(path, tx_error));
+    }
+
     fn process_path(&self, path: &Path, tx_error: &DiagnosticSender) {
         if self.init_cache_state(path) {
             return;
@@ -196,7 +350,7 @@ impl Runtime {
             return;
         };
 
-        let Some(source_type_and_text) = Self::get_source_type_and_text(path, ext) else {
+        let Some(source_type_and_text) = self.get_source_type_and_text(path, ext) else {
             self.ignore_path(path);
             return;
         };
@@ -220,25 +374,185 @@ impl Runtime {
             return;
         }
 
-        for JavaScriptSource { source_text, source_type, .. } in sources {
+        // Number of lines before each source, for the line numbers in `changed_lines`
+        let line_offset = |start: usize| source_text[..start].matches('\n').count();
+
+        // Fixed files are written, so their diagnostics are not cached
+        let cache = self
+            .cache
+            .as_ref()
+            .filter(|_| self.paths.contains(path) && self.linter.options().fix.is_empty());
+        let cache_key = cache.map(|cache| {
+            let directory_linter = self.directory_linter(path, tx_error);
+            let config_hash = directory_linter.as_deref().unwrap_or(&self.linter).config_hash();
+            cache.key(path, &source_text, config_hash)
+        });
+        if let Some((cache, key)) = cache.zip(cache_key) {
+            if let Some(diagnostics) = cache.get(path, key) {
+                for (source, errors) in sources.iter().zip(diagnostics) {
+                    let line_offset = line_offset(source.start);
+                    let lines = self.changed_lines(path);
+                    self.send_errors(
+                        path,
+                        source.source_text,
+                        line_offset,
+                        lines,
+                        errors,
+                        tx_error,
+                    );
+                }
+                // Modules importing this file still need its module record, see `process_source`
+                if !self.linter.options().import_plugin {
+                    return;
+                }
+            }
+        }
+        let mut cached_diagnostics = vec![];
+
+        for JavaScriptSource { source_text, source_type, start } in sources {
+            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
+            if !is_processed_by_partial_loader && !self.linter.options().fix.is_empty() {
+                self.fix_source(path, source_text, source_type, tx_error);
+                continue;
+            }
+
             let allocator = Allocator::default();
-            let mut messages =
+            let messages =
                 self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+            let errors = messages.into_iter().map(|m| m.error).collect::<Vec<_>>();
+            if cache.is_some() {
+                cached_diagnostics.push(errors.iter().map(LintCache::to_cached).collect());
+            }
+            let lines = self.changed_lines(path);
+            self.send_errors(path, source_text, line_offset(start), lines, errors, tx_error);
+        }
 
-            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+        if let Some((cache, key)) = cache.zip(cache_key) {
+            if !cache.is_hit(path) {
+                cache.insert(path, key, cached_diagnostics);
             }
+        }
+    }
+
+    /// Paths in the baseline are relative to the current working directory with `/` separators
+    fn baseline_path(path: &Path) -> String {
+        path.to_string_lossy().replace('\\', "/")
+    }
 
-            if !messages.is_empty() {
-                let errors = messages.into_iter().map(|m| m.error).collect();
-                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
-                tx_error.send(Some(diagnostics)).unwrap();
+    /// Write the baseline in write mode, otherwise report its stale entries
+    fn finish_baseline(&self, tx_error: &DiagnosticSender) {
+        let Some(baseline) = &self.baseline else { return };
+        let linted_paths = self
+            .paths
+            .iter()
+            .map(|path| Self::baseline_path(path.strip_prefix(&self.cwd).unwrap_or(path.key())))
+            .collect::<Vec<_>>();
+        match baseline.finish(&self.cwd, linted_paths.iter().map(String::as_str)) {
+            Ok(stale) => {
+                for diagnostics in stale {
+                    tx_error.send(Some(diagnostics)).unwrap();
+                }
+            }
+            Err(error) => tx_error.send(Some((self.cwd.to_path_buf(), vec![error]))).unwrap(),
+        }
+    }
+
+    /// Write the cache with the modules imported by each linted file
+    fn save_cache(&self, tx_error: &DiagnosticSender) {
+        let Some(cache) = &self.cache else { return };
+        let dependencies = |path: &Path| {
+            let Some(ModuleState::Resolved(module_record)) =
+                self.module_map.get(path).map(|state| state.value().clone())
+            else {
+                return vec![];
+            };
+            let mut dependencies = FxHashSet::default();
+            let mut stack = vec![module_record];
+            while let Some(module_record) = stack.pop() {
+                for loaded_module in &module_record.loaded_modules {
+                    if dependencies.insert(loaded_module.resolved_absolute_path.clone()) {
+                        stack.push(Arc::clone(loaded_module.value()));
+                    }
+                }
+            }
+            dependencies.into_iter().collect()
+        };
+        if let Err(error) = cache.save(dependencies) {
+            tx_error.send(Some((self.cwd.to_path_buf(), vec![error]))).unwrap();
+        }
+    }
+
+    /// Lint and fix `source_text` in passes with [fix_in_passes],
+    /// then write the fixed code, or keep it for [LintService::fixed_stdin] when linting stdin,
+    /// and report the problems which are left.
+    ///
+    /// With `changed_lines`, problems on other lines are neither fixed nor reported,
+    /// and the changed lines are mapped through the fixes of each pass.
+    fn fix_source(
+        &self,
+        path: &Path,
+        source_text: &str,
+        source_type: SourceType,
+        tx_error: &DiagnosticSender,
+    ) {
+        let lines = RefCell::new(self.changed_lines(path).cloned());
+        let FixedCode { code, errors } = fix_in_passes(
+            source_text,
+            |allocator, code| {
+                let mut messages =
+                    self.process_source(path, allocator, code, source_type, true, tx_error);
+                if let Some(lines) = &*lines.borrow() {
+                    messages.retain(|message| is_on_lines(&message.error, code, 0, lines));
+                }
+                messages
+            },
+            |code, fixed_code, applied_edits| {
+                if let Some(lines) = &mut *lines.borrow_mut() {
+                    *lines = map_lines(code, fixed_code, applied_edits, lines);
+                }
+            },
+        );
+
+        if code != source_text {
+            if self.stdin.is_some() && self.paths.contains(path) {
+                *self.fixed_stdin.lock().unwrap() = Some(code.clone());
+            } else {
+                fs::write(path, code.as_bytes()).unwrap();
             }
         }
+        self.send_errors(path, &code, 0, lines.into_inner().as_ref(), errors, tx_error);
+    }
+
+    /// The changed lines of `path` to report, `None` when the whole file is reported
+    fn changed_lines(&self, path: &Path) -> Option<&HashSet<usize>> {
+        self.changed_lines.as_ref().and_then(|lines| lines.get(path))
+    }
+
+    /// Send `errors` of `source_text`, which starts after `line_offset` lines of the file at `path`,
+    /// only the ones on `lines` when given
+    fn send_errors(
+        &self,
+        path: &Path,
+        source_text: &str,
+        line_offset: usize,
+        lines: Option<&HashSet<usize>>,
+        mut errors: Vec<Error>,
+        tx_error: &DiagnosticSender,
+    ) {
+        if let Some(lines) = lines {
+            errors.retain(|error| is_on_lines(error, source_text, line_offset, lines));
+        }
+        let full_path = path;
+        let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        if let Some(baseline) = &self.baseline {
+            let baseline_path = Self::baseline_path(path);
+            errors.retain(|error| !baseline.suppress(&baseline_path, source_text, error));
+        }
+        // Linted files are sent without errors too, they are listed by the JSON output
+        if !errors.is_empty() || self.paths.contains(full_path) {
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -251,11 +565,14 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
+        let start = Instant::now();
         let ret = Parser::new(allocator, source_text, source_type)
             .allow_return_outside_function(true)
             .parse();
+        let parse_time = start.elapsed();
 
         if !ret.errors.is_empty() {
+            self.add_file_timing(path, parse_time, Duration::ZERO);
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
@@ -263,11 +580,14 @@ impl Runtime {
 
         // Build the module record to unblock other threads from waiting for too long.
         // The semantic model is not built at this stage.
+        let start = Instant::now();
         let semantic_builder = SemanticBuilder::new(source_text, source_type)
             .with_trivias(ret.trivias)
             .with_check_syntax_error(check_syntax_errors)
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
+        // Resolving and processing the dependencies below is not part of the semantic time
+        let mut semantic_time = start.elapsed();
 
         if self.linter.options().import_plugin {
             self.module_map.insert(
@@ -300,13 +620,19 @@ impl Runtime {
                     }
                 });
 
-            // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
+            // Stop if the current module is not marked for lint, e.g. ignored, or its diagnostics are cached.
+            if !self.paths.contains(path)
+                || self.cache.as_ref().is_some_and(|cache| cache.is_hit(path))
+            {
+                self.add_file_timing(path, parse_time, semantic_time);
                 return vec![];
             }
         }
 
+        let start = Instant::now();
         let semantic_ret = semantic_builder.build(program);
+        semantic_time += start.elapsed();
+        self.add_file_timing(path, parse_time, semantic_time);
 
         if !semantic_ret.errors.is_empty() {
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
@@ -314,7 +640,33 @@ impl Runtime {
 
         let lint_ctx =
             LintContext::new(path.to_path_buf().into_boxed_path(), &Rc::new(semantic_ret.semantic));
-        self.linter.run(lint_ctx)
+        let directory_linter = self.directory_linter(path, tx_error);
+        directory_linter.as_deref().unwrap_or(&self.linter).run(lint_ctx)
+    }
+
+    fn add_file_timing(&self, path: &Path, parse: Duration, semantic: Duration) {
+        if self.linter.options().timing {
+            self.linter.timings().add_file(path, parse, semantic);
+        }
+    }
+
+    /// The linter for the nearest configuration file of `path`,
+    /// `None` if config discovery is disabled or there is no configuration file.
+    fn directory_linter(&self, path: &Path, tx_error: &DiagnosticSender) -> Option<Arc<Linter>> {
+        let directory_linters = self.directory_linters.as_ref()?;
+        let dir = path.parent()?;
+        if let Some(linter) = directory_linters.get(dir) {
+            return linter.clone();
+        }
+        // Read the configuration files without holding the lock of the map
+        let linter = match ESLintConfig::from_directory(&self.cwd.join(dir), &self.project_root) {
+            Ok(config) => config.map(|config| Arc::new(self.linter.with_config(config))),
+            Err(error) => {
+                tx_error.send(Some((dir.to_path_buf(), vec![error]))).unwrap();
+                None
+            }
+        };
+        directory_linters.entry(dir.to_path_buf().into_boxed_path()).or_insert(linter).clone()
     }
 
     fn init_cache_state(&self, path: &Path) -> bool {
@@ -379,3 +731,135 @@ impl Runtime {
         }
     }
 }
+
+/// Whether `error` starts on one of the 1-based `lines` of the file,
+/// `source_text` starts after `line_offset` lines of the file. Errors without a label are kept.
+fn is_on_lines(
+    error: &Error,
+    source_text: &str,
+    line_offset: usize,
+    lines: &HashSet<usize>,
+) -> bool {
+    let Some(offset) =
+        error.labels().and_then(|mut labels| labels.next()).map(|label| label.offset())
+    else {
+        return true;
+    };
+    let offset = offset.min(source_text.len());
+    let line = line_offset + source_text[..offset].matches('\n').count() + 1;
+    lines.contains(&line)
+}
+
+/// The 1-based `lines` of `source_text` mapped to the lines of `fixed_code`,
+/// which is `source_text` with `applied_edits` applied.
+/// A line maps to every line its text, including the content of the edits on it, ends up on.
+fn map_lines(
+    source_text: &str,
+    fixed_code: &str,
+    applied_edits: &[(Span, usize)],
+    lines: &HashSet<usize>,
+) -> HashSet<usize> {
+    // Offset in `fixed_code` of `offset` in `source_text`, offsets inside an edit map to its end
+    let map_offset = |offset: usize| {
+        let (mut added, mut removed) = (0, 0);
+        for (span, content_len) in applied_edits {
+            let (start, end) = (span.start as usize, span.end as usize);
+            if end <= offset {
+                added += content_len;
+                removed += end - start;
+            } else if start < offset {
+                return start + added - removed + content_len;
+            } else {
+                break;
+            }
+        }
+        offset + added - removed
+    };
+    let line_of = |offset: usize| fixed_code[..offset].matches('\n').count() + 1;
+
+    let mut line_start = 0;
+    let mut mapped_lines = HashSet::new();
+    for (index, line) in source_text.split('\n').enumerate() {
+        let line_end = line_start + line.len();
+        if lines.contains(&(index + 1)) {
+            mapped_lines.extend(line_of(map_offset(line_start))..=line_of(map_offset(line_end)));
+        }
+        line_start = line_end + 1;
+    }
+    mapped_lines
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::{HashMap, HashSet},
+        fs,
+        path::Path,
+    };
+
+    use oxc_diagnostics::DiagnosticService;
+    use oxc_span::Span;
+
+    use super::{map_lines, LintService, LintServiceOptions};
+    use crate::{LintOptions, Linter};
+
+    #[test]
+    fn invalidate_importers() {
+        let dir = std::env::temp_dir().join("oxc_linter_service_invalidate_test");
+        fs::create_dir_all(&dir).unwrap();
+        let dir = fs::canonicalize(dir).unwrap();
+        fs::write(dir.join("a.js"), "import b from './b.js';").unwrap();
+        fs::write(dir.join("b.js"), "import c from './c.js'; export default c;").unwrap();
+        fs::write(dir.join("c.js"), "export default 1;").unwrap();
+        fs::write(dir.join("d.js"), "export default 1;").unwrap();
+        let path = |name: &str| dir.join(name).into_boxed_path();
+
+        let linter = Linter::from_options(LintOptions::default().with_import_plugin(true)).unwrap();
+        let options = LintServiceOptions {
+            cwd: dir.clone().into_boxed_path(),
+            paths: ["a.js", "b.js", "c.js", "d.js"].map(path).to_vec(),
+            tsconfig: None,
+            config_discovery: false,
+            cache: None,
+            changed_lines: None,
+            baseline: None,
+            stdin: None,
+            staged: HashMap::default(),
+        };
+        let lint_service = LintService::new(linter, options);
+        let diagnostic_service = DiagnosticService::default();
+        lint_service.run(diagnostic_service.sender());
+        assert_eq!(lint_service.number_of_dependencies(), 0);
+
+        // The changed file and its importers, directly or transitively
+        let mut affected = lint_service.invalidate(&[dir.join("c.js")]);
+        affected.sort_unstable();
+        assert_eq!(affected, ["a.js", "b.js", "c.js"].map(path));
+        assert_eq!(lint_service.runtime.module_map.len(), 1);
+
+        // Removed paths are forgotten and no longer affected by changes
+        lint_service.run_paths(&affected, diagnostic_service.sender());
+        lint_service.set_paths(["a.js", "b.js", "c.js"].map(path).to_vec());
+        assert!(!lint_service.runtime.module_map.contains_key(Path::new(&dir.join("d.js"))));
+        assert_eq!(lint_service.runtime.module_map.len(), 3);
+        assert!(lint_service.invalidate(&[dir.join("d.js")]).is_empty());
+        assert_eq!(lint_service.invalidate(&[dir.join("b.js")]).len(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn map_lines_through_edits() {
+        let source_text = "a;\nb;\nc;\nd;\n";
+        // Delete `b;\n`, split `c;` into two lines
+        let fixed_code = "a;\nc\n;\nd;\n";
+        let applied_edits = [(Span::new(3, 6), 0), (Span::new(7, 7), 1)];
+        let lines = HashSet::from([3, 4]);
+        assert_eq!(
+            map_lines(source_text, fixed_code, &applied_edits, &lines),
+            HashSet::from([2, 3, 4])
+        );
+        let lines = HashSet::from([1]);
+        assert_eq!(map_lines(source_text, fixed_code, &applied_edits, &lines), HashSet::from([1]));
+    }
+}
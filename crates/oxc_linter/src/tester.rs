@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     env,
     path::{Path, PathBuf},
 };
@@ -10,8 +11,9 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    rules::RULES, ESLintSettings, Fixer, LintOptions, LintService, LintServiceOptions, Linter,
-    RuleEnum,
+    fixer::{Fixer, Message},
+    rules::RULES,
+    ESLintSettings, FixKind, LintOptions, LintService, LintServiceOptions, Linter, RuleEnum,
 };
 
 #[derive(Eq, PartialEq)]
@@ -138,6 +140,7 @@ impl Tester {
         self.test_pass();
         self.test_fail();
         self.test_fix();
+        self.test_fix_kinds();
     }
 
     pub fn test_and_snapshot(&mut self) {
@@ -179,6 +182,47 @@ impl Tester {
         }
     }
 
+    /// The fix kinds declared with `declare_oxc_lint!` are the ones produced by the failing and fix cases
+    /// Testers with only passing cases are skipped, they are extra tests of rules tested elsewhere.
+    fn test_fix_kinds(&self) {
+        if self.expect_fail.is_empty() && self.expect_fix.is_empty() {
+            return;
+        }
+        let fix_cases = self.expect_fix.iter().map(|(source, _, config)| TestCase {
+            source: source.clone(),
+            config: config.clone(),
+            ..TestCase::default()
+        });
+        let mut produced = FixKind::empty();
+        for TestCase { source, config, settings, path } in
+            self.expect_fail.iter().cloned().chain(fix_cases)
+        {
+            let count = |fix: FixKind, has_fix: fn(&Message) -> bool| {
+                let allocator = Allocator::default();
+                let messages =
+                    self.messages(&allocator, &source, config.clone(), fix, &settings, &path);
+                messages.iter().filter(|message| has_fix(message)).count()
+            };
+            let fixes = count(FixKind::Fix, |message| message.fix.is_some());
+            if fixes > 0 {
+                produced |= FixKind::Fix;
+            }
+            // Dangerous fixes are only computed when they are enabled
+            if count(FixKind::Fix | FixKind::Dangerous, |message| message.fix.is_some()) > fixes {
+                produced |= FixKind::Fix | FixKind::Dangerous;
+            }
+            if count(FixKind::Suggestion, |message| !message.suggestions.is_empty()) > 0 {
+                produced |= FixKind::Suggestion;
+            }
+        }
+        assert_eq!(
+            self.find_rule().fix(),
+            produced,
+            "fix kinds declared by {} and produced by its test cases",
+            self.rule_name
+        );
+    }
+
     fn run(
         &mut self,
         source_text: &str,
@@ -188,12 +232,53 @@ impl Tester {
         path: &Option<PathBuf>,
     ) -> TestResult {
         let allocator = Allocator::default();
+        let fix = if is_fix { FixKind::all() } else { FixKind::empty() };
+        let result = self.messages(&allocator, source_text, config, fix, settings, path);
+
+        if result.is_empty() {
+            return TestResult::Passed;
+        }
+
+        if is_fix {
+            let fix_result = Fixer::new(source_text, result).fix();
+            return TestResult::Fixed(fix_result.fixed_code.to_string());
+        }
+
+        let diagnostic_path = if self.import_plugin {
+            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
+        } else {
+            &self.rule_path
+        }
+        .to_string_lossy();
+
+        let handler = GraphicalReportHandler::new().with_theme(GraphicalTheme::unicode_nocolor());
+        for diagnostic in result {
+            let diagnostic = diagnostic.error.with_source_code(source_text.to_string());
+            let diagnostic = diagnostic.with_source_code(NamedSource::new(
+                diagnostic_path.clone(),
+                source_text.to_string(),
+            ));
+            handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
+        }
+        TestResult::Failed
+    }
+
+    /// Lint `source_text` with the rule, computing the fixes of the kinds in `fix`
+    fn messages<'a>(
+        &self,
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        config: Option<Value>,
+        fix: FixKind,
+        settings: &Option<Value>,
+        path: &Option<PathBuf>,
+    ) -> Vec<Message<'a>> {
         let rule = self.find_rule().read_json(config);
         let lint_settings: ESLintSettings = settings
             .as_ref()
             .map_or_else(ESLintSettings::default, |v| ESLintSettings::deserialize(v).unwrap());
         let options = LintOptions::default()
-            .with_fix(is_fix)
+            .with_fix(fix)
             .with_import_plugin(self.import_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_jsx_a11y_plugin(self.jsx_a11y_plugin)
@@ -214,38 +299,21 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![path_to_lint.into_boxed_path()];
-        let options = LintServiceOptions { cwd, paths, tsconfig: None };
+        let options = LintServiceOptions {
+            cwd,
+            paths,
+            tsconfig: None,
+            config_discovery: false,
+            cache: None,
+            changed_lines: None,
+            baseline: None,
+            stdin: None,
+            staged: HashMap::default(),
+        };
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
-        let result = lint_service.run_source(&allocator, source_text, false, tx_error);
-
-        if result.is_empty() {
-            return TestResult::Passed;
-        }
-
-        if is_fix {
-            let fix_result = Fixer::new(source_text, result).fix();
-            return TestResult::Fixed(fix_result.fixed_code.to_string());
-        }
-
-        let diagnostic_path = if self.import_plugin {
-            self.rule_path.strip_prefix(&self.current_working_directory).unwrap()
-        } else {
-            &self.rule_path
-        }
-        .to_string_lossy();
-
-        let handler = GraphicalReportHandler::new().with_theme(GraphicalTheme::unicode_nocolor());
-        for diagnostic in result {
-            let diagnostic = diagnostic.error.with_source_code(source_text.to_string());
-            let diagnostic = diagnostic.with_source_code(NamedSource::new(
-                diagnostic_path.clone(),
-                source_text.to_string(),
-            ));
-            handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
-        }
-        TestResult::Failed
+        lint_service.run_source(allocator, source_text, false, tx_error)
     }
 
     fn find_rule(&self) -> &RuleEnum {
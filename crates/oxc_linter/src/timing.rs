@@ -0,0 +1,234 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use rustc_hash::FxHashMap;
+
+/// Number of the slowest files to print
+const SLOWEST_FILES: usize = 10;
+
+/// Time spent in each rule and in parsing and building the semantic model of each file,
+/// accumulated over all linted files when `LintOptions::timing` is enabled.
+#[derive(Debug, Default)]
+pub struct Timings {
+    rules: Mutex<FxHashMap<&'static str, RuleTiming>>,
+    files: Mutex<FxHashMap<PathBuf, FileTiming>>,
+}
+
+/// Time spent in each method of a rule
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RuleTiming {
+    pub run: Duration,
+    pub run_on_symbol: Duration,
+    pub run_once: Duration,
+}
+
+impl RuleTiming {
+    pub fn total(&self) -> Duration {
+        self.run + self.run_on_symbol + self.run_once
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileTiming {
+    pub parse: Duration,
+    pub semantic: Duration,
+}
+
+impl FileTiming {
+    pub fn total(&self) -> Duration {
+        self.parse + self.semantic
+    }
+}
+
+/// A rule method measured by [RuleTimer::measure]
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RuleMethod {
+    Run,
+    RunOnSymbol,
+    RunOnce,
+}
+
+/// Measures the rules run on a single file, so the shared [Timings] is only locked once per file.
+/// A disabled timer only runs the measured closures.
+pub(crate) struct RuleTimer(Option<Vec<RuleTiming>>);
+
+impl RuleTimer {
+    pub(crate) fn new(enabled: bool, number_of_rules: usize) -> Self {
+        Self(enabled.then(|| vec![RuleTiming::default(); number_of_rules]))
+    }
+
+    #[inline]
+    pub(crate) fn measure<F: FnOnce()>(&mut self, rule_index: usize, method: RuleMethod, f: F) {
+        let Some(timings) = &mut self.0 else {
+            f();
+            return;
+        };
+        let start = Instant::now();
+        f();
+        let elapsed = start.elapsed();
+        let timing = &mut timings[rule_index];
+        match method {
+            RuleMethod::Run => timing.run += elapsed,
+            RuleMethod::RunOnSymbol => timing.run_on_symbol += elapsed,
+            RuleMethod::RunOnce => timing.run_once += elapsed,
+        }
+    }
+}
+
+impl Timings {
+    /// Add the times of `timer`, measured for the rules named `rule_names` in the same order
+    pub(crate) fn add_rules(
+        &self,
+        rule_names: impl Iterator<Item = &'static str>,
+        timer: RuleTimer,
+    ) {
+        let Some(timings) = timer.0 else { return };
+        let mut rules = self.rules.lock().unwrap();
+        for (name, timing) in rule_names.zip(timings) {
+            let total = rules.entry(name).or_default();
+            total.run += timing.run;
+            total.run_on_symbol += timing.run_on_symbol;
+            total.run_once += timing.run_once;
+        }
+    }
+
+    pub(crate) fn add_file(&self, path: &Path, parse: Duration, semantic: Duration) {
+        let mut files = self.files.lock().unwrap();
+        let total = files.entry(path.to_path_buf()).or_default();
+        total.parse += parse;
+        total.semantic += semantic;
+    }
+
+    /// Rules sorted by total time, the slowest first
+    ///
+    /// # Panics
+    ///
+    /// * When another thread panicked while adding timings
+    pub fn rules(&self) -> Vec<(&'static str, RuleTiming)> {
+        let rules = self.rules.lock().unwrap();
+        let mut rules = rules.iter().map(|(name, timing)| (*name, *timing)).collect::<Vec<_>>();
+        rules.sort_unstable_by(|a, b| b.1.total().cmp(&a.1.total()).then(a.0.cmp(b.0)));
+        rules
+    }
+
+    /// Files sorted by total time, the slowest first
+    ///
+    /// # Panics
+    ///
+    /// * When another thread panicked while adding timings
+    pub fn files(&self) -> Vec<(PathBuf, FileTiming)> {
+        let files = self.files.lock().unwrap();
+        let mut files =
+            files.iter().map(|(path, timing)| (path.clone(), *timing)).collect::<Vec<_>>();
+        files.sort_unstable_by(|a, b| b.1.total().cmp(&a.1.total()).then(a.0.cmp(&b.0)));
+        files
+    }
+
+    /// Print a table of the time spent in each rule, and the slowest files to parse and build semantic for
+    ///
+    /// # Panics
+    ///
+    /// * When the writer fails to write
+    /// * When another thread panicked while adding timings
+    pub fn print<W: Write>(&self, writer: &mut W) {
+        let rules = self.rules();
+        let rules_total = rules.iter().map(|(_, timing)| timing.total()).sum::<Duration>();
+        let name_width = rules.iter().map(|(name, _)| name.len()).max().unwrap_or(0).max(4);
+        writeln!(
+            writer,
+            "{:name_width$} | {:>10} | {:>14} | {:>10} | {:>10} | {:>8}",
+            "Rule", "run", "run_on_symbol", "run_once", "total", "relative"
+        )
+        .unwrap();
+        writeln!(
+            writer,
+            "{:-<name_width$}-|{:-<12}|{:-<16}|{:-<12}|{:-<12}|{:-<9}",
+            "", "", "", "", "", ""
+        )
+        .unwrap();
+        for (name, timing) in &rules {
+            writeln!(
+                writer,
+                "{name:name_width$} | {:>10} | {:>14} | {:>10} | {:>10} | {:>7.1}%",
+                format_duration(timing.run),
+                format_duration(timing.run_on_symbol),
+                format_duration(timing.run_once),
+                format_duration(timing.total()),
+                relative(timing.total(), rules_total),
+            )
+            .unwrap();
+        }
+
+        let files = self.files();
+        let parse_total = files.iter().map(|(_, timing)| timing.parse).sum::<Duration>();
+        let semantic_total = files.iter().map(|(_, timing)| timing.semantic).sum::<Duration>();
+        writeln!(writer).unwrap();
+        writeln!(
+            writer,
+            "Parse {} and semantic {} for {} files, the slowest {}:",
+            format_duration(parse_total),
+            format_duration(semantic_total),
+            files.len(),
+            SLOWEST_FILES.min(files.len())
+        )
+        .unwrap();
+        for (path, timing) in files.iter().take(SLOWEST_FILES) {
+            writeln!(
+                writer,
+                "{:>10} | {:>10} | {}",
+                format_duration(timing.parse),
+                format_duration(timing.semantic),
+                path.display()
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn relative(duration: Duration, total: Duration) -> f64 {
+    if total.is_zero() {
+        0.0
+    } else {
+        duration.as_secs_f64() / total.as_secs_f64() * 100.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, time::Duration};
+
+    use super::{RuleMethod, RuleTimer, Timings};
+
+    #[test]
+    fn accumulate_and_print() {
+        let timings = Timings::default();
+        for _ in 0..2 {
+            let mut timer = RuleTimer::new(true, 2);
+            timer.measure(0, RuleMethod::Run, || std::thread::sleep(Duration::from_millis(2)));
+            timer.measure(1, RuleMethod::RunOnce, || {});
+            timings.add_rules(["no-debugger", "no-empty"].into_iter(), timer);
+        }
+        timings.add_rules(["no-debugger"].into_iter(), RuleTimer::new(false, 1));
+        timings.add_file(Path::new("a.js"), Duration::from_millis(1), Duration::from_millis(2));
+
+        let rules = timings.rules();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].0, "no-debugger");
+        assert!(rules[0].1.run >= Duration::from_millis(4));
+        assert_eq!(timings.files()[0].1.total(), Duration::from_millis(3));
+
+        let mut writer = Vec::new();
+        timings.print(&mut writer);
+        let output = String::from_utf8(writer).unwrap();
+        assert!(output.contains("no-empty"));
+        assert!(output.contains("a.js"));
+    }
+}
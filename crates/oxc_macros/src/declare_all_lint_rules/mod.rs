@@ -66,7 +66,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     quote! {
         #(#use_stmts)*
 
-        use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, AstNode};
+        use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, AstNode, FixKind};
         use oxc_semantic::SymbolId;
 
         #[derive(Debug, Clone)]
@@ -88,18 +88,33 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn fix(&self) -> FixKind {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIX),*
+                }
+            }
+
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
                     #(Self::#struct_names(_) => #struct_names::documentation()),*
                 }
             }
 
-            pub fn plugin_name(&self) -> &str {
+            pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #mod_names),*
                 }
             }
 
+            pub fn options_schema(
+                &self,
+                gen: &mut schemars::gen::SchemaGenerator,
+            ) -> Vec<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::options_schema(gen)),*
+                }
+            }
+
             pub fn read_json(&self, maybe_value: Option<serde_json::Value>) -> Self {
                 match self {
                     #(Self::#struct_names(_) => Self::#struct_names(
@@ -9,6 +9,8 @@ use syn::{
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    /// `fix`, `dangerous_fix` or `suggestion`, the kinds of fixes the rule can produce
+    fix_kinds: Vec<Ident>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -34,15 +36,21 @@ impl Parse for LintRuleMeta {
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
 
+        let mut fix_kinds = vec![];
+        while input.parse::<Option<Token!(,)>>()?.is_some() {
+            let Some(fix_kind) = input.parse::<Option<Ident>>()? else { break };
+            fix_kinds.push(fix_kind);
+        }
+
         // Ignore the rest
         input.parse::<TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self { name: struct_name, category, fix_kinds, documentation, used_in_test: false })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix_kinds, documentation, used_in_test } = metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
@@ -55,6 +63,25 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         _ => panic!("invalid rule category"),
     };
 
+    let fix_kind_path = if used_in_test {
+        quote! { oxc_linter::FixKind }
+    } else {
+        quote! { crate::FixKind }
+    };
+    let fix = if fix_kinds.is_empty() {
+        None
+    } else {
+        let fix_kinds = fix_kinds.iter().map(|fix_kind| match fix_kind.to_string().as_str() {
+            "fix" => quote! { #fix_kind_path::Fix },
+            "dangerous_fix" => quote! { #fix_kind_path::Fix.union(#fix_kind_path::Dangerous) },
+            "suggestion" => quote! { #fix_kind_path::Suggestion },
+            _ => panic!("invalid fix kind, expected fix, dangerous_fix or suggestion"),
+        });
+        Some(quote! {
+            const FIX: #fix_kind_path = #fix_kind_path::empty()#(.union(#fix_kinds))*;
+        })
+    };
+
     let import_statement = if used_in_test {
         None
     } else {
@@ -69,6 +96,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            #fix
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
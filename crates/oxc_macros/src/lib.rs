@@ -5,10 +5,12 @@ mod declare_oxc_lint;
 
 /// Macro used to declare an oxc lint rule
 ///
-/// Every lint declaration consists of 2 parts:
+/// Every lint declaration consists of 3 parts:
 ///
 /// 1. The documentation
 /// 2. The lint's struct
+/// 3. The category, optionally followed by the kinds of fixes the rule can produce:
+///    `fix`, `dangerous_fix` or `suggestion`
 ///
 /// # Example
 ///
@@ -0,0 +1,233 @@
+use std::path::{Path, PathBuf};
+
+use globset::{GlobBuilder, GlobMatcher};
+use oxc_diagnostics::Error;
+
+use super::{errors::FailedToReadPrettierConfigError, PartialPrettierOptions};
+use crate::EndOfLine;
+
+/// A `.editorconfig` file, only the properties which map to Prettier options are kept.
+/// <https://spec.editorconfig.org>
+#[derive(Debug)]
+pub struct EditorConfig {
+    dir: PathBuf,
+    /// `root = true` in the preamble, the files in the parent directories are not read
+    pub root: bool,
+    sections: Vec<Section>,
+}
+
+#[derive(Debug)]
+struct Section {
+    glob: GlobMatcher,
+    properties: Vec<(Property, Option<String>)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Property {
+    IndentStyle,
+    IndentSize,
+    TabWidth,
+    EndOfLine,
+    MaxLineLength,
+}
+
+impl Property {
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "indent_style" => Some(Self::IndentStyle),
+            "indent_size" => Some(Self::IndentSize),
+            "tab_width" => Some(Self::TabWidth),
+            "end_of_line" => Some(Self::EndOfLine),
+            "max_line_length" => Some(Self::MaxLineLength),
+            _ => None,
+        }
+    }
+}
+
+/// The properties applying to a file, `None` when unset
+#[derive(Debug, Default)]
+pub struct Properties {
+    indent_style: Option<String>,
+    indent_size: Option<String>,
+    tab_width: Option<String>,
+    end_of_line: Option<String>,
+    max_line_length: Option<String>,
+}
+
+impl EditorConfig {
+    /// The `.editorconfig` file in `dir`, `Ok(None)` if there is none.
+    pub fn find(dir: &Path) -> Result<Option<Self>, Error> {
+        let path = dir.join(".editorconfig");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let source_text = std::fs::read_to_string(&path)
+            .map_err(|e| FailedToReadPrettierConfigError(path.clone(), e.to_string()))?;
+        Ok(Some(Self::parse(dir, &source_text)))
+    }
+
+    /// Invalid lines and sections with an invalid glob are ignored, as other EditorConfig parsers do.
+    fn parse(dir: &Path, source_text: &str) -> Self {
+        let mut root = false;
+        let mut sections: Vec<Section> = vec![];
+        // Properties of a section with an invalid glob are skipped
+        let mut skip_section = false;
+        for line in source_text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+            if let Some(pattern) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+                let glob = Self::glob(pattern);
+                skip_section = glob.is_none();
+                if let Some(glob) = glob {
+                    sections.push(Section { glob, properties: vec![] });
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once(['=', ':']) else { continue };
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+            match sections.last_mut() {
+                // The preamble
+                None => root = key == "root" && value == "true" || root,
+                Some(_) if skip_section => {}
+                Some(section) => {
+                    if let Some(property) = Property::from_key(&key) {
+                        let value = (value != "unset").then_some(value);
+                        section.properties.push((property, value));
+                    }
+                }
+            }
+        }
+        Self { dir: dir.to_path_buf(), root, sections }
+    }
+
+    /// Patterns without a slash match the file name in any directory,
+    /// other patterns are relative to the directory of the `.editorconfig` file.
+    fn glob(pattern: &str) -> Option<GlobMatcher> {
+        let pattern = if pattern.contains('/') {
+            pattern.trim_start_matches('/').to_string()
+        } else {
+            format!("**/{pattern}")
+        };
+        let glob = GlobBuilder::new(&pattern).literal_separator(true).build().ok()?;
+        Some(glob.compile_matcher())
+    }
+
+    /// Apply the sections matching `path` to `properties`, later sections take precedence
+    pub fn apply(&self, path: &Path, properties: &mut Properties) {
+        let Ok(path) = path.strip_prefix(&self.dir) else { return };
+        for section in self.sections.iter().filter(|section| section.glob.is_match(path)) {
+            for (property, value) in &section.properties {
+                let value = value.clone();
+                match property {
+                    Property::IndentStyle => properties.indent_style = value,
+                    Property::IndentSize => properties.indent_size = value,
+                    Property::TabWidth => properties.tab_width = value,
+                    Property::EndOfLine => properties.end_of_line = value,
+                    Property::MaxLineLength => properties.max_line_length = value,
+                }
+            }
+        }
+    }
+}
+
+impl Properties {
+    /// The Prettier options of the properties, the same as Prettier's `editorconfig-to-prettier`.
+    /// <https://github.com/prettier/prettier/blob/main/src/config/editorconfig/editorconfig-to-prettier.js>
+    pub fn to_prettier_options(&self) -> PartialPrettierOptions {
+        let indent_style = self.indent_style.as_deref();
+        let mut indent_size = self.indent_size.as_deref();
+        let mut tab_width = self.tab_width.as_deref();
+        // Defaults of the EditorConfig core library
+        if indent_style == Some("tab") && indent_size.is_none() {
+            indent_size = Some("tab");
+        }
+        if indent_size.is_some_and(|size| size != "tab") && tab_width.is_none() {
+            tab_width = indent_size;
+        }
+        if indent_size == Some("tab") && tab_width.is_some() {
+            indent_size = tab_width;
+        }
+        let number = |value: Option<&str>| value.and_then(|value| value.parse::<usize>().ok());
+
+        let mut options = PartialPrettierOptions::default();
+        if let Some(indent_style) = indent_style {
+            options.use_tabs = Some(indent_style == "tab");
+        }
+        if indent_size == Some("tab") {
+            options.use_tabs = Some(true);
+        }
+        options.tab_width = if options.use_tabs == Some(true) && tab_width.is_some() {
+            number(tab_width)
+        } else if indent_style == Some("space") && indent_size.is_some_and(|size| size != "tab") {
+            number(indent_size)
+        } else {
+            number(tab_width)
+        };
+        options.print_width = match self.max_line_length.as_deref() {
+            // The printer computes the remaining width as an `isize`
+            Some("off") => Some(isize::MAX.unsigned_abs()),
+            max_line_length => number(max_line_length),
+        };
+        options.end_of_line = match self.end_of_line.as_deref() {
+            Some("lf") => Some(EndOfLine::Lf),
+            Some("crlf") => Some(EndOfLine::Crlf),
+            Some("cr") => Some(EndOfLine::Cr),
+            _ => None,
+        };
+        options
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::{EditorConfig, Properties};
+    use crate::{config::PartialPrettierOptions, EndOfLine};
+
+    fn resolve(source_text: &str, path: &str) -> PartialPrettierOptions {
+        let editorconfig = EditorConfig::parse(Path::new("/project"), source_text);
+        let mut properties = Properties::default();
+        editorconfig.apply(&Path::new("/project").join(path), &mut properties);
+        properties.to_prettier_options()
+    }
+
+    #[test]
+    fn to_prettier_options() {
+        let options = resolve("[*]\nindent_style = tab\ntab_width = 8\nend_of_line = crlf", "a.js");
+        assert_eq!(options.use_tabs, Some(true));
+        assert_eq!(options.tab_width, Some(8));
+        assert!(matches!(options.end_of_line, Some(EndOfLine::Crlf)));
+
+        let options = resolve("[*]\nindent_style = space\nindent_size = 4\ntab_width = 8", "a.js");
+        assert_eq!(options.use_tabs, Some(false));
+        assert_eq!(options.tab_width, Some(4));
+
+        let options = resolve("[*]\nindent_size = tab\nmax_line_length = off", "a.js");
+        assert_eq!(options.use_tabs, Some(true));
+        assert_eq!(options.print_width, Some(isize::MAX.unsigned_abs()));
+
+        let options = resolve("[*]\nmax_line_length = 100\nindent_size = 3", "a.js");
+        assert_eq!(options.print_width, Some(100));
+        assert_eq!(options.tab_width, Some(3));
+        assert_eq!(options.use_tabs, None);
+    }
+
+    #[test]
+    fn sections() {
+        let source_text = "root = true\n\n[*]\nindent_size = 4\nmax_line_length = 100\n\n\
+                           [*.ts]\nindent_size = 2\n\n[lib/**.js]\nmax_line_length = unset\n\n\
+                           [{invalid]\nindent_size = 8";
+        assert!(EditorConfig::parse(Path::new("/project"), source_text).root);
+        // Later sections take precedence
+        assert_eq!(resolve(source_text, "src/a.ts").tab_width, Some(2));
+        assert_eq!(resolve(source_text, "src/a.js").tab_width, Some(4));
+        // Patterns with a slash are relative to the directory of the file
+        assert_eq!(resolve(source_text, "lib/a.js").print_width, None);
+        assert_eq!(resolve(source_text, "src/lib/a.js").print_width, Some(100));
+        assert_eq!(resolve(source_text, "a.invalid").tab_width, Some(4));
+    }
+}
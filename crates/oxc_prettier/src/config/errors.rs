@@ -0,0 +1,16 @@
+use std::path::PathBuf;
+
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to read config {0:?} with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToReadPrettierConfigError(pub PathBuf, pub String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Failed to parse config {0:?} with error {1:?}")]
+#[diagnostic()]
+pub struct FailedToParsePrettierConfigError(pub PathBuf, pub String);
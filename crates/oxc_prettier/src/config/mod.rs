@@ -0,0 +1,274 @@
+mod editorconfig;
+mod errors;
+mod prettierrc;
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use oxc_diagnostics::Error;
+use serde::Deserialize;
+
+pub use self::errors::{FailedToParsePrettierConfigError, FailedToReadPrettierConfigError};
+use self::{
+    editorconfig::{EditorConfig, Properties},
+    prettierrc::Prettierrc,
+};
+use crate::options::{ArrowParens, EndOfLine, PrettierOptions, QuoteProps, TrailingComma};
+
+/// Resolves the [PrettierOptions] of a file from the configuration files of its directory and the parent directories:
+///
+/// * The properties of the `.editorconfig` files, up to the one with `root = true`
+/// * The nearest `.prettierrc` (JSON, YAML or TOML) or `prettier` field of a `package.json`,
+///   including the `overrides` matching the file
+///
+/// Options of the Prettier configuration take precedence over the EditorConfig properties.
+/// The configuration files of each directory are read once.
+#[derive(Debug, Default)]
+pub struct PrettierConfigResolver {
+    /// The nearest Prettier configuration of a directory
+    prettierrc: Mutex<HashMap<PathBuf, Option<Arc<Prettierrc>>>>,
+    /// The `.editorconfig` file in a directory
+    editorconfig: Mutex<HashMap<PathBuf, Option<Arc<EditorConfig>>>>,
+}
+
+impl PrettierConfigResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The options of the file at `path`
+    ///
+    /// # Errors
+    ///
+    /// * When a configuration file fails to be read or parsed
+    pub fn resolve(&self, path: &Path) -> Result<PrettierOptions, Error> {
+        self.resolve_with(path, PrettierOptions::default())
+    }
+
+    /// The options of the file at `path`, with the configuration files applied over `options`
+    ///
+    /// # Errors
+    ///
+    /// * When a configuration file fails to be read or parsed
+    pub fn resolve_with(
+        &self,
+        path: &Path,
+        mut options: PrettierOptions,
+    ) -> Result<PrettierOptions, Error> {
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| {
+            std::env::current_dir().map_or_else(|_| path.to_path_buf(), |cwd| cwd.join(path))
+        });
+        let Some(dir) = path.parent() else { return Ok(options) };
+
+        let mut properties = Properties::default();
+        for editorconfig in self.editorconfigs(dir)?.iter().rev() {
+            editorconfig.apply(&path, &mut properties);
+        }
+        properties.to_prettier_options().apply(&mut options);
+
+        if let Some(prettierrc) = self.prettierrc(dir)? {
+            prettierrc.apply(&path, &mut options);
+        }
+        Ok(options)
+    }
+
+    /// The nearest Prettier configuration of `dir`
+    fn prettierrc(&self, dir: &Path) -> Result<Option<Arc<Prettierrc>>, Error> {
+        if let Some(prettierrc) = self.prettierrc.lock().unwrap().get(dir) {
+            return Ok(prettierrc.clone());
+        }
+        let prettierrc = match Prettierrc::find(dir)? {
+            Some(prettierrc) => Some(Arc::new(prettierrc)),
+            None => match dir.parent() {
+                Some(parent) => self.prettierrc(parent)?,
+                None => None,
+            },
+        };
+        self.prettierrc.lock().unwrap().insert(dir.to_path_buf(), prettierrc.clone());
+        Ok(prettierrc)
+    }
+
+    /// The `.editorconfig` files of `dir` and its parent directories, nearest first
+    fn editorconfigs(&self, dir: &Path) -> Result<Vec<Arc<EditorConfig>>, Error> {
+        let mut editorconfigs = vec![];
+        for dir in dir.ancestors() {
+            let cached = self.editorconfig.lock().unwrap().get(dir).cloned();
+            let editorconfig = if let Some(editorconfig) = cached {
+                editorconfig
+            } else {
+                let editorconfig = EditorConfig::find(dir)?.map(Arc::new);
+                self.editorconfig.lock().unwrap().insert(dir.to_path_buf(), editorconfig.clone());
+                editorconfig
+            };
+            if let Some(editorconfig) = editorconfig {
+                let root = editorconfig.root;
+                editorconfigs.push(editorconfig);
+                if root {
+                    break;
+                }
+            }
+        }
+        Ok(editorconfigs)
+    }
+}
+
+/// Options of a configuration file, `None` when not configured
+/// <https://prettier.io/docs/en/options>
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialPrettierOptions {
+    pub print_width: Option<usize>,
+    pub tab_width: Option<usize>,
+    pub use_tabs: Option<bool>,
+    pub end_of_line: Option<EndOfLine>,
+    pub semi: Option<bool>,
+    pub single_quote: Option<bool>,
+    pub quote_props: Option<QuoteProps>,
+    pub jsx_single_quote: Option<bool>,
+    pub trailing_comma: Option<TrailingComma>,
+    pub bracket_spacing: Option<bool>,
+    pub bracket_same_line: Option<bool>,
+    pub arrow_parens: Option<ArrowParens>,
+}
+
+impl PartialPrettierOptions {
+    /// Override `options` with the configured options
+    pub fn apply(&self, options: &mut PrettierOptions) {
+        if let Some(print_width) = self.print_width {
+            options.print_width = print_width;
+        }
+        if let Some(tab_width) = self.tab_width {
+            options.tab_width = tab_width;
+        }
+        if let Some(use_tabs) = self.use_tabs {
+            options.use_tabs = use_tabs;
+        }
+        if let Some(end_of_line) = self.end_of_line {
+            options.end_of_line = end_of_line;
+        }
+        if let Some(semi) = self.semi {
+            options.semi = semi;
+        }
+        if let Some(single_quote) = self.single_quote {
+            options.single_quote = single_quote;
+        }
+        if let Some(quote_props) = self.quote_props {
+            options.quote_props = quote_props;
+        }
+        if let Some(jsx_single_quote) = self.jsx_single_quote {
+            options.jsx_single_quote = jsx_single_quote;
+        }
+        if let Some(trailing_comma) = self.trailing_comma {
+            options.trailing_comma = trailing_comma;
+        }
+        if let Some(bracket_spacing) = self.bracket_spacing {
+            options.bracket_spacing = bracket_spacing;
+        }
+        if let Some(bracket_same_line) = self.bracket_same_line {
+            options.bracket_same_line = bracket_same_line;
+        }
+        if let Some(arrow_parens) = self.arrow_parens {
+            options.arrow_parens = arrow_parens;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::PathBuf};
+
+    use super::PrettierConfigResolver;
+    use crate::options::{PrettierOptions, TrailingComma};
+
+    /// A directory with the `files`, removed when dropped
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str, files: &[(&str, &str)]) -> Self {
+            let dir = std::env::temp_dir().join(name);
+            let _ = fs::remove_dir_all(&dir);
+            for (path, content) in files {
+                let path = dir.join(path);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, content).unwrap();
+            }
+            Self(dir)
+        }
+
+        fn resolve(&self, path: &str) -> PrettierOptions {
+            PrettierConfigResolver::new().resolve(&self.0.join(path)).unwrap()
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn file_formats() {
+        let dir = TempDir::new(
+            "oxc_prettier_config_file_formats",
+            &[
+                ("yaml/.prettierrc", "semi: false\ntrailingComma: es5"),
+                ("json/.prettierrc", "{ \"semi\": false }"),
+                ("toml/.prettierrc.toml", "semi = false\ntabWidth = 4"),
+                ("package/package.json", "{ \"prettier\": { \"semi\": false } }"),
+                // Without the `prettier` field, the `.prettierrc` of the same directory is used
+                ("package_without/package.json", "{ \"name\": \"a\" }"),
+                ("package_without/.prettierrc.yml", "semi: false"),
+            ],
+        );
+        let yaml = dir.resolve("yaml/a.js");
+        assert!(!yaml.semi);
+        assert_eq!(yaml.trailing_comma, TrailingComma::ES5);
+        assert!(!dir.resolve("json/a.js").semi);
+        let toml = dir.resolve("toml/a.js");
+        assert!(!toml.semi);
+        assert_eq!(toml.tab_width, 4);
+        assert!(!dir.resolve("package/a.js").semi);
+        assert!(!dir.resolve("package_without/a.js").semi);
+
+        let dir = TempDir::new("oxc_prettier_config_invalid", &[(".prettierrc", "semi: [")]);
+        assert!(PrettierConfigResolver::new().resolve(&dir.0.join("a.js")).is_err());
+    }
+
+    #[test]
+    fn overrides_and_precedence() {
+        let dir = TempDir::new(
+            "oxc_prettier_config_overrides",
+            &[
+                (".editorconfig", "root = true\n[*]\nindent_size = 4\nmax_line_length = 100"),
+                (
+                    ".prettierrc.json",
+                    r#"{
+                        "printWidth": 120,
+                        "overrides": [
+                            { "files": "*.ts", "excludeFiles": "legacy/**", "options": { "semi": false } },
+                            { "files": ["src/*.js"], "options": { "singleQuote": true } }
+                        ]
+                    }"#,
+                ),
+                ("nested/.prettierrc", "tabWidth: 8"),
+            ],
+        );
+        let options = dir.resolve("a.js");
+        // The Prettier configuration takes precedence over `.editorconfig`
+        assert_eq!(options.print_width, 120);
+        assert_eq!(options.tab_width, 4);
+        assert!(options.semi);
+        assert!(!dir.resolve("lib/a.ts").semi);
+        assert!(dir.resolve("legacy/a.ts").semi);
+        assert!(dir.resolve("src/a.js").single_quote);
+        assert!(!dir.resolve("src/nested/a.js").single_quote);
+        // Only the nearest Prettier configuration is used, `.editorconfig` files are merged
+        let options = dir.resolve("nested/a.ts");
+        assert_eq!(options.tab_width, 8);
+        assert_eq!(options.print_width, 100);
+        assert!(options.semi);
+    }
+}
@@ -0,0 +1,124 @@
+use std::path::{Path, PathBuf};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use oxc_diagnostics::Error;
+use serde::{de, Deserialize, Deserializer};
+
+use super::{
+    errors::{FailedToParsePrettierConfigError, FailedToReadPrettierConfigError},
+    PartialPrettierOptions,
+};
+use crate::PrettierOptions;
+
+/// File names of a Prettier configuration, in the order they are searched for in a directory.
+/// <https://prettier.io/docs/en/configuration>
+const PRETTIERRC_FILE_NAMES: [&str; 6] = [
+    "package.json",
+    ".prettierrc",
+    ".prettierrc.json",
+    ".prettierrc.yaml",
+    ".prettierrc.yml",
+    ".prettierrc.toml",
+];
+
+/// A `.prettierrc` file, or the `prettier` field of a `package.json`
+#[derive(Debug, Default, Deserialize)]
+pub struct Prettierrc {
+    /// Directory of the configuration file, which the glob patterns of the overrides are relative to
+    #[serde(skip)]
+    dir: PathBuf,
+    #[serde(flatten)]
+    options: PartialPrettierOptions,
+    #[serde(default)]
+    overrides: Vec<PrettierOverride>,
+}
+
+/// <https://prettier.io/docs/en/configuration#configuration-overrides>
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrettierOverride {
+    #[serde(deserialize_with = "deserialize_globs")]
+    files: GlobSet,
+    #[serde(default, deserialize_with = "deserialize_globs")]
+    exclude_files: GlobSet,
+    #[serde(default)]
+    options: PartialPrettierOptions,
+}
+
+impl Prettierrc {
+    /// The configuration file in `dir`, `Ok(None)` if there is none.
+    pub fn find(dir: &Path) -> Result<Option<Self>, Error> {
+        for file_name in PRETTIERRC_FILE_NAMES {
+            let path = dir.join(file_name);
+            if !path.is_file() {
+                continue;
+            }
+            let source_text = std::fs::read_to_string(&path)
+                .map_err(|e| FailedToReadPrettierConfigError(path.clone(), e.to_string()))?;
+            let parse_error = |e: &dyn std::fmt::Display| {
+                Error::new(FailedToParsePrettierConfigError(path.clone(), e.to_string()))
+            };
+            let prettierrc = match file_name {
+                "package.json" => {
+                    let mut package_json: serde_json::Value =
+                        serde_json::from_str(&source_text).map_err(|e| parse_error(&e))?;
+                    // A `package.json` without the `prettier` field is skipped
+                    let Some(value) = package_json.get_mut("prettier") else { continue };
+                    Self::deserialize(value.take()).map_err(|e| parse_error(&e))?
+                }
+                ".prettierrc.json" => {
+                    serde_json::from_str(&source_text).map_err(|e| parse_error(&e))?
+                }
+                ".prettierrc.toml" => toml::from_str(&source_text).map_err(|e| parse_error(&e))?,
+                // `.prettierrc` is either JSON or YAML, JSON is mostly a subset of YAML
+                _ if source_text.trim().is_empty() => Self::default(),
+                _ => serde_yaml::from_str(&source_text).map_err(|e| parse_error(&e))?,
+            };
+            return Ok(Some(Self { dir: dir.to_path_buf(), ..prettierrc }));
+        }
+        Ok(None)
+    }
+
+    /// Apply the options, then the options of the overrides matching `path` in declaration order
+    pub fn apply(&self, path: &Path, options: &mut PrettierOptions) {
+        self.options.apply(options);
+        let Ok(path) = path.strip_prefix(&self.dir) else { return };
+        for r#override in &self.overrides {
+            if r#override.files.is_match(path) && !r#override.exclude_files.is_match(path) {
+                r#override.options.apply(options);
+            }
+        }
+    }
+}
+
+/// `files` and `excludeFiles` are `string | string[]`.
+/// Patterns without a slash match the file name in any directory.
+fn deserialize_globs<'de, D>(deserializer: D) -> Result<GlobSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    let patterns = match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(pattern) => vec![pattern],
+        OneOrMany::Many(patterns) => patterns,
+    };
+
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.trim_start_matches("./");
+        let pattern =
+            if pattern.contains('/') { pattern.to_string() } else { format!("**/{pattern}") };
+        let glob = GlobBuilder::new(&pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(de::Error::custom)?;
+        builder.add(glob);
+    }
+    builder.build().map_err(de::Error::custom)
+}
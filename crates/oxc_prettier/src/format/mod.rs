@@ -73,6 +73,8 @@ impl<'a> Format<'a> for Program<'a> {
             /* is_root */ true,
         ) {
             parts.push(doc);
+            // Same as Prettier, non-empty files end with a newline
+            parts.extend(hardline!());
         }
         p.leave_node();
         Doc::Array(parts)
@@ -6,6 +6,7 @@
 
 mod binaryish;
 mod comments;
+mod config;
 mod doc;
 mod format;
 mod macros;
@@ -23,7 +24,13 @@ use oxc_syntax::identifier::is_line_terminator;
 
 use crate::{doc::Doc, doc::DocBuilder, format::Format, printer::Printer};
 
-pub use crate::options::{ArrowParens, EndOfLine, PrettierOptions, QuoteProps, TrailingComma};
+pub use crate::{
+    config::{
+        FailedToParsePrettierConfigError, FailedToReadPrettierConfigError, PartialPrettierOptions,
+        PrettierConfigResolver,
+    },
+    options::{ArrowParens, EndOfLine, PrettierOptions, QuoteProps, TrailingComma},
+};
 
 type GroupId = u32;
 #[derive(Default)]
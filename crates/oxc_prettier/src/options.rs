@@ -1,5 +1,7 @@
 use std::str::FromStr;
 
+use serde::Deserialize;
+
 /// Prettier Options
 ///
 /// References
@@ -81,7 +83,8 @@ impl Default for PrettierOptions {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum EndOfLine {
     #[default]
     Lf,
@@ -114,7 +117,8 @@ impl FromStr for EndOfLine {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum QuoteProps {
     /// Only add quotes around object properties where required.
     #[default]
@@ -152,7 +156,8 @@ impl FromStr for QuoteProps {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TrailingComma {
     /// Trailing commas wherever possible (including function parameters and calls).
     #[default]
@@ -190,7 +195,8 @@ impl FromStr for TrailingComma {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ArrowParens {
     /// Always include parens. `Example: (x) => x`
     #[default]